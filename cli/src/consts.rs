@@ -7,3 +7,10 @@ pub const MARINADE_WITHDRAW_AUTHORITY: Pubkey =
 /// Marinade's operations voting wallet
 pub const MARINADE_OPS_VOTING_WALLET: Pubkey =
     Pubkey::from_str_const("opLSF7LdfyWNBby5o6FT8UFsr2A4UGKteECgtLSYrSm");
+
+/// Max. number of proof hashes sent per transaction when initializing a MetaMerkleProof.
+/// Longer proofs are appended in chunks of this size.
+pub const META_MERKLE_PROOF_CHUNK_SIZE: usize = 16;
+
+/// Max. number of operators whose OperatorStats are recorded per transaction.
+pub const RECORD_OPERATOR_STATS_CHUNK_SIZE: usize = 8;
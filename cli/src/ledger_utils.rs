@@ -0,0 +1,162 @@
+use anyhow::{anyhow, Result};
+use log::info;
+use solana_accounts_db::hardened_unpack::{open_genesis_config, MAX_GENESIS_ARCHIVE_UNPACKED_SIZE};
+use solana_ledger::{
+    bank_forks_utils,
+    blockstore::Blockstore,
+    blockstore_options::{AccessType, BlockstoreOptions},
+    blockstore_processor::{self, ProcessOptions},
+};
+use solana_runtime::{
+    accounts_background_service::AbsRequestSender,
+    bank::Bank,
+    snapshot_archive_info::SnapshotArchiveInfoGetter,
+    snapshot_bank_utils,
+    snapshot_config::SnapshotConfig,
+    snapshot_utils::{self, ArchiveFormat, SnapshotVersion, ZstdConfig},
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Paths used to load a bank from the ledger and its snapshots.
+pub struct SnapshotPaths {
+    pub ledger_path: PathBuf,
+    pub account_paths: Vec<PathBuf>,
+    pub full_snapshots_path: PathBuf,
+    pub incremental_snapshots_path: PathBuf,
+    pub backup_snapshots_dir: PathBuf,
+}
+
+/// Loads the bank at `desired_slot` by replaying the ledger from the latest snapshot in
+/// `full_snapshots_path` and `incremental_snapshots_path`. If `save_snapshot` is set, a full
+/// snapshot of the bank is written to `snapshot_save_path`.
+pub fn get_bank_from_ledger(
+    ledger_path: &Path,
+    account_paths: Vec<PathBuf>,
+    full_snapshots_path: PathBuf,
+    incremental_snapshots_path: PathBuf,
+    desired_slot: u64,
+    save_snapshot: bool,
+    snapshot_save_path: &Path,
+) -> Result<Arc<Bank>> {
+    let genesis_config = open_genesis_config(ledger_path, MAX_GENESIS_ARCHIVE_UNPACKED_SIZE)?;
+    // Secondary access, so the ledger can be read while the validator is running.
+    let blockstore = Blockstore::open_with_options(
+        ledger_path,
+        BlockstoreOptions {
+            access_type: AccessType::Secondary,
+            ..BlockstoreOptions::default()
+        },
+    )?;
+    if blockstore.meta(desired_slot)?.is_none() {
+        return Err(anyhow!("Slot {} not found in ledger", desired_slot));
+    }
+
+    let snapshot_config = SnapshotConfig {
+        full_snapshot_archives_dir: full_snapshots_path.clone(),
+        incremental_snapshot_archives_dir: incremental_snapshots_path,
+        bank_snapshots_dir: full_snapshots_path,
+        ..SnapshotConfig::new_load_only()
+    };
+    let process_options = ProcessOptions {
+        halt_at_slot: Some(desired_slot),
+        ..ProcessOptions::default()
+    };
+    let exit = Arc::new(AtomicBool::new(false));
+    let (bank_forks, leader_schedule_cache, _) = bank_forks_utils::load_bank_forks(
+        &genesis_config,
+        &blockstore,
+        account_paths,
+        &snapshot_config,
+        &process_options,
+        None,
+        None,
+        None,
+        exit.clone(),
+    )?;
+    blockstore_processor::process_blockstore_from_root(
+        &blockstore,
+        &bank_forks,
+        &leader_schedule_cache,
+        &process_options,
+        None,
+        None,
+        &AbsRequestSender::default(),
+    )?;
+    let working_bank = bank_forks.read().unwrap().working_bank();
+    exit.store(true, Ordering::Relaxed);
+    if working_bank.slot() != desired_slot {
+        return Err(anyhow!(
+            "Replay halted at slot {} instead of {}",
+            working_bank.slot(),
+            desired_slot
+        ));
+    }
+
+    if save_snapshot {
+        let archive_info = snapshot_bank_utils::bank_to_full_snapshot_archive(
+            ledger_path,
+            &working_bank,
+            Some(SnapshotVersion::default()),
+            snapshot_save_path,
+            snapshot_save_path,
+            ArchiveFormat::TarZstd {
+                config: ZstdConfig::default(),
+            },
+        )?;
+        info!(
+            "Saved full snapshot for slot {} to {:?}",
+            archive_info.slot(),
+            archive_info.path()
+        );
+    }
+
+    Ok(working_bank)
+}
+
+/// Loads the bank from the full snapshot archive taken at `snapshot_slot`, without replaying
+/// the ledger.
+pub fn get_bank_from_snapshot_at_slot(
+    snapshot_slot: u64,
+    full_snapshots_path: &Path,
+    bank_snapshots_dir: &Path,
+    account_paths: Vec<PathBuf>,
+    ledger_path: &Path,
+) -> Result<Bank> {
+    let full_snapshot_archive_info =
+        snapshot_utils::get_full_snapshot_archives(full_snapshots_path)
+            .into_iter()
+            .find(|archive| archive.slot() == snapshot_slot)
+            .ok_or_else(|| anyhow!("No full snapshot found for slot {}", snapshot_slot))?;
+    let genesis_config = open_genesis_config(ledger_path, MAX_GENESIS_ARCHIVE_UNPACKED_SIZE)?;
+    let process_options = ProcessOptions {
+        halt_at_slot: Some(snapshot_slot),
+        ..ProcessOptions::default()
+    };
+    let exit = Arc::new(AtomicBool::new(false));
+    let (bank, _) = snapshot_bank_utils::bank_from_snapshot_archives(
+        &account_paths,
+        bank_snapshots_dir,
+        &full_snapshot_archive_info,
+        None,
+        &genesis_config,
+        &process_options.runtime_config,
+        process_options.debug_keys.clone(),
+        None,
+        process_options.limit_load_slot_count_from_snapshot,
+        process_options.accounts_db_test_hash_calculation,
+        process_options.accounts_db_skip_shrink,
+        process_options.accounts_db_force_initial_clean,
+        process_options.verify_index,
+        process_options.accounts_db_config.clone(),
+        None,
+        exit.clone(),
+    )?;
+    exit.store(true, Ordering::Relaxed);
+    Ok(bank)
+}
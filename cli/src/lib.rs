@@ -1,4 +1,5 @@
 pub mod consts;
+pub mod ledger_utils;
 pub mod merkle;
 pub mod utils;
 
@@ -8,11 +9,9 @@ pub use merkle::*;
 
 use anyhow::Error;
 use borsh_stake::BorshDeserialize;
+use gov_merkle::MerkleTree;
 use gov_v1::{MetaMerkleLeaf, StakeMerkleLeaf};
 use itertools::Itertools;
-use meta_merkle_tree::{
-    generated_merkle_tree::Delegation, merkle_tree::MerkleTree, utils::get_proof,
-};
 use solana_program::vote::state::VoteState;
 use solana_program::{pubkey::Pubkey, stake_history::StakeHistory, sysvar};
 use solana_runtime::{bank::Bank, stakes::StakeAccount};
@@ -24,6 +23,15 @@ use spl_stake_pool::state::AccountType;
 use spl_stake_pool::state::StakePool;
 use std::sync::Arc;
 
+/// Active stake of a stake account and its authorities.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delegation {
+    pub stake_account_pubkey: Pubkey,
+    pub staker_pubkey: Pubkey,
+    pub withdrawer_pubkey: Pubkey,
+    pub lamports_delegated: u64,
+}
+
 fn get_validator_identity(
     bank: &solana_runtime::bank::Bank,
     vote_account: &Pubkey,
@@ -179,7 +187,7 @@ pub fn generate_meta_merkle_snapshot(bank: &Arc<Bank>) -> Result<MetaMerkleSnaps
                 .iter()
                 .map(|n| n.hash().to_bytes())
                 .collect();
-            let stake_merkle = MerkleTree::new(&hashed_nodes[..], true);
+            let stake_merkle = MerkleTree::new(&hashed_nodes);
 
             let voting_wallet = get_validator_identity(bank, voter_pubkey);
             if voting_wallet.is_none() {
@@ -193,7 +201,7 @@ pub fn generate_meta_merkle_snapshot(bank: &Arc<Bank>) -> Result<MetaMerkleSnaps
             let meta_merkle_leaf = MetaMerkleLeaf {
                 vote_account: *voter_pubkey,
                 voting_wallet: voting_wallet.unwrap_or_default(),
-                stake_merkle_root: stake_merkle.get_root().unwrap().to_bytes(),
+                stake_merkle_root: stake_merkle.root().unwrap(),
                 active_stake: vote_account_stake,
             };
 
@@ -217,7 +225,7 @@ pub fn generate_meta_merkle_snapshot(bank: &Arc<Bank>) -> Result<MetaMerkleSnaps
         .iter()
         .map(|n| n.hash().to_bytes())
         .collect();
-    let meta_merkle = MerkleTree::new(&hashed_nodes[..], true);
+    let meta_merkle = MerkleTree::new(&hashed_nodes);
 
     // 4. Generate MetaMerkleLeafBundle with proof.
     let meta_merkle_bundles = meta_merkle_leaves
@@ -228,7 +236,7 @@ pub fn generate_meta_merkle_snapshot(bank: &Arc<Bank>) -> Result<MetaMerkleSnaps
             |(i, (meta_merkle_leaf, stake_merkle_leaves))| MetaMerkleLeafBundle {
                 meta_merkle_leaf,
                 stake_merkle_leaves,
-                proof: Some(get_proof(&meta_merkle, i)),
+                proof: meta_merkle.get_proof(i),
             },
         )
         .collect();
@@ -237,7 +245,7 @@ pub fn generate_meta_merkle_snapshot(bank: &Arc<Bank>) -> Result<MetaMerkleSnaps
     println!("Stake Accounts Count: {}", stake_account_count);
 
     Ok(MetaMerkleSnapshot {
-        root: meta_merkle.get_root().unwrap().to_bytes(),
+        root: meta_merkle.root().unwrap(),
         leaf_bundles: meta_merkle_bundles,
         slot: bank.slot(),
     })
@@ -3,23 +3,29 @@ use anchor_client::{
         bs58,
         commitment_config::CommitmentConfig,
         pubkey::Pubkey,
-        signature::{read_keypair_file, Keypair},
+        signature::{read_keypair_file, Keypair, Signature},
     },
     Client, Cluster, Program,
 };
 use anyhow::{anyhow, Result};
 use clap::Parser;
-use cli::{generate_meta_merkle_snapshot, utils::*, MetaMerkleSnapshot};
-use gov_v1::{Ballot, BallotBox, ConsensusResult, MetaMerkleProof, ProgramConfig};
+use cli::{
+    consts::META_MERKLE_PROOF_CHUNK_SIZE,
+    generate_meta_merkle_snapshot,
+    ledger_utils::{get_bank_from_ledger, get_bank_from_snapshot_at_slot, SnapshotPaths},
+    utils::*,
+    MetaMerkleSnapshot,
+};
+use gov_v1::{
+    AdminAction, AdminProposal, Ballot, BallotBox, ConsensusResult, ExpiryResolution,
+    MetaMerkleProof, OperatorCommit, OperatorKeyRotation, OperatorStats, OperatorWeight,
+    OperatorWhitelistUpdate, ProgramConfig, ProgramConfigUpdate, Proposal, VoteChoice,
+};
 use log::info;
 use solana_sdk::signer::Signer;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::{collections::HashMap, fs, process::Command, thread, time::Duration};
-use tip_router_operator_cli::{
-    cli::SnapshotPaths,
-    ledger_utils::{get_bank_from_ledger, get_bank_from_snapshot_at_slot},
-};
 use tokio::runtime::Builder;
 
 #[derive(Clone, Parser)]
@@ -31,9 +37,6 @@ struct Cli {
     #[arg(short, long, env, default_value = "/")]
     pub authority_path: PathBuf,
 
-    #[arg(short, long, env, default_value = "11111111111111111111111111111111")]
-    pub operator_address: String,
-
     #[arg(short, long, env, default_value = "http://localhost:8899")]
     pub rpc_url: String,
 
@@ -49,9 +52,6 @@ struct Cli {
     #[arg(short, long, env)]
     pub backup_snapshots_dir: Option<PathBuf>,
 
-    #[arg(long, env, default_value = "mainnet")]
-    pub cluster: String,
-
     #[arg(long, env)]
     pub micro_lamports: Option<u64>,
 
@@ -77,6 +77,41 @@ impl Cli {
     }
 }
 
+/// Ballot of a meta merkle snapshot, as printed by `log-meta-merkle-hash`.
+#[derive(clap::Args, Clone)]
+pub struct BallotArgs {
+    #[arg(long, value_parser = parse_base_58_32, help = "Meta merkle tree root, base-58 encoded.")]
+    root: [u8; 32],
+
+    #[arg(long, value_parser = parse_base_58_32, help = "SHA256 hash of the meta merkle snapshot, base-58 encoded.")]
+    hash: [u8; 32],
+
+    #[arg(long, help = "Slot of the meta merkle snapshot")]
+    slot: u64,
+
+    #[arg(long, help = "Total active stake of the meta merkle snapshot")]
+    total_stake: u64,
+
+    #[arg(long, help = "Number of vote accounts in the meta merkle snapshot")]
+    vote_accounts: u32,
+
+    #[arg(long, help = "Number of stake accounts in the meta merkle snapshot")]
+    stake_accounts: u32,
+}
+
+impl From<BallotArgs> for Ballot {
+    fn from(args: BallotArgs) -> Self {
+        Ballot {
+            meta_merkle_root: args.root,
+            snapshot_hash: args.hash,
+            snapshot_slot: args.slot,
+            total_active_stake: args.total_stake,
+            vote_account_count: args.vote_accounts,
+            stake_account_count: args.stake_accounts,
+        }
+    }
+}
+
 #[derive(clap::Subcommand, Clone)]
 pub enum Commands {
     SnapshotSlot {
@@ -127,14 +162,96 @@ pub enum Commands {
         #[arg(long, help = "Generate MetaMerkleSnapshot after snapshot")]
         generate_meta_merkle: bool,
     },
-    InitProgramConfig {},
+    InitProgramConfig {
+        #[arg(long, help = "Min. percentage of votes required to finalize a ballot")]
+        min_consensus_threshold_bps: u16,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Min. number of operators that must vote before a ballot can reach consensus"
+        )]
+        min_votes: u16,
+
+        #[arg(long, value_parser = parse_pubkey)]
+        tie_breaker_admin: Pubkey,
+
+        #[arg(long)]
+        vote_duration: i64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Duration after finalization before a ballot box can be closed"
+        )]
+        close_grace_period: i64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Delay in seconds before queued config and whitelist changes can be applied"
+        )]
+        config_change_delay: i64,
+
+        #[arg(
+            long,
+            help = "Slot offset within each epoch targeted by scheduled ballot boxes"
+        )]
+        scheduled_slot_offset: Option<u64>,
+
+        #[arg(long, value_parser = parse_pubkey, help = "Key allowed to pause and unpause the program")]
+        guardian: Option<Pubkey>,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Max. total duration a ballot box can be open for, including vote extensions"
+        )]
+        max_vote_duration: i64,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Duration of the commit window of new ballot boxes, 0 disables commit-reveal"
+        )]
+        commit_duration: i64,
+
+        #[arg(
+            long,
+            value_parser = parse_expiry_resolution,
+            default_value = "tie-breaker-admin",
+            help = "Resolution of expired ballot boxes: tie-breaker-admin | plurality | no-result"
+        )]
+        expiry_resolution: ExpiryResolution,
+
+        #[arg(
+            long,
+            default_value_t = 0,
+            help = "Min. percentage of votes the highest ballot needs to win by plurality"
+        )]
+        plurality_floor_bps: u16,
+    },
+    ReallocProgramConfig {},
+    Migrate {},
     UpdateOperatorWhitelist {
         #[arg(short, long, value_delimiter = ',', value_parser = parse_pubkey)]
         add: Option<Vec<Pubkey>>,
 
         #[arg(short, long, value_delimiter = ',', value_parser = parse_pubkey)]
         remove: Option<Vec<Pubkey>>,
+
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_operator_weight, help = "Operator weights as <pubkey>:<weight>")]
+        weights: Option<Vec<OperatorWeight>>,
+    },
+    ProposeOperatorKeyRotation {
+        #[arg(long, value_parser = parse_pubkey, help = "New operator key")]
+        new_operator: Pubkey,
     },
+    AcceptOperatorKeyRotation {
+        #[arg(long, value_parser = parse_pubkey, help = "Operator key being replaced")]
+        old_operator: Pubkey,
+    },
+    CancelOperatorKeyRotation {},
     UpdateProgramConfig {
         #[arg(long, env)]
         proposed_authority: Option<Pubkey>,
@@ -147,22 +264,155 @@ pub enum Commands {
 
         #[arg(long)]
         vote_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Duration after finalization before a ballot box can be closed"
+        )]
+        close_grace_period: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Delay in seconds before queued config and whitelist changes can be applied"
+        )]
+        config_change_delay: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Slot offset within each epoch targeted by scheduled ballot boxes"
+        )]
+        scheduled_slot_offset: Option<u64>,
+
+        #[arg(long, conflicts_with = "scheduled_slot_offset")]
+        disable_scheduled_ballot_boxes: bool,
+
+        #[arg(long, value_parser = parse_pubkey, help = "Key allowed to pause and unpause the program")]
+        guardian: Option<Pubkey>,
+
+        #[arg(
+            long,
+            help = "Max. total duration a ballot box can be open for, including vote extensions"
+        )]
+        max_vote_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Duration of the commit window of new ballot boxes, 0 disables commit-reveal"
+        )]
+        commit_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Min. number of operators that must vote before a ballot can reach consensus"
+        )]
+        min_votes: Option<u16>,
+
+        #[arg(
+            long,
+            value_parser = parse_expiry_resolution,
+            help = "Resolution of expired ballot boxes: tie-breaker-admin | plurality | no-result"
+        )]
+        expiry_resolution: Option<ExpiryResolution>,
+
+        #[arg(
+            long,
+            help = "Min. percentage of votes the highest ballot needs to win by plurality"
+        )]
+        plurality_floor_bps: Option<u16>,
     },
     FinalizeProposedAuthority {},
-    InitBallotBox {},
+    Pause {
+        #[arg(long, help = "Pause ballot box creation, voting and ballot finalization")]
+        balloting: bool,
+
+        #[arg(long, help = "Pause creation, sealing and verification of merkle proofs")]
+        verification: bool,
+    },
+    Unpause {
+        #[arg(long, help = "Unpause ballot box creation, voting and ballot finalization")]
+        balloting: bool,
+
+        #[arg(long, help = "Unpause creation, sealing and verification of merkle proofs")]
+        verification: bool,
+    },
+    ApplyPendingConfig {},
+    CancelPendingConfig {},
+    SetAdmins {
+        #[arg(long, value_delimiter = ',', value_parser = parse_pubkey, help = "Admins of the multisig, empty to disable multisig")]
+        admins: Vec<Pubkey>,
+
+        #[arg(long, help = "Min. number of admin approvals required to execute an admin proposal")]
+        threshold: u8,
+    },
+    CreateAdminProposal {
+        #[command(subcommand)]
+        action: AdminActionCommand,
+    },
+    ApproveAdminProposal {
+        #[arg(long, help = "Id of admin proposal")]
+        id: u64,
+    },
+    ExecuteAdminProposal {
+        #[arg(long, help = "Id of admin proposal")]
+        id: u64,
+    },
+    CancelAdminProposal {
+        #[arg(long, help = "Id of admin proposal")]
+        id: u64,
+    },
+    InitBallotBox {
+        #[arg(long, help = "Slot of the snapshot to vote on")]
+        target_slot: u64,
+    },
+    InitScheduledBallotBox {
+        #[arg(long, help = "Epoch of the scheduled ballot box. Defaults to the current epoch")]
+        epoch: Option<u64>,
+    },
+    MigrateBallotBox {
+        #[arg(long, value_parser = parse_pubkey, help = "Address of a ballot box in the legacy layout")]
+        address: Pubkey,
+    },
     FinalizeBallot {
         #[arg(long, help = "Id of ballot box")]
         id: u64,
     },
+    RecordOperatorStats {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
     CastVote {
         #[arg(long, help = "Id of ballot box")]
         id: u64,
 
-        #[arg(long, value_parser = parse_base_58_32, help = "Meta merkle tree root, base-58 encoded.")]
-        root: [u8; 32],
+        #[command(flatten)]
+        ballot: BallotArgs,
+    },
+    CommitVote {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[command(flatten)]
+        ballot: BallotArgs,
+
+        #[arg(long, value_parser = parse_base_58_32, help = "Salt of the commitment, base-58 encoded. Defaults to a salt derived from the operator key")]
+        salt: Option<[u8; 32]>,
+    },
+    RevealVote {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[command(flatten)]
+        ballot: BallotArgs,
+
+        #[arg(long, value_parser = parse_base_58_32, help = "Salt of the commitment, base-58 encoded. Defaults to a salt derived from the operator key")]
+        salt: Option<[u8; 32]>,
+    },
+    CloseOperatorCommit {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
 
-        #[arg(long, value_parser = parse_base_58_32, help = "SHA256 hash of the meta merkle snapshot, base-58 encoded.")]
-        hash: [u8; 32],
+        #[arg(long, value_parser = parse_pubkey, help = "Operator of the unrevealed commitment")]
+        operator: Pubkey,
     },
     CastVoteFromSnapshot {
         #[arg(long, help = "Id of ballot box")]
@@ -174,6 +424,13 @@ pub enum Commands {
         #[arg(long, default_value = "true")]
         is_compressed: bool,
     },
+    ChangeVote {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[command(flatten)]
+        ballot: BallotArgs,
+    },
     RemoveVote {
         #[arg(long, help = "Id of ballot box")]
         id: u64,
@@ -185,6 +442,77 @@ pub enum Commands {
         #[arg(long, help = "Index in ballot tallies to set as winning ballot")]
         idx: u8,
     },
+    FinalizeExpiredBallot {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
+    ExtendVoteExpiry {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[arg(long, help = "New timestamp when voting ends")]
+        vote_expiry_timestamp: i64,
+    },
+    CancelBallotBox {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
+    CloseBallotBox {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
+    ArchiveConsensusResult {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
+    CreateProposal {
+        #[arg(
+            long,
+            help = "Id of ballot box whose consensus result is used for stake weights"
+        )]
+        id: u64,
+
+        #[arg(long, value_parser = parse_base_58_32, help = "SHA256 hash of the proposal description, base-58 encoded.")]
+        description_hash: [u8; 32],
+
+        #[arg(
+            long,
+            help = "Duration in seconds for which the proposal is open for voting"
+        )]
+        vote_duration: i64,
+
+        #[arg(long, help = "Min. total stake (lamports) that must vote")]
+        quorum_stake: u64,
+
+        #[arg(
+            long,
+            help = "Min. percentage of for stake out of for and against stake"
+        )]
+        pass_threshold_bps: u16,
+    },
+    CastProposalVote {
+        #[arg(long, help = "Id of proposal")]
+        id: u64,
+
+        #[arg(long, env, help = "Path to read meta merkle tree")]
+        read_path: PathBuf,
+
+        #[arg(long, default_value = "true")]
+        is_compressed: bool,
+
+        #[arg(long, value_parser = parse_pubkey, help = "Vote account to vote with, or that the stake account is delegated to")]
+        vote_account: Pubkey,
+
+        #[arg(long, value_parser = parse_pubkey, help = "Stake account to vote with")]
+        stake_account: Option<Pubkey>,
+
+        #[arg(long, value_parser = parse_vote_choice, help = "Vote choice: for | against | abstain")]
+        choice: VoteChoice,
+    },
+    FinalizeProposal {
+        #[arg(long, help = "Id of proposal")]
+        id: u64,
+    },
     Log {
         #[arg(long, help = "Id of ballot box to fetch")]
         id: Option<u64>,
@@ -192,9 +520,224 @@ pub enum Commands {
         #[arg(long, value_parser = parse_pubkey)]
         vote_account: Option<Pubkey>,
 
-        #[arg(long, value_parser = parse_log_type, help = "Account type: program-config | ballot-box | consensus-result | proof")]
+        #[arg(long, value_parser = parse_log_type, help = "Account type: program-config | ballot-box | consensus-result | proof | proposal | admin-proposal")]
         ty: LogType,
     },
+    DecodeEvents {
+        #[arg(long, value_parser = parse_signature, help = "Signature of the transaction to decode events from")]
+        signature: Signature,
+    },
+    OperatorStatsReport {
+        #[arg(long, value_parser = parse_pubkey, help = "Only report the stats of this operator")]
+        operator: Option<Pubkey>,
+    },
+}
+
+/// Action of an admin proposal, mirrors the single-authority instructions.
+#[derive(clap::Subcommand, Clone)]
+pub enum AdminActionCommand {
+    UpdateOperatorWhitelist {
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_pubkey)]
+        add: Option<Vec<Pubkey>>,
+
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_pubkey)]
+        remove: Option<Vec<Pubkey>>,
+
+        #[arg(short, long, value_delimiter = ',', value_parser = parse_operator_weight, help = "Operator weights as <pubkey>:<weight>")]
+        weights: Option<Vec<OperatorWeight>>,
+    },
+    UpdateProgramConfig {
+        #[arg(long, value_parser = parse_pubkey)]
+        proposed_authority: Option<Pubkey>,
+
+        #[arg(long)]
+        min_consensus_threshold_bps: Option<u16>,
+
+        #[arg(long, value_parser = parse_pubkey)]
+        tie_breaker_admin: Option<Pubkey>,
+
+        #[arg(long)]
+        vote_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Duration after finalization before a ballot box can be closed"
+        )]
+        close_grace_period: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Delay in seconds before queued config and whitelist changes can be applied"
+        )]
+        config_change_delay: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Slot offset within each epoch targeted by scheduled ballot boxes"
+        )]
+        scheduled_slot_offset: Option<u64>,
+
+        #[arg(long, conflicts_with = "scheduled_slot_offset")]
+        disable_scheduled_ballot_boxes: bool,
+
+        #[arg(long, value_parser = parse_pubkey, help = "Key allowed to pause and unpause the program")]
+        guardian: Option<Pubkey>,
+
+        #[arg(
+            long,
+            help = "Max. total duration a ballot box can be open for, including vote extensions"
+        )]
+        max_vote_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Duration of the commit window of new ballot boxes, 0 disables commit-reveal"
+        )]
+        commit_duration: Option<i64>,
+
+        #[arg(
+            long,
+            help = "Min. number of operators that must vote before a ballot can reach consensus"
+        )]
+        min_votes: Option<u16>,
+
+        #[arg(
+            long,
+            value_parser = parse_expiry_resolution,
+            help = "Resolution of expired ballot boxes: tie-breaker-admin | plurality | no-result"
+        )]
+        expiry_resolution: Option<ExpiryResolution>,
+
+        #[arg(
+            long,
+            help = "Min. percentage of votes the highest ballot needs to win by plurality"
+        )]
+        plurality_floor_bps: Option<u16>,
+    },
+    SetTieBreaker {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[arg(long, help = "Index in ballot tallies to set as winning ballot")]
+        idx: u8,
+    },
+    ExtendVoteExpiry {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+
+        #[arg(long, help = "New timestamp when voting ends")]
+        vote_expiry_timestamp: i64,
+    },
+    CancelBallotBox {
+        #[arg(long, help = "Id of ballot box")]
+        id: u64,
+    },
+    CancelPendingConfig,
+    SetAdmins {
+        #[arg(long, value_delimiter = ',', value_parser = parse_pubkey, help = "Admins of the multisig, empty to disable multisig")]
+        admins: Vec<Pubkey>,
+
+        #[arg(long, help = "Min. number of admin approvals required to execute an admin proposal")]
+        threshold: u8,
+    },
+}
+
+impl From<AdminActionCommand> for AdminAction {
+    fn from(command: AdminActionCommand) -> Self {
+        match command {
+            AdminActionCommand::UpdateOperatorWhitelist {
+                add,
+                remove,
+                weights,
+            } => AdminAction::UpdateOperatorWhitelist(OperatorWhitelistUpdate {
+                operators_to_add: add,
+                operators_to_remove: remove,
+                operator_weights: weights,
+            }),
+            AdminActionCommand::UpdateProgramConfig {
+                proposed_authority,
+                min_consensus_threshold_bps,
+                tie_breaker_admin,
+                vote_duration,
+                close_grace_period,
+                config_change_delay,
+                scheduled_slot_offset,
+                disable_scheduled_ballot_boxes,
+                guardian,
+                max_vote_duration,
+                commit_duration,
+                min_votes,
+                expiry_resolution,
+                plurality_floor_bps,
+            } => AdminAction::UpdateProgramConfig(ProgramConfigUpdate {
+                proposed_authority,
+                min_consensus_threshold_bps,
+                tie_breaker_admin,
+                vote_duration,
+                close_grace_period,
+                config_change_delay,
+                scheduled_slot_offset: scheduled_slot_offset_update(
+                    scheduled_slot_offset,
+                    disable_scheduled_ballot_boxes,
+                ),
+                guardian,
+                max_vote_duration,
+                commit_duration,
+                min_votes,
+                expiry_resolution,
+                plurality_floor_bps,
+            }),
+            AdminActionCommand::SetTieBreaker { id, idx } => AdminAction::SetTieBreaker {
+                ballot_id: id,
+                ballot_index: idx,
+            },
+            AdminActionCommand::ExtendVoteExpiry {
+                id,
+                vote_expiry_timestamp,
+            } => AdminAction::ExtendVoteExpiry {
+                ballot_id: id,
+                vote_expiry_timestamp,
+            },
+            AdminActionCommand::CancelBallotBox { id } => {
+                AdminAction::CancelBallotBox { ballot_id: id }
+            }
+            AdminActionCommand::CancelPendingConfig => AdminAction::CancelPendingConfig,
+            AdminActionCommand::SetAdmins { admins, threshold } => {
+                AdminAction::SetAdmins { admins, threshold }
+            }
+        }
+    }
+}
+
+/// Maps the scheduled slot offset arguments to the update: Some(None) disables scheduled
+/// ballot boxes, None leaves them unchanged.
+fn scheduled_slot_offset_update(offset: Option<u64>, disable: bool) -> Option<Option<u64>> {
+    if disable {
+        Some(None)
+    } else {
+        offset.map(Some)
+    }
+}
+
+/// Derives the commit-reveal salt for a ballot box from a signature of the operator, so
+/// the salt doesn't have to be stored between commit and reveal.
+fn derive_commit_salt(operator: &Keypair, ballot_box: &Pubkey) -> [u8; 32] {
+    let signature = operator.sign_message(ballot_box.as_ref());
+    solana_sdk::hash::hash(signature.as_ref()).to_bytes()
+}
+
+/// Maps the pause scope flags to the pause flag updates. If no scope is given, both
+/// balloting and verification are updated.
+fn pause_update(
+    balloting: bool,
+    verification: bool,
+    paused: bool,
+) -> (Option<bool>, Option<bool>) {
+    let all = !balloting && !verification;
+    (
+        (all || balloting).then_some(paused),
+        (all || verification).then_some(paused),
+    )
 }
 
 fn main() -> Result<()> {
@@ -215,7 +758,7 @@ fn main() -> Result<()> {
         client.program(gov_v1::id()).unwrap()
     }
 
-    fn cast_vote_shared(cli: Cli, id: u64, root: [u8; 32], hash: [u8; 32]) -> Result<()> {
+    fn cast_vote_shared(cli: Cli, id: u64, ballot: Ballot) -> Result<()> {
         let payer = read_keypair_file(&cli.payer_path).unwrap();
         let authority = read_keypair_file(&cli.authority_path).unwrap();
         let program = load_client_program(&payer, cli.rpc_url);
@@ -226,20 +769,46 @@ fn main() -> Result<()> {
             payer: &payer,
             authority: &authority,
         };
-        let ballot_box_pda = BallotBox::pda(id).0;
-        let tx = send_cast_vote(
-            tx_sender,
-            ballot_box_pda,
-            Ballot {
-                meta_merkle_root: root,
-                snapshot_hash: hash,
-            },
-        )?;
+        let ballot_box_pda = find_ballot_box(&program, id)?;
+        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot)?;
         info!("Transaction sent: {}", tx);
 
         info!("== Voted For Ballot Box {:?} ==", id);
-        info!("Merkle Root: {}", bs58::encode(root).into_string());
-        info!("Snapshot Hash: {}", bs58::encode(hash).into_string());
+        log_ballot(&ballot);
+
+        Ok(())
+    }
+
+    fn log_ballot(ballot: &Ballot) {
+        info!("Merkle Root: {}", bs58::encode(ballot.meta_merkle_root).into_string());
+        info!("Snapshot Hash: {}", bs58::encode(ballot.snapshot_hash).into_string());
+        info!("Snapshot Slot: {}", ballot.snapshot_slot);
+        info!("Total Stake: {}", ballot.total_active_stake);
+        info!("Vote Accounts: {}", ballot.vote_account_count);
+        info!("Stake Accounts: {}", ballot.stake_account_count);
+    }
+
+    fn set_paused_shared(
+        cli: Cli,
+        balloting_paused: Option<bool>,
+        verification_paused: Option<bool>,
+    ) -> Result<()> {
+        let payer = read_keypair_file(&cli.payer_path).unwrap();
+        let authority = read_keypair_file(&cli.authority_path).unwrap();
+        let program = load_client_program(&payer, cli.rpc_url);
+
+        let tx_sender = &TxSender {
+            program: &program,
+            micro_lamports: cli.micro_lamports,
+            payer: &payer,
+            authority: &authority,
+        };
+        let tx = send_set_paused(tx_sender, balloting_paused, verification_paused)?;
+        info!("Transaction sent: {}", tx);
+
+        let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+        info!("Balloting Paused: {}", program_config.balloting_paused);
+        info!("Verification Paused: {}", program_config.verification_paused);
 
         Ok(())
     }
@@ -260,8 +829,9 @@ fn main() -> Result<()> {
                     println!("{:?}", data);
                 }
                 LogType::BallotBox => {
-                    let data: BallotBox =
-                        program.account(BallotBox::pda(id.expect("Missing --id argument")).0)?;
+                    let ballot_box_pda =
+                        find_ballot_box(&program, id.expect("Missing --id argument"))?;
+                    let data: BallotBox = program.account(ballot_box_pda)?;
                     println!("{:?}", data);
                 }
                 LogType::ConsensusResult => {
@@ -281,10 +851,391 @@ fn main() -> Result<()> {
                     )?;
                     println!("{:?}", data);
                 }
+                LogType::Proposal => {
+                    let data: Proposal =
+                        program.account(Proposal::pda(id.expect("Missing --id argument")).0)?;
+                    println!("{:?}", data);
+                }
+                LogType::AdminProposal => {
+                    let data: AdminProposal = program
+                        .account(AdminProposal::pda(id.expect("Missing --id argument")).0)?;
+                    println!("{:?}", data);
+                }
+            }
+        }
+        Commands::DecodeEvents { signature } => {
+            let temp = Keypair::new();
+            let program = load_client_program(&temp, cli.rpc_url);
+
+            for event in fetch_events(&program, signature)? {
+                println!("{:?}", event);
+            }
+        }
+        Commands::OperatorStatsReport { operator } => {
+            let temp = Keypair::new();
+            let program = load_client_program(&temp, cli.rpc_url);
+
+            let mut stats: Vec<OperatorStats> = program
+                .accounts::<OperatorStats>(vec![])?
+                .into_iter()
+                .map(|(_, stats)| stats)
+                .filter(|stats| operator.is_none() || operator == Some(stats.operator))
+                .collect();
+            stats.sort_by_key(|stats| std::cmp::Reverse(stats.votes_cast));
+
+            println!(
+                "{:<44} {:>8} {:>8} {:>8} {:>10} {:>12}",
+                "Operator", "Seen", "Cast", "Matching", "Agreement", "Avg Latency"
+            );
+            for stats in stats {
+                let agreement = if stats.ballots_seen == 0 {
+                    "-".to_string()
+                } else {
+                    format!(
+                        "{:.2}%",
+                        stats.votes_matching_consensus as f64 * 100.0 / stats.ballots_seen as f64
+                    )
+                };
+                println!(
+                    "{:<44} {:>8} {:>8} {:>8} {:>10} {:>12}",
+                    stats.operator.to_string(),
+                    stats.ballots_seen,
+                    stats.votes_cast,
+                    stats.votes_matching_consensus,
+                    agreement,
+                    stats.average_vote_latency_slots()
+                );
+            }
+        }
+        Commands::InitProgramConfig {
+            min_consensus_threshold_bps,
+            min_votes,
+            tie_breaker_admin,
+            vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            expiry_resolution,
+            plurality_floor_bps,
+        } => {
+            info!("InitProgramConfig...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_init_program_config(
+                tx_sender,
+                min_consensus_threshold_bps,
+                min_votes,
+                tie_breaker_admin,
+                vote_duration,
+                close_grace_period,
+                config_change_delay,
+                scheduled_slot_offset,
+                guardian.unwrap_or_default(),
+                max_vote_duration,
+                commit_duration,
+                expiry_resolution,
+                plurality_floor_bps,
+            )?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::ReallocProgramConfig {} => {
+            info!("ReallocProgramConfig...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_realloc_program_config(tx_sender)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::Migrate {} => {
+            info!("Migrate...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let accounts = find_accounts_to_migrate(&program)?;
+            info!("Accounts to migrate: {}", accounts.len());
+            for (address, migration) in accounts {
+                let tx = match migration {
+                    AccountMigration::LegacyBallotBox => {
+                        send_migrate_ballot_box(tx_sender, address)?
+                    }
+                    AccountMigration::Account => send_migrate_account(tx_sender, address)?,
+                };
+                info!("Migrated {}: {}", address, tx);
+            }
+        }
+        Commands::UpdateOperatorWhitelist {
+            add,
+            remove,
+            weights,
+        } => {
+            info!("UpdateOperatorWhitelist...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_update_operator_whitelist(tx_sender, add, remove, weights)?;
+            info!("Transaction sent: {}", tx);
+
+            let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+            if let Some(pending_whitelist) = program_config.pending_whitelist {
+                info!(
+                    "Pending whitelist effective at: {}",
+                    pending_whitelist.effective_at
+                );
+            }
+        }
+        Commands::ProposeOperatorKeyRotation { new_operator } => {
+            info!("ProposeOperatorKeyRotation...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_propose_operator_key_rotation(tx_sender, new_operator)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::AcceptOperatorKeyRotation { old_operator } => {
+            info!("AcceptOperatorKeyRotation...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let operator_key_rotation: OperatorKeyRotation =
+                program.account(OperatorKeyRotation::pda(&old_operator).0)?;
+            let ballot_boxes = find_open_ballot_boxes(&program)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_accept_operator_key_rotation(
+                tx_sender,
+                old_operator,
+                operator_key_rotation.payer,
+                &ballot_boxes,
+            )?;
+            info!("Transaction sent: {}", tx);
+            info!("Passed {} open ballot boxes", ballot_boxes.len());
+        }
+        Commands::CancelOperatorKeyRotation {} => {
+            info!("CancelOperatorKeyRotation...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let operator_key_rotation: OperatorKeyRotation =
+                program.account(OperatorKeyRotation::pda(&authority.pubkey()).0)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_cancel_operator_key_rotation(tx_sender, operator_key_rotation.payer)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::UpdateProgramConfig {
+            proposed_authority,
+            min_consensus_threshold_bps,
+            tie_breaker_admin,
+            vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            disable_scheduled_ballot_boxes,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            min_votes,
+            expiry_resolution,
+            plurality_floor_bps,
+        } => {
+            info!("UpdateProgramConfig...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_update_program_config(
+                tx_sender,
+                proposed_authority,
+                min_consensus_threshold_bps,
+                tie_breaker_admin,
+                vote_duration,
+                close_grace_period,
+                config_change_delay,
+                scheduled_slot_offset_update(
+                    scheduled_slot_offset,
+                    disable_scheduled_ballot_boxes,
+                ),
+                guardian,
+                max_vote_duration,
+                commit_duration,
+                min_votes,
+                expiry_resolution,
+                plurality_floor_bps,
+            )?;
+            info!("Transaction sent: {}", tx);
+
+            let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+            if let Some(pending_config) = program_config.pending_config {
+                info!("Pending config effective at: {}", pending_config.effective_at);
             }
         }
-        Commands::InitProgramConfig {} => {
-            info!("InitProgramConfig...");
+        Commands::FinalizeProposedAuthority {} => {
+            info!("FinalizeProposedAuthority...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_finalize_proposed_authority(tx_sender)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::Pause {
+            balloting,
+            verification,
+        } => {
+            info!("Pause...");
+            let (balloting_paused, verification_paused) =
+                pause_update(balloting, verification, true);
+            set_paused_shared(cli, balloting_paused, verification_paused)?;
+        }
+        Commands::Unpause {
+            balloting,
+            verification,
+        } => {
+            info!("Unpause...");
+            let (balloting_paused, verification_paused) =
+                pause_update(balloting, verification, false);
+            set_paused_shared(cli, balloting_paused, verification_paused)?;
+        }
+        Commands::ApplyPendingConfig {} => {
+            info!("ApplyPendingConfig...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_apply_pending_config(tx_sender)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CancelPendingConfig {} => {
+            info!("CancelPendingConfig...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_cancel_pending_config(tx_sender)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::SetAdmins { admins, threshold } => {
+            info!("SetAdmins...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_set_admins(tx_sender, admins, threshold)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CreateAdminProposal { action } => {
+            info!("CreateAdminProposal...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_create_admin_proposal(
+                tx_sender,
+                program_config.next_admin_proposal_id,
+                action.into(),
+            )?;
+            info!("Transaction sent: {}", tx);
+            info!("Admin Proposal Id: {}", program_config.next_admin_proposal_id);
+        }
+        Commands::ApproveAdminProposal { id } => {
+            info!("ApproveAdminProposal...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
@@ -296,11 +1247,36 @@ fn main() -> Result<()> {
                 payer: &payer,
                 authority: &authority,
             };
-            let tx = send_init_program_config(tx_sender)?;
+            let tx = send_approve_admin_proposal(tx_sender, id)?;
             info!("Transaction sent: {}", tx);
         }
-        Commands::UpdateOperatorWhitelist { add, remove } => {
-            info!("UpdateOperatorWhitelist...");
+        Commands::ExecuteAdminProposal { id } => {
+            info!("ExecuteAdminProposal...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let admin_proposal: AdminProposal = program.account(AdminProposal::pda(id).0)?;
+            let ballot_box = match admin_proposal.action {
+                AdminAction::SetTieBreaker { ballot_id, .. }
+                | AdminAction::ExtendVoteExpiry { ballot_id, .. }
+                | AdminAction::CancelBallotBox { ballot_id } => {
+                    Some(find_ballot_box(&program, ballot_id)?)
+                }
+                _ => None,
+            };
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx =
+                send_execute_admin_proposal(tx_sender, id, admin_proposal.proposer, ballot_box)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CancelAdminProposal { id } => {
+            info!("CancelAdminProposal...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
@@ -312,62 +1288,109 @@ fn main() -> Result<()> {
                 payer: &payer,
                 authority: &authority,
             };
-            let tx = send_update_operator_whitelist(tx_sender, add, remove)?;
+            let tx = send_cancel_admin_proposal(tx_sender, id)?;
             info!("Transaction sent: {}", tx);
         }
-        Commands::UpdateProgramConfig {
-            proposed_authority,
-            min_consensus_threshold_bps,
-            tie_breaker_admin,
-            vote_duration,
-        } => {
-            info!("UpdateProgramConfig...");
+        Commands::InitBallotBox { target_slot } => {
+            info!("InitBallotBox...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
 
+            let program_config_pda = ProgramConfig::pda().0;
+            let program_config: ProgramConfig = program.account(program_config_pda)?;
+            let ballot_box_pda = BallotBox::pda(program_config.next_ballot_id).0;
+
             let tx_sender = &TxSender {
                 program: &program,
                 micro_lamports: cli.micro_lamports,
                 payer: &payer,
                 authority: &authority,
             };
-            let tx = send_update_program_config(
-                tx_sender,
-                proposed_authority,
-                min_consensus_threshold_bps,
-                tie_breaker_admin,
-                vote_duration,
-            )?;
+            let tx = send_init_ballot_box(tx_sender, ballot_box_pda, target_slot)?;
             info!("Transaction sent: {}", tx);
         }
-        Commands::FinalizeProposedAuthority {} => {
-            info!("FinalizeProposedAuthority...");
+        Commands::InitScheduledBallotBox { epoch } => {
+            info!("InitScheduledBallotBox...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+            let scheduled_slot_offset = program_config
+                .scheduled_slot_offset
+                .ok_or_else(|| anyhow!("Scheduled ballot boxes are disabled"))?;
+            let epoch = match epoch {
+                Some(epoch) => epoch,
+                None => program.rpc().get_epoch_info()?.epoch,
+            };
+            let target_slot = program
+                .rpc()
+                .get_epoch_schedule()?
+                .get_first_slot_in_epoch(epoch)
+                + scheduled_slot_offset;
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_init_scheduled_ballot_box(tx_sender, epoch, target_slot)?;
+            info!("Transaction sent: {}", tx);
+            info!("Ballot Box Id: {}", program_config.next_ballot_id);
+            info!("Target Slot: {}", target_slot);
+        }
+        Commands::MigrateBallotBox { address } => {
+            info!("MigrateBallotBox...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_migrate_ballot_box(tx_sender, address)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CastVote { id, ballot } => cast_vote_shared(cli, id, ballot.into())?,
+        Commands::CommitVote { id, ballot, salt } => {
+            info!("CommitVote...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
 
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+            let ballot = Ballot::from(ballot);
+            let salt = salt.unwrap_or_else(|| derive_commit_salt(&authority, &ballot_box_pda));
+            let commitment = ballot.commitment(&authority.pubkey(), &ballot_box_pda, &salt);
+
             let tx_sender = &TxSender {
                 program: &program,
                 micro_lamports: cli.micro_lamports,
                 payer: &payer,
                 authority: &authority,
             };
-            let tx = send_finalize_proposed_authority(tx_sender)?;
+            let tx = send_commit_vote(tx_sender, ballot_box_pda, commitment)?;
             info!("Transaction sent: {}", tx);
+            info!("Commitment: {}", bs58::encode(commitment).into_string());
         }
-        Commands::InitBallotBox {} => {
-            info!("InitBallotBox...");
+        Commands::RevealVote { id, ballot, salt } => {
+            info!("RevealVote...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
 
-            let program_config_pda = ProgramConfig::pda().0;
-            let program_config: ProgramConfig = program.account(program_config_pda)?;
-            let ballot_box_pda = BallotBox::pda(program_config.next_ballot_id).0;
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+            let operator_commit: OperatorCommit = program
+                .account(OperatorCommit::pda(&ballot_box_pda, &authority.pubkey()).0)?;
+            let salt = salt.unwrap_or_else(|| derive_commit_salt(&authority, &ballot_box_pda));
 
             let tx_sender = &TxSender {
                 program: &program,
@@ -375,10 +1398,41 @@ fn main() -> Result<()> {
                 payer: &payer,
                 authority: &authority,
             };
-            let tx = send_init_ballot_box(tx_sender, ballot_box_pda)?;
+            let tx = send_reveal_vote(
+                tx_sender,
+                ballot_box_pda,
+                ballot.into(),
+                salt,
+                operator_commit.payer,
+            )?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CloseOperatorCommit { id, operator } => {
+            info!("CloseOperatorCommit...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            // The ballot box may already be closed.
+            let ballot_box_pda =
+                find_ballot_box(&program, id).unwrap_or_else(|_| BallotBox::pda(id).0);
+            let operator_commit: OperatorCommit =
+                program.account(OperatorCommit::pda(&ballot_box_pda, &operator).0)?;
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_close_operator_commit(
+                tx_sender,
+                ballot_box_pda,
+                operator,
+                operator_commit.payer,
+            )?;
             info!("Transaction sent: {}", tx);
         }
-        Commands::CastVote { id, root, hash } => cast_vote_shared(cli, id, root, hash)?,
         Commands::CastVoteFromSnapshot {
             id,
             ref read_path,
@@ -387,9 +1441,43 @@ fn main() -> Result<()> {
             let snapshot = MetaMerkleSnapshot::read(read_path.clone(), is_compressed)?;
             info!("Using snapshot for slot {}", snapshot.slot);
 
+            // Refuse to vote with a snapshot of a different slot than the ballot box targets.
+            let temp = Keypair::new();
+            let program = load_client_program(&temp, cli.rpc_url.clone());
+            let ballot_box: BallotBox = program.account(find_ballot_box(&program, id)?)?;
+            if snapshot.slot != ballot_box.target_slot {
+                return Err(anyhow!(
+                    "Snapshot slot {} does not match target slot {} of ballot box {}",
+                    snapshot.slot,
+                    ballot_box.target_slot,
+                    id
+                ));
+            }
+
             let snapshot_hash =
                 MetaMerkleSnapshot::snapshot_hash(read_path.clone(), is_compressed)?;
-            cast_vote_shared(cli, id, snapshot.root, snapshot_hash.to_bytes())?;
+            cast_vote_shared(cli, id, snapshot.ballot(snapshot_hash))?;
+        }
+        Commands::ChangeVote { id, ballot } => {
+            info!("ChangeVote...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let ballot = Ballot::from(ballot);
+            let tx = send_change_vote(tx_sender, ballot_box_pda, ballot)?;
+            info!("Transaction sent: {}", tx);
+
+            info!("== Changed Vote For Ballot Box {:?} ==", id);
+            log_ballot(&ballot);
         }
         Commands::RemoveVote { id } => {
             info!("RemoveVote...");
@@ -398,7 +1486,7 @@ fn main() -> Result<()> {
             let authority = read_keypair_file(&cli.authority_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
 
-            let ballot_box_pda = BallotBox::pda(id).0;
+            let ballot_box_pda = find_ballot_box(&program, id)?;
             let tx_sender = &TxSender {
                 program: &program,
                 micro_lamports: cli.micro_lamports,
@@ -414,7 +1502,7 @@ fn main() -> Result<()> {
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let authority = read_keypair_file(&cli.authority_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
-            let ballot_box_pda = BallotBox::pda(id).0;
+            let ballot_box_pda = find_ballot_box(&program, id)?;
 
             let tx_sender = &TxSender {
                 program: &program,
@@ -425,13 +1513,74 @@ fn main() -> Result<()> {
             let tx = send_set_tie_breaker(tx_sender, ballot_box_pda, idx)?;
             info!("Transaction sent: {}", tx);
         }
+        Commands::FinalizeExpiredBallot { id } => {
+            info!("FinalizeExpiredBallot...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_finalize_expired_ballot(tx_sender, ballot_box_pda)?;
+            info!("Transaction sent: {}", tx);
+
+            let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+            if ballot_box.is_failed() {
+                info!("Ballot box failed, a new ballot box must be created");
+            } else {
+                info!("Winning ballot selected by plurality, run finalize-ballot to finalize");
+                log_ballot(&ballot_box.winning_ballot);
+            }
+        }
+        Commands::ExtendVoteExpiry {
+            id,
+            vote_expiry_timestamp,
+        } => {
+            info!("ExtendVoteExpiry...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_extend_vote_expiry(tx_sender, ballot_box_pda, vote_expiry_timestamp)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CancelBallotBox { id } => {
+            info!("CancelBallotBox...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_cancel_ballot_box(tx_sender, ballot_box_pda)?;
+            info!("Transaction sent: {}", tx);
+        }
         Commands::FinalizeBallot { id } => {
             info!("FinalizeBallot...");
 
             let payer = read_keypair_file(&cli.payer_path).unwrap();
             let program = load_client_program(&payer, cli.rpc_url);
 
-            let ballot_box_pda = BallotBox::pda(id).0;
+            let ballot_box_pda = find_ballot_box(&program, id)?;
             let consensus_result_pda = ConsensusResult::pda(id).0;
             let tx_sender = &TxSender {
                 program: &program,
@@ -441,6 +1590,205 @@ fn main() -> Result<()> {
             };
             let tx = send_finalize_ballot(tx_sender, ballot_box_pda, consensus_result_pda)?;
             info!("Transaction sent: {}", tx);
+            let txs = send_pending_operator_stats(tx_sender, ballot_box_pda)?;
+            info!("Operator stats recorded: {:?}", txs);
+
+            let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
+            info!("Decision Method: {:?}", consensus_result.decision_method);
+            info!(
+                "Tally: {}/{} ({} bps)",
+                consensus_result.tally, consensus_result.total_weight, consensus_result.tally_bps
+            );
+        }
+        Commands::RecordOperatorStats { id } => {
+            info!("RecordOperatorStats...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let txs = send_pending_operator_stats(tx_sender, ballot_box_pda)?;
+            info!("Transactions sent: {:?}", txs);
+        }
+        Commands::CloseBallotBox { id } => {
+            info!("CloseBallotBox...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let ballot_box_pda = find_ballot_box(&program, id)?;
+            let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_close_ballot_box(tx_sender, ballot_box_pda, ballot_box.payer)?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::ArchiveConsensusResult { id } => {
+            info!("ArchiveConsensusResult...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let consensus_result_pda = ConsensusResult::pda(id).0;
+            let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_archive_consensus_result(
+                tx_sender,
+                consensus_result_pda,
+                consensus_result.payer,
+            )?;
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::CreateProposal {
+            id,
+            description_hash,
+            vote_duration,
+            quorum_stake,
+            pass_threshold_bps,
+        } => {
+            info!("CreateProposal...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let program_config: ProgramConfig = program.account(ProgramConfig::pda().0)?;
+            let proposal_pda = Proposal::pda(program_config.next_proposal_id).0;
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+            let tx = send_create_proposal(
+                tx_sender,
+                proposal_pda,
+                ConsensusResult::pda(id).0,
+                description_hash,
+                vote_duration,
+                quorum_stake,
+                pass_threshold_bps,
+            )?;
+            info!("Transaction sent: {}", tx);
+            info!("Proposal Id: {}", program_config.next_proposal_id);
+        }
+        Commands::CastProposalVote {
+            id,
+            ref read_path,
+            is_compressed,
+            vote_account,
+            stake_account,
+            choice,
+        } => {
+            info!("CastProposalVote...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let authority = read_keypair_file(&cli.authority_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url.clone());
+
+            let proposal_pda = Proposal::pda(id).0;
+            let proposal: Proposal = program.account(proposal_pda)?;
+            let snapshot = MetaMerkleSnapshot::read(read_path.clone(), is_compressed)?;
+            let bundle = snapshot
+                .leaf_bundles
+                .into_iter()
+                .find(|bundle| bundle.meta_merkle_leaf.vote_account == vote_account)
+                .ok_or_else(|| anyhow!("Vote account {} not found in snapshot", vote_account))?;
+            let meta_merkle_leaf = bundle.meta_merkle_leaf.clone();
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &authority,
+            };
+
+            // Initialize MetaMerkleProof for the vote account if it doesn't exist yet.
+            let meta_merkle_proof_pda =
+                MetaMerkleProof::pda(&proposal.consensus_result, &vote_account).0;
+            if program.rpc().get_account(&meta_merkle_proof_pda).is_err() {
+                let meta_merkle_proof = bundle.proof.clone().unwrap();
+                // Proofs that don't fit in a single transaction are appended in chunks.
+                let send_init = if meta_merkle_proof.len() > META_MERKLE_PROOF_CHUNK_SIZE {
+                    send_init_meta_merkle_proof_in_chunks
+                } else {
+                    send_init_meta_merkle_proof
+                };
+                let tx = send_init(
+                    tx_sender,
+                    meta_merkle_proof_pda,
+                    proposal.consensus_result,
+                    meta_merkle_leaf.clone(),
+                    meta_merkle_proof,
+                    proposal.vote_expiry_timestamp,
+                )?;
+                info!("MetaMerkleProof initialized: {}", tx);
+            }
+
+            // Stake account votes override the vote account's vote for the delegated stake.
+            let tx = match stake_account {
+                Some(stake_account) => {
+                    let index = bundle
+                        .stake_merkle_leaves
+                        .iter()
+                        .position(|leaf| leaf.stake_account == stake_account)
+                        .ok_or_else(|| {
+                            anyhow!("Stake account {} not found in snapshot", stake_account)
+                        })?;
+                    send_cast_delegator_vote(
+                        tx_sender,
+                        proposal_pda,
+                        proposal.consensus_result,
+                        &meta_merkle_leaf,
+                        choice,
+                        bundle.get_stake_merkle_proof(index),
+                        bundle.stake_merkle_leaves[index].clone(),
+                    )?
+                }
+                None => send_cast_proposal_vote(
+                    tx_sender,
+                    proposal_pda,
+                    proposal.consensus_result,
+                    &meta_merkle_leaf,
+                    choice,
+                )?,
+            };
+            info!("Transaction sent: {}", tx);
+        }
+        Commands::FinalizeProposal { id } => {
+            info!("FinalizeProposal...");
+
+            let payer = read_keypair_file(&cli.payer_path).unwrap();
+            let program = load_client_program(&payer, cli.rpc_url);
+
+            let tx_sender = &TxSender {
+                program: &program,
+                micro_lamports: cli.micro_lamports,
+                payer: &payer,
+                authority: &payer,
+            };
+            let tx = send_finalize_proposal(tx_sender, id)?;
+            info!("Transaction sent: {}", tx);
+
+            let proposal: Proposal = program.account(Proposal::pda(id).0)?;
+            info!("Proposal Status: {:?}", proposal.status);
         }
         // === Snapshot Processing ===
         Commands::SnapshotSlot { slot } => {
@@ -456,16 +1804,14 @@ fn main() -> Result<()> {
             } = cli.get_snapshot_paths();
 
             get_bank_from_ledger(
-                cli.operator_address,
                 &ledger_path,
                 account_paths,
                 full_snapshots_path,
                 backup_snapshots_dir.clone(),
-                &slot,
+                slot,
                 save_snapshot,
-                backup_snapshots_dir,
-                &cli.cluster,
-            );
+                &backup_snapshots_dir,
+            )?;
         }
         Commands::GenerateMetaMerkle {
             slot,
@@ -510,6 +1856,7 @@ fn main() -> Result<()> {
             let snapshot = MetaMerkleSnapshot::read(read_path.clone(), is_compressed)?;
             let snapshot_hash = MetaMerkleSnapshot::snapshot_hash(read_path, is_compressed)?;
 
+            let ballot = snapshot.ballot(snapshot_hash);
             let encoded_root = bs58::encode(snapshot.root).into_string();
             let encoded_hash = bs58::encode(snapshot_hash.to_bytes()).into_string();
 
@@ -522,6 +1869,9 @@ fn main() -> Result<()> {
             println!("Slot: {}", snapshot.slot);
             println!("Merkle Root: {}", encoded_root);
             println!("Snapshot Hash: {}", encoded_hash);
+            println!("Total Stake: {}", ballot.total_active_stake);
+            println!("Vote Accounts: {}", ballot.vote_account_count);
+            println!("Stake Accounts: {}", ballot.stake_account_count);
         }
         Commands::AwaitSnapshot {
             scan_interval,
@@ -667,16 +2017,14 @@ fn main() -> Result<()> {
                         let save_snapshot = true;
                         let account_paths = vec![backup_ledger_dir.clone()];
                         get_bank_from_ledger(
-                            cli.operator_address,
                             &backup_ledger_dir,
                             account_paths,
                             backup_snapshots_dir.clone(),
                             backup_snapshots_dir.clone(),
-                            &slot,
+                            slot,
                             save_snapshot,
-                            backup_snapshots_dir.clone(),
-                            &cli.cluster,
-                        );
+                            &backup_snapshots_dir,
+                        )?;
 
                         if generate_meta_merkle {
                             info!("Generating MetaMerkleSnapshot for slot {}...", slot);
@@ -1,8 +1,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use flate2::{write::GzEncoder, Compression};
-use gov_v1::{MetaMerkleLeaf, StakeMerkleLeaf};
+use gov_merkle::MerkleTree;
+use gov_v1::{Ballot, MetaMerkleLeaf, StakeMerkleLeaf};
 use crate::utils::{decompress_gzip_with_limit, max_snapshot_bytes, read_all_with_limit};
-use meta_merkle_tree::{merkle_tree::MerkleTree, utils::get_proof};
 use solana_sdk::hash::{hash, Hash};
 use std::fs::File;
 use std::io::{self, Write};
@@ -64,6 +64,28 @@ impl MetaMerkleSnapshot {
         Self::try_from_slice(&buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     }
 
+    /// Builds the Ballot operators vote for with this snapshot.
+    pub fn ballot(&self, snapshot_hash: Hash) -> Ballot {
+        Ballot {
+            meta_merkle_root: self.root,
+            snapshot_hash: snapshot_hash.to_bytes(),
+            snapshot_slot: self.slot,
+            total_active_stake: self
+                .leaf_bundles
+                .iter()
+                .map(|bundle| bundle.meta_merkle_leaf.active_stake)
+                .sum(),
+            vote_account_count: self.leaf_bundles.len().try_into().unwrap(),
+            stake_account_count: self
+                .leaf_bundles
+                .iter()
+                .map(|bundle| bundle.stake_merkle_leaves.len())
+                .sum::<usize>()
+                .try_into()
+                .unwrap(),
+        }
+    }
+
     pub fn snapshot_hash(path: PathBuf, is_compressed: bool) -> io::Result<Hash> {
         let file = File::open(path)?;
         let buf = if is_compressed {
@@ -87,13 +109,23 @@ pub struct MetaMerkleLeafBundle {
 }
 
 impl MetaMerkleLeafBundle {
-    pub fn get_stake_merkle_proof(self, index: usize) -> Vec<[u8; 32]> {
+    fn stake_merkle_tree(&self) -> MerkleTree {
         let hashed_nodes: Vec<[u8; 32]> = self
             .stake_merkle_leaves
             .iter()
             .map(|n| n.hash().to_bytes())
             .collect();
-        let stake_merkle = MerkleTree::new(&hashed_nodes[..], true);
-        get_proof(&stake_merkle, index)
+        MerkleTree::new(&hashed_nodes)
+    }
+
+    pub fn get_stake_merkle_proof(self, index: usize) -> Vec<[u8; 32]> {
+        self.stake_merkle_tree().get_proof(index).unwrap()
+    }
+
+    /// Generates a multiproof for the StakeMerkleLeaves at `indices`, as consumed by
+    /// `verify_stake_merkle_proofs_batch`. Returns the proof hashes and proof flags.
+    /// Leaves must be passed to the instruction in ascending index order.
+    pub fn get_stake_merkle_multiproof(&self, indices: &[usize]) -> (Vec<[u8; 32]>, Vec<bool>) {
+        self.stake_merkle_tree().get_multiproof(indices).unwrap()
     }
 }
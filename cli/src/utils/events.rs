@@ -0,0 +1,121 @@
+use anchor_client::{
+    solana_client::rpc_config::RpcTransactionConfig,
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        signature::{Keypair, Signature},
+    },
+    ClientError, Program,
+};
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use gov_v1::{
+    AccountMigrated, AdminProposalApproved, AdminProposalCancelled, AdminProposalCreated,
+    AdminProposalExecuted, AdminsUpdated, AuthorityTransferred, BallotBoxCancelled,
+    BallotBoxClosed, BallotBoxInitialized, BallotBoxMigrated, BallotFinalized, ConfigUpdateQueued,
+    ConfigUpdated, ConsensusReached, ConsensusResultArchived, ExpiredBallotFinalized,
+    MetaMerkleProofClosed, MetaMerkleProofInitialized, MetaMerkleProofSealed, OperatorCommitClosed,
+    OperatorKeyRotated, OperatorKeyRotationCancelled, OperatorKeyRotationProposed,
+    OperatorWhitelistUpdateQueued, OperatorWhitelistUpdated, PauseUpdated, PendingConfigCancelled,
+    ProgramConfigInitialized, ProposalCreated, ProposalFinalized, ProposalVoteCast, TieBreakerSet,
+    VoteCast, VoteChanged, VoteCommitted, VoteExpiryExtended, VoteRemoved,
+};
+
+/// Prefix of the log line that `emit!` writes the base64-encoded event to.
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+macro_rules! gov_events {
+    ($($event:ident),+ $(,)?) => {
+        /// Events emitted by the gov-v1 program.
+        #[derive(Debug)]
+        pub enum GovEvent {
+            $($event($event),)+
+        }
+
+        /// Decodes an event from its discriminator-prefixed borsh data.
+        fn decode_event(data: &[u8]) -> Option<GovEvent> {
+            $(
+                if data.starts_with($event::DISCRIMINATOR) {
+                    return $event::try_from_slice(&data[$event::DISCRIMINATOR.len()..])
+                        .ok()
+                        .map(GovEvent::$event);
+                }
+            )+
+            None
+        }
+    };
+}
+
+gov_events!(
+    ProgramConfigInitialized,
+    ConfigUpdated,
+    AuthorityTransferred,
+    OperatorWhitelistUpdated,
+    ConfigUpdateQueued,
+    OperatorWhitelistUpdateQueued,
+    OperatorKeyRotationProposed,
+    OperatorKeyRotationCancelled,
+    OperatorKeyRotated,
+    PendingConfigCancelled,
+    PauseUpdated,
+    AccountMigrated,
+    AdminsUpdated,
+    AdminProposalCreated,
+    AdminProposalApproved,
+    AdminProposalExecuted,
+    AdminProposalCancelled,
+    BallotBoxInitialized,
+    BallotBoxMigrated,
+    VoteCast,
+    VoteCommitted,
+    OperatorCommitClosed,
+    VoteChanged,
+    VoteRemoved,
+    ConsensusReached,
+    TieBreakerSet,
+    ExpiredBallotFinalized,
+    VoteExpiryExtended,
+    BallotBoxCancelled,
+    BallotFinalized,
+    BallotBoxClosed,
+    ConsensusResultArchived,
+    MetaMerkleProofInitialized,
+    MetaMerkleProofSealed,
+    MetaMerkleProofClosed,
+    ProposalCreated,
+    ProposalVoteCast,
+    ProposalFinalized,
+);
+
+/// Decodes gov-v1 events from transaction log messages, in the order they were emitted.
+/// Log lines that are not gov-v1 events are skipped.
+pub fn decode_events(logs: &[String]) -> Vec<GovEvent> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix(PROGRAM_DATA_PREFIX))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|data| decode_event(&data))
+        .collect()
+}
+
+/// Fetches a confirmed transaction and decodes the gov-v1 events in its logs.
+pub fn fetch_events(
+    program: &Program<&Keypair>,
+    signature: Signature,
+) -> Result<Vec<GovEvent>, ClientError> {
+    let tx = program
+        .rpc()
+        .get_transaction_with_config(
+            &signature,
+            RpcTransactionConfig {
+                encoding: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )
+        .map_err(ClientError::SolanaClientError)?;
+    let logs: Option<Vec<String>> = tx
+        .transaction
+        .meta
+        .and_then(|meta| meta.log_messages.into());
+
+    Ok(decode_events(&logs.unwrap_or_default()))
+}
@@ -0,0 +1,94 @@
+use anchor_client::{
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair},
+    ClientError, Program,
+};
+use anchor_lang::{AccountDeserialize, Discriminator};
+use gov_v1::{BallotBox, ConsensusResult, MetaMerkleProof, ProgramConfig};
+
+/// Offset of `BallotBox.ballot_id`, after the account discriminator.
+const BALLOT_ID_OFFSET: usize = 8;
+
+/// Returns the address of the BallotBox with the given ID. Sequential ballot boxes are
+/// derived from the ID, while scheduled ballot boxes are looked up by their `ballot_id`.
+pub fn find_ballot_box(program: &Program<&Keypair>, ballot_id: u64) -> Result<Pubkey, ClientError> {
+    let ballot_box_pda = BallotBox::pda(ballot_id).0;
+    if program.rpc().get_account(&ballot_box_pda).is_ok() {
+        return Ok(ballot_box_pda);
+    }
+
+    // Legacy ballot boxes awaiting migration have a different size and are skipped.
+    let filters = vec![
+        RpcFilterType::DataSize(BallotBox::SIZE as u64),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            BALLOT_ID_OFFSET,
+            &ballot_id.to_le_bytes(),
+        )),
+    ];
+    program
+        .accounts::<BallotBox>(filters)?
+        .into_iter()
+        .map(|(address, _)| address)
+        .next()
+        .ok_or(ClientError::AccountNotFound)
+}
+
+/// Returns the addresses of the ballot boxes that are not finalized, cancelled or failed,
+/// sorted by address.
+pub fn find_open_ballot_boxes(program: &Program<&Keypair>) -> Result<Vec<Pubkey>, ClientError> {
+    let filters = vec![RpcFilterType::DataSize(BallotBox::SIZE as u64)];
+    let mut ballot_boxes: Vec<Pubkey> = program
+        .accounts::<BallotBox>(filters)?
+        .into_iter()
+        .filter(|(_, ballot_box)| ballot_box.is_open())
+        .map(|(address, _)| address)
+        .collect();
+    ballot_boxes.sort();
+    Ok(ballot_boxes)
+}
+
+/// Instruction that upgrades an account created with an older layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccountMigration {
+    /// Borsh BallotBox, converted to the zero-copy layout by `migrate_ballot_box`.
+    LegacyBallotBox,
+    /// Account upgraded to the current version by `migrate_account`.
+    Account,
+}
+
+/// Returns the accounts of the program that were created with an older layout. The
+/// ProgramConfig comes first, since `migrate_account` checks the authority against it.
+pub fn find_accounts_to_migrate(
+    program: &Program<&Keypair>,
+) -> Result<Vec<(Pubkey, AccountMigration)>, ClientError> {
+    let mut accounts: Vec<(Pubkey, AccountMigration)> = program
+        .rpc()
+        .get_program_accounts(&gov_v1::ID)?
+        .into_iter()
+        .filter_map(|(address, account)| Some((address, account_migration(&account.data)?)))
+        .collect();
+    accounts.sort_by_key(|(address, _)| *address != ProgramConfig::pda().0);
+    Ok(accounts)
+}
+
+fn account_migration(data: &[u8]) -> Option<AccountMigration> {
+    let discriminator = data.get(..8)?;
+    if discriminator == BallotBox::DISCRIMINATOR {
+        return (data.len() < BallotBox::SIZE).then_some(AccountMigration::LegacyBallotBox);
+    }
+
+    // Borsh accounts that fail to deserialize are missing fields added since.
+    let outdated = if discriminator == ProgramConfig::DISCRIMINATOR {
+        ProgramConfig::try_deserialize(&mut &data[..])
+            .map_or(true, |config| config.version < ProgramConfig::VERSION)
+    } else if discriminator == ConsensusResult::DISCRIMINATOR {
+        ConsensusResult::try_deserialize(&mut &data[..])
+            .map_or(true, |result| result.version < ConsensusResult::VERSION)
+    } else if discriminator == MetaMerkleProof::DISCRIMINATOR {
+        MetaMerkleProof::try_deserialize(&mut &data[..])
+            .map_or(true, |proof| proof.version < MetaMerkleProof::VERSION)
+    } else {
+        false
+    };
+    outdated.then_some(AccountMigration::Account)
+}
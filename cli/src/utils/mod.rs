@@ -1,7 +1,11 @@
+pub mod events;
+pub mod fetch_utils;
 pub mod parsers;
 pub mod send_utils;
 pub mod io;
 
+pub use events::*;
+pub use fetch_utils::*;
 pub use parsers::*;
 pub use send_utils::*;
 pub use io::*;
@@ -1,4 +1,5 @@
-use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signature};
+use gov_v1::{ExpiryResolution, OperatorWeight, VoteChoice};
 use solana_sdk::bs58;
 use std::str::FromStr;
 
@@ -7,6 +8,11 @@ pub fn parse_pubkey(s: &str) -> Result<Pubkey, String> {
     Pubkey::from_str(s).map_err(|e| format!("invalid pubkey: {e}"))
 }
 
+/// Parse a string in base58 format to a transaction Signature.
+pub fn parse_signature(s: &str) -> Result<Signature, String> {
+    Signature::from_str(s).map_err(|e| format!("invalid signature: {e}"))
+}
+
 /// Parse a string in base58 format to a 32-byte array.
 pub fn parse_base_58_32(s: &str) -> Result<[u8; 32], String> {
     let bytes = bs58::decode(s)
@@ -20,12 +26,46 @@ pub fn parse_base_58_32(s: &str) -> Result<[u8; 32], String> {
     Ok(array)
 }
 
+/// Parse a string in `<pubkey>:<weight>` format to an OperatorWeight.
+pub fn parse_operator_weight(s: &str) -> Result<OperatorWeight, String> {
+    let (operator, weight) = s
+        .split_once(':')
+        .ok_or_else(|| format!("expected <pubkey>:<weight>, got {}", s))?;
+    let weight = weight
+        .parse::<u16>()
+        .map_err(|e| format!("invalid weight: {e}"))?;
+    Ok(OperatorWeight {
+        operator: parse_pubkey(operator)?,
+        weight,
+    })
+}
+
+pub fn parse_vote_choice(s: &str) -> Result<VoteChoice, String> {
+    match s.to_lowercase().as_str() {
+        "for" => Ok(VoteChoice::For),
+        "against" => Ok(VoteChoice::Against),
+        "abstain" => Ok(VoteChoice::Abstain),
+        _ => Err(format!("invalid vote choice: {}", s)),
+    }
+}
+
+pub fn parse_expiry_resolution(s: &str) -> Result<ExpiryResolution, String> {
+    match s.to_lowercase().as_str() {
+        "tie-breaker-admin" => Ok(ExpiryResolution::TieBreakerAdmin),
+        "plurality" => Ok(ExpiryResolution::Plurality),
+        "no-result" => Ok(ExpiryResolution::NoResult),
+        _ => Err(format!("invalid expiry resolution: {}", s)),
+    }
+}
+
 pub fn parse_log_type(s: &str) -> Result<LogType, String> {
     match s.to_lowercase().as_str() {
         "program-config" => Ok(LogType::ProgramConfig),
         "ballot-box" => Ok(LogType::BallotBox),
         "consensus-result" => Ok(LogType::ConsensusResult),
         "proof" => Ok(LogType::MetaMerkleProof),
+        "proposal" => Ok(LogType::Proposal),
+        "admin-proposal" => Ok(LogType::AdminProposal),
         _ => Err(format!("invalid log type: {}", s)),
     }
 }
@@ -36,6 +76,8 @@ pub enum LogType {
     BallotBox,
     ConsensusResult,
     MetaMerkleProof,
+    Proposal,
+    AdminProposal,
 }
 
 // Snapshot filename parsers
@@ -1,5 +1,6 @@
+use crate::consts::{META_MERKLE_PROOF_CHUNK_SIZE, RECORD_OPERATOR_STATS_CHUNK_SIZE};
 use anchor_client::{
-    anchor_lang::system_program,
+    anchor_lang::{solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE, system_program},
     solana_sdk::{
         pubkey::Pubkey,
         signature::{Keypair, Signature},
@@ -7,8 +8,13 @@ use anchor_client::{
     },
     ClientError, Program,
 };
-use gov_v1::{accounts, instruction, Ballot, MetaMerkleLeaf, ProgramConfig, StakeMerkleLeaf};
-use solana_sdk::instruction::Instruction;
+use gov_v1::{
+    accounts, instruction, AdminAction, AdminProposal, Ballot, BallotBox, BallotBoxSeeds,
+    ConsensusResult, ExpiryResolution, MetaMerkleLeaf, MetaMerkleProof, OperatorCommit,
+    OperatorKeyRotation, OperatorStats, OperatorWeight, ProgramConfig, Proposal, StakeMerkleLeaf,
+    VoteChoice, VoteRecord,
+};
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::{compute_budget::ComputeBudgetInstruction, transaction::Transaction};
 
 pub struct TxSender<'a> {
@@ -61,8 +67,22 @@ fn send_with_anchor(
         .map_err(ClientError::SolanaClientError)
 }
 
-pub fn send_init_program_config(tx_sender: &TxSender) -> Result<Signature, ClientError> {
-    let ixs = tx_sender
+pub fn send_init_program_config(
+    tx_sender: &TxSender,
+    min_consensus_threshold_bps: u16,
+    min_votes: u16,
+    tie_breaker_admin: Pubkey,
+    vote_duration: i64,
+    close_grace_period: i64,
+    config_change_delay: i64,
+    scheduled_slot_offset: Option<u64>,
+    guardian: Pubkey,
+    max_vote_duration: i64,
+    commit_duration: i64,
+    expiry_resolution: ExpiryResolution,
+    plurality_floor_bps: u16,
+) -> Result<Signature, ClientError> {
+    let mut ixs = tx_sender
         .program
         .request()
         .accounts(accounts::InitProgramConfig {
@@ -71,16 +91,52 @@ pub fn send_init_program_config(tx_sender: &TxSender) -> Result<Signature, Clien
             program_config: ProgramConfig::pda().0,
             system_program: system_program::ID,
         })
-        .args(instruction::InitProgramConfig {})
+        .args(instruction::InitProgramConfig {
+            min_consensus_threshold_bps,
+            min_votes,
+            tie_breaker_admin,
+            vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            expiry_resolution,
+            plurality_floor_bps,
+        })
         .instructions()?;
+    // ProgramConfig is created at the max size of a single allocation and grown to full size.
+    ixs.extend(realloc_program_config_ixs(tx_sender)?);
 
     tx_sender.send(ixs)
 }
 
+/// Grows ProgramConfig by the max size of a single allocation. Permissionless.
+pub fn send_realloc_program_config(tx_sender: &TxSender) -> Result<Signature, ClientError> {
+    let ixs = realloc_program_config_ixs(tx_sender)?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
+fn realloc_program_config_ixs(tx_sender: &TxSender) -> Result<Vec<Instruction>, ClientError> {
+    tx_sender
+        .program
+        .request()
+        .accounts(accounts::ReallocProgramConfig {
+            payer: tx_sender.payer.pubkey(),
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::ReallocProgramConfig {})
+        .instructions()
+}
+
 pub fn send_update_operator_whitelist(
     tx_sender: &TxSender,
     operators_to_add: Option<Vec<Pubkey>>,
     operators_to_remove: Option<Vec<Pubkey>>,
+    operator_weights: Option<Vec<OperatorWeight>>,
 ) -> Result<Signature, ClientError> {
     let ixs = tx_sender
         .program
@@ -92,7 +148,82 @@ pub fn send_update_operator_whitelist(
         .args(instruction::UpdateOperatorWhitelist {
             operators_to_add,
             operators_to_remove,
+            operator_weights,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+/// Proposes rotating the operator key of the authority to new_operator.
+pub fn send_propose_operator_key_rotation(
+    tx_sender: &TxSender,
+    new_operator: Pubkey,
+) -> Result<Signature, ClientError> {
+    let operator = tx_sender.authority.pubkey();
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ProposeOperatorKeyRotation {
+            payer: tx_sender.payer.pubkey(),
+            operator,
+            operator_key_rotation: OperatorKeyRotation::pda(&operator).0,
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::ProposeOperatorKeyRotation { new_operator })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+/// Cancels the pending rotation of the authority's operator key.
+pub fn send_cancel_operator_key_rotation(
+    tx_sender: &TxSender,
+    payer: Pubkey,
+) -> Result<Signature, ClientError> {
+    let operator = tx_sender.authority.pubkey();
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CancelOperatorKeyRotation {
+            operator,
+            payer,
+            operator_key_rotation: OperatorKeyRotation::pda(&operator).0,
         })
+        .args(instruction::CancelOperatorKeyRotation {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+/// Accepts a rotation of old_operator to the authority, replacing the key in the open ballot
+/// boxes. All open ballot boxes must be passed, sorted by address.
+pub fn send_accept_operator_key_rotation(
+    tx_sender: &TxSender,
+    old_operator: Pubkey,
+    payer: Pubkey,
+    ballot_boxes: &[Pubkey],
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::AcceptOperatorKeyRotation {
+            new_operator: tx_sender.authority.pubkey(),
+            payer,
+            operator_key_rotation: OperatorKeyRotation::pda(&old_operator).0,
+            program_config: ProgramConfig::pda().0,
+            old_operator_stats: OperatorStats::pda(&old_operator).0,
+            new_operator_stats: OperatorStats::pda(&tx_sender.authority.pubkey()).0,
+            system_program: system_program::ID,
+        })
+        .accounts(
+            ballot_boxes
+                .iter()
+                .map(|ballot_box| AccountMeta::new(*ballot_box, false))
+                .collect::<Vec<_>>(),
+        )
+        .args(instruction::AcceptOperatorKeyRotation {})
         .instructions()?;
 
     tx_sender.send(ixs)
@@ -104,6 +235,15 @@ pub fn send_update_program_config(
     min_consensus_threshold_bps: Option<u16>,
     tie_breaker_admin: Option<Pubkey>,
     vote_duration: Option<i64>,
+    close_grace_period: Option<i64>,
+    config_change_delay: Option<i64>,
+    scheduled_slot_offset: Option<Option<u64>>,
+    guardian: Option<Pubkey>,
+    max_vote_duration: Option<i64>,
+    commit_duration: Option<i64>,
+    min_votes: Option<u16>,
+    expiry_resolution: Option<ExpiryResolution>,
+    plurality_floor_bps: Option<u16>,
 ) -> Result<Signature, ClientError> {
     let signers = vec![tx_sender.payer, tx_sender.authority];
     let accounts = accounts::UpdateProgramConfig {
@@ -120,24 +260,180 @@ pub fn send_update_program_config(
             min_consensus_threshold_bps,
             tie_breaker_admin,
             vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            min_votes,
+            expiry_resolution,
+            plurality_floor_bps,
         })
         .instructions()?;
 
     tx_sender.send_with_signers(ixs, &signers)
 }
 
+pub fn send_set_paused(
+    tx_sender: &TxSender,
+    balloting_paused: Option<bool>,
+    verification_paused: Option<bool>,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::SetPaused {
+            signer: tx_sender.authority.pubkey(),
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::SetPaused {
+            balloting_paused,
+            verification_paused,
+        })
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer, tx_sender.authority])
+}
+
+pub fn send_apply_pending_config(tx_sender: &TxSender) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ApplyPendingConfig {
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::ApplyPendingConfig {})
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
+pub fn send_cancel_pending_config(tx_sender: &TxSender) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CancelPendingConfig {
+            authority: tx_sender.authority.pubkey(),
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::CancelPendingConfig {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_set_admins(
+    tx_sender: &TxSender,
+    admins: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::SetAdmins {
+            authority: tx_sender.authority.pubkey(),
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::SetAdmins { admins, threshold })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_create_admin_proposal(
+    tx_sender: &TxSender,
+    proposal_id: u64,
+    action: AdminAction,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CreateAdminProposal {
+            payer: tx_sender.payer.pubkey(),
+            proposer: tx_sender.authority.pubkey(),
+            admin_proposal: AdminProposal::pda(proposal_id).0,
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::CreateAdminProposal { action })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_approve_admin_proposal(
+    tx_sender: &TxSender,
+    proposal_id: u64,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ApproveAdminProposal {
+            admin: tx_sender.authority.pubkey(),
+            admin_proposal: AdminProposal::pda(proposal_id).0,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::ApproveAdminProposal {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_execute_admin_proposal(
+    tx_sender: &TxSender,
+    proposal_id: u64,
+    proposer: Pubkey,
+    ballot_box: Option<Pubkey>,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ExecuteAdminProposal {
+            proposer,
+            admin_proposal: AdminProposal::pda(proposal_id).0,
+            program_config: ProgramConfig::pda().0,
+            ballot_box,
+        })
+        .args(instruction::ExecuteAdminProposal {})
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
+pub fn send_cancel_admin_proposal(
+    tx_sender: &TxSender,
+    proposal_id: u64,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CancelAdminProposal {
+            proposer: tx_sender.authority.pubkey(),
+            admin_proposal: AdminProposal::pda(proposal_id).0,
+        })
+        .args(instruction::CancelAdminProposal {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
 pub fn send_cast_vote(
     tx_sender: &TxSender,
     ballot_box: Pubkey,
     ballot: Ballot,
 ) -> Result<Signature, ClientError> {
+    let operator = tx_sender.authority.pubkey();
     let ixs = tx_sender
         .program
         .request()
         .accounts(accounts::CastVote {
-            operator: tx_sender.authority.pubkey(),
+            payer: tx_sender.payer.pubkey(),
+            operator,
             ballot_box,
+            operator_stats: OperatorStats::pda(&operator).0,
             program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
         })
         .args(instruction::CastVote { ballot })
         .instructions()?;
@@ -145,21 +441,229 @@ pub fn send_cast_vote(
     tx_sender.send(ixs)
 }
 
+pub fn send_change_vote(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    ballot: Ballot,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ChangeVote {
+            operator: tx_sender.authority.pubkey(),
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::ChangeVote { ballot })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+/// Allocates the BallotBox and initializes it in the same transaction.
 pub fn send_init_ballot_box(
     tx_sender: &TxSender,
     ballot_box: Pubkey,
+    target_slot: u64,
+) -> Result<Signature, ClientError> {
+    let ballot_id = tx_sender
+        .program
+        .account::<ProgramConfig>(ProgramConfig::pda().0)?
+        .next_ballot_id;
+    let mut ixs = realloc_ballot_box_ixs(tx_sender, ballot_box, BallotBoxSeeds::Id(ballot_id))?;
+    ixs.extend(
+        tx_sender
+            .program
+            .request()
+            .accounts(accounts::InitBallotBox {
+                payer: tx_sender.payer.pubkey(),
+                operator: tx_sender.authority.pubkey(),
+                ballot_box,
+                program_config: ProgramConfig::pda().0,
+            })
+            .args(instruction::InitBallotBox { target_slot })
+            .instructions()?,
+    );
+
+    tx_sender.send(ixs)
+}
+
+/// Allocates the scheduled BallotBox and initializes it in the same transaction.
+pub fn send_init_scheduled_ballot_box(
+    tx_sender: &TxSender,
+    epoch: u64,
+    target_slot: u64,
+) -> Result<Signature, ClientError> {
+    let ballot_box = BallotBox::scheduled_pda(epoch, target_slot).0;
+    let seeds = BallotBoxSeeds::Scheduled { epoch, target_slot };
+    let mut ixs = realloc_ballot_box_ixs(tx_sender, ballot_box, seeds)?;
+    ixs.extend(
+        tx_sender
+            .program
+            .request()
+            .accounts(accounts::InitScheduledBallotBox {
+                payer: tx_sender.payer.pubkey(),
+                ballot_box,
+                program_config: ProgramConfig::pda().0,
+            })
+            .args(instruction::InitScheduledBallotBox { epoch, target_slot })
+            .instructions()?,
+    );
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
+/// Instructions that allocate a BallotBox to BallotBox::SIZE, one per
+/// MAX_PERMITTED_DATA_INCREASE bytes.
+fn realloc_ballot_box_ixs(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    seeds: BallotBoxSeeds,
+) -> Result<Vec<Instruction>, ClientError> {
+    let num_ixs = BallotBox::SIZE.div_ceil(MAX_PERMITTED_DATA_INCREASE);
+    let mut ixs = Vec::with_capacity(num_ixs);
+    for _ in 0..num_ixs {
+        ixs.extend(
+            tx_sender
+                .program
+                .request()
+                .accounts(accounts::ReallocBallotBox {
+                    payer: tx_sender.payer.pubkey(),
+                    ballot_box,
+                    system_program: system_program::ID,
+                })
+                .args(instruction::ReallocBallotBox { seeds })
+                .instructions()?,
+        );
+    }
+    Ok(ixs)
+}
+
+/// Converts a BallotBox in the legacy borsh layout to the zero-copy layout. Permissionless.
+/// Each instruction grows the account by at most MAX_PERMITTED_DATA_INCREASE bytes, so
+/// enough of them are sent to reach the full size.
+pub fn send_migrate_ballot_box(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
 ) -> Result<Signature, ClientError> {
+    let data = tx_sender.program.rpc().get_account_data(&ballot_box)?;
+    // ballot_id is the first field in both layouts.
+    let ballot_id = u64::from_le_bytes(data[8..16].try_into().unwrap());
+    let num_ixs = BallotBox::SIZE
+        .saturating_sub(data.len())
+        .div_ceil(MAX_PERMITTED_DATA_INCREASE)
+        .max(1);
     let ixs = tx_sender
         .program
         .request()
-        .accounts(accounts::InitBallotBox {
+        .accounts(accounts::MigrateBallotBox {
             payer: tx_sender.payer.pubkey(),
-            operator: tx_sender.authority.pubkey(),
             ballot_box,
+            consensus_result: ConsensusResult::pda(ballot_id).0,
             program_config: ProgramConfig::pda().0,
             system_program: system_program::ID,
         })
-        .args(instruction::InitBallotBox {})
+        .args(instruction::MigrateBallotBox {})
+        .instructions()?;
+
+    tx_sender.send_with_signers(vec![ixs; num_ixs].concat(), &[tx_sender.payer])
+}
+
+/// Upgrades an account created with an older layout. Authority only. The ProgramConfig can
+/// need several instructions to reach its full size, which are sent in one transaction.
+pub fn send_migrate_account(
+    tx_sender: &TxSender,
+    account: Pubkey,
+) -> Result<Signature, ClientError> {
+    let num_ixs = if account == ProgramConfig::pda().0 {
+        let data = tx_sender.program.rpc().get_account_data(&account)?;
+        ProgramConfig::SIZE
+            .saturating_sub(data.len())
+            .div_ceil(MAX_PERMITTED_DATA_INCREASE)
+            .max(1)
+    } else {
+        1
+    };
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::MigrateAccount {
+            payer: tx_sender.payer.pubkey(),
+            authority: tx_sender.authority.pubkey(),
+            program_config: ProgramConfig::pda().0,
+            account,
+            system_program: system_program::ID,
+        })
+        .args(instruction::MigrateAccount {})
+        .instructions()?;
+
+    tx_sender.send(vec![ixs; num_ixs].concat())
+}
+
+pub fn send_commit_vote(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    commitment: [u8; 32],
+) -> Result<Signature, ClientError> {
+    let operator = tx_sender.authority.pubkey();
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CommitVote {
+            payer: tx_sender.payer.pubkey(),
+            operator,
+            ballot_box,
+            operator_commit: OperatorCommit::pda(&ballot_box, &operator).0,
+            operator_stats: OperatorStats::pda(&operator).0,
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::CommitVote { commitment })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_reveal_vote(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    ballot: Ballot,
+    salt: [u8; 32],
+    payer: Pubkey,
+) -> Result<Signature, ClientError> {
+    let operator = tx_sender.authority.pubkey();
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::RevealVote {
+            operator,
+            payer,
+            ballot_box,
+            operator_commit: OperatorCommit::pda(&ballot_box, &operator).0,
+            operator_stats: OperatorStats::pda(&operator).0,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::RevealVote { ballot, salt })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_close_operator_commit(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    operator: Pubkey,
+    payer: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CloseOperatorCommit {
+            payer,
+            ballot_box,
+            operator_commit: OperatorCommit::pda(&ballot_box, &operator).0,
+        })
+        .args(instruction::CloseOperatorCommit {})
         .instructions()?;
 
     tx_sender.send(ixs)
@@ -175,7 +679,6 @@ pub fn send_remove_vote(
         .accounts(accounts::RemoveVote {
             operator: tx_sender.authority.pubkey(),
             ballot_box,
-            program_config: ProgramConfig::pda().0,
         })
         .args(instruction::RemoveVote {})
         .instructions()?;
@@ -195,6 +698,7 @@ pub fn send_finalize_ballot(
             payer: tx_sender.payer.pubkey(),
             ballot_box,
             consensus_result,
+            program_config: ProgramConfig::pda().0,
             system_program: system_program::ID,
         })
         .args(instruction::FinalizeBallot {})
@@ -203,6 +707,55 @@ pub fn send_finalize_ballot(
     tx_sender.send_with_signers(ixs, &[tx_sender.payer])
 }
 
+/// Records a finalized ballot box in the OperatorStats of each of the given operators.
+pub fn send_record_operator_stats(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    operators: &[Pubkey],
+) -> Result<Vec<Signature>, ClientError> {
+    let mut signatures = vec![];
+    for operators_chunk in operators.chunks(RECORD_OPERATOR_STATS_CHUNK_SIZE) {
+        let mut ixs = vec![];
+        for operator in operators_chunk {
+            ixs.extend(
+                tx_sender
+                    .program
+                    .request()
+                    .accounts(accounts::RecordOperatorStats {
+                        payer: tx_sender.payer.pubkey(),
+                        ballot_box,
+                        operator_stats: OperatorStats::pda(operator).0,
+                        system_program: system_program::ID,
+                    })
+                    .args(instruction::RecordOperatorStats {
+                        operator: *operator,
+                    })
+                    .instructions()?,
+            );
+        }
+        signatures.push(tx_sender.send_with_signers(ixs, &[tx_sender.payer])?);
+    }
+
+    Ok(signatures)
+}
+
+/// Records a finalized ballot box in the OperatorStats of each eligible operator that is
+/// yet to be counted.
+pub fn send_pending_operator_stats(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+) -> Result<Vec<Signature>, ClientError> {
+    let ballot_box_account: BallotBox = tx_sender.program.account(ballot_box)?;
+    let operators: Vec<Pubkey> = ballot_box_account
+        .eligible_operators()
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !ballot_box_account.has_operator_stats_recorded(*idx))
+        .map(|(_, operator)| *operator)
+        .collect();
+    send_record_operator_stats(tx_sender, ballot_box, &operators)
+}
+
 pub fn send_set_tie_breaker(
     tx_sender: &TxSender,
     ballot_box: Pubkey,
@@ -222,6 +775,107 @@ pub fn send_set_tie_breaker(
     tx_sender.send(ixs)
 }
 
+pub fn send_finalize_expired_ballot(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::FinalizeExpiredBallot {
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::FinalizeExpiredBallot {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_extend_vote_expiry(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    vote_expiry_timestamp: i64,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ExtendVoteExpiry {
+            authority: tx_sender.authority.pubkey(),
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::ExtendVoteExpiry {
+            vote_expiry_timestamp,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_cancel_ballot_box(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CancelBallotBox {
+            authority: tx_sender.authority.pubkey(),
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::CancelBallotBox {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_close_ballot_box(
+    tx_sender: &TxSender,
+    ballot_box: Pubkey,
+    payer: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CloseBallotBox {
+            signer: tx_sender.authority.pubkey(),
+            payer,
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::CloseBallotBox {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_archive_consensus_result(
+    tx_sender: &TxSender,
+    consensus_result: Pubkey,
+    payer: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ballot_box = tx_sender
+        .program
+        .account::<ConsensusResult>(consensus_result)?
+        .ballot_box;
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::ArchiveConsensusResult {
+            authority: tx_sender.authority.pubkey(),
+            payer,
+            consensus_result,
+            ballot_box,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::ArchiveConsensusResult {})
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
 pub fn send_init_meta_merkle_proof(
     tx_sender: &TxSender,
     meta_merkle_proof_pda: Pubkey,
@@ -237,6 +891,7 @@ pub fn send_init_meta_merkle_proof(
             payer: tx_sender.payer.pubkey(),
             merkle_proof: meta_merkle_proof_pda,
             consensus_result,
+            program_config: ProgramConfig::pda().0,
             system_program: system_program::ID,
         })
         .args(instruction::InitMetaMerkleProof {
@@ -249,6 +904,95 @@ pub fn send_init_meta_merkle_proof(
     tx_sender.send(ixs)
 }
 
+pub fn send_init_chunked_meta_merkle_proof(
+    tx_sender: &TxSender,
+    meta_merkle_proof_pda: Pubkey,
+    consensus_result: Pubkey,
+    meta_merkle_leaf: MetaMerkleLeaf,
+    proof_len: u8,
+    close_timestamp: i64,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::InitChunkedMetaMerkleProof {
+            payer: tx_sender.payer.pubkey(),
+            merkle_proof: meta_merkle_proof_pda,
+            consensus_result,
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::InitChunkedMetaMerkleProof {
+            meta_merkle_leaf,
+            proof_len,
+            close_timestamp,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+/// Initializes a MetaMerkleProof over several transactions: init with the declared proof
+/// length, append the proof in chunks, then seal. Returns the signature of the seal.
+pub fn send_init_meta_merkle_proof_in_chunks(
+    tx_sender: &TxSender,
+    meta_merkle_proof_pda: Pubkey,
+    consensus_result: Pubkey,
+    meta_merkle_leaf: MetaMerkleLeaf,
+    meta_merkle_proof: Vec<[u8; 32]>,
+    close_timestamp: i64,
+) -> Result<Signature, ClientError> {
+    send_init_chunked_meta_merkle_proof(
+        tx_sender,
+        meta_merkle_proof_pda,
+        consensus_result,
+        meta_merkle_leaf,
+        meta_merkle_proof.len() as u8,
+        close_timestamp,
+    )?;
+    for proof_chunk in meta_merkle_proof.chunks(META_MERKLE_PROOF_CHUNK_SIZE) {
+        send_append_meta_merkle_proof(tx_sender, meta_merkle_proof_pda, proof_chunk.to_vec())?;
+    }
+    send_seal_meta_merkle_proof(tx_sender, consensus_result, meta_merkle_proof_pda)
+}
+
+pub fn send_append_meta_merkle_proof(
+    tx_sender: &TxSender,
+    meta_merkle_proof: Pubkey,
+    proof_chunk: Vec<[u8; 32]>,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::AppendMetaMerkleProof {
+            payer: tx_sender.payer.pubkey(),
+            meta_merkle_proof,
+        })
+        .args(instruction::AppendMetaMerkleProof { proof_chunk })
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
+pub fn send_seal_meta_merkle_proof(
+    tx_sender: &TxSender,
+    consensus_result: Pubkey,
+    meta_merkle_proof: Pubkey,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::SealMetaMerkleProof {
+            meta_merkle_proof,
+            consensus_result,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::SealMetaMerkleProof {})
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
 pub fn send_verify_merkle_proof(
     tx_sender: &TxSender,
     consensus_result: Pubkey,
@@ -262,6 +1006,7 @@ pub fn send_verify_merkle_proof(
         .accounts(accounts::VerifyMerkleProof {
             consensus_result,
             meta_merkle_proof,
+            program_config: ProgramConfig::pda().0,
         })
         .args(instruction::VerifyMerkleProof {
             stake_merkle_proof,
@@ -272,6 +1017,32 @@ pub fn send_verify_merkle_proof(
     tx_sender.send(ixs)
 }
 
+pub fn send_verify_stake_merkle_proofs_batch(
+    tx_sender: &TxSender,
+    consensus_result: Pubkey,
+    meta_merkle_proof: Pubkey,
+    stake_merkle_leaves: Vec<StakeMerkleLeaf>,
+    stake_merkle_proof: Vec<[u8; 32]>,
+    proof_flags: Vec<bool>,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::VerifyStakeMerkleProofsBatch {
+            consensus_result,
+            meta_merkle_proof,
+            program_config: ProgramConfig::pda().0,
+        })
+        .args(instruction::VerifyStakeMerkleProofsBatch {
+            stake_merkle_leaves,
+            stake_merkle_proof,
+            proof_flags,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
 pub fn send_close_meta_merkle_proof(
     tx_sender: &TxSender,
     meta_merkle_proof: Pubkey,
@@ -290,6 +1061,116 @@ pub fn send_close_meta_merkle_proof(
     tx_sender.send(ixs)
 }
 
+pub fn send_create_proposal(
+    tx_sender: &TxSender,
+    proposal: Pubkey,
+    consensus_result: Pubkey,
+    description_hash: [u8; 32],
+    vote_duration: i64,
+    quorum_stake: u64,
+    pass_threshold_bps: u16,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CreateProposal {
+            payer: tx_sender.payer.pubkey(),
+            proposer: tx_sender.authority.pubkey(),
+            proposal,
+            consensus_result,
+            program_config: ProgramConfig::pda().0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::CreateProposal {
+            description_hash,
+            vote_duration,
+            quorum_stake,
+            pass_threshold_bps,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_cast_proposal_vote(
+    tx_sender: &TxSender,
+    proposal: Pubkey,
+    consensus_result: Pubkey,
+    meta_merkle_leaf: &MetaMerkleLeaf,
+    choice: VoteChoice,
+) -> Result<Signature, ClientError> {
+    let meta_merkle_proof =
+        MetaMerkleProof::pda(&consensus_result, &meta_merkle_leaf.vote_account).0;
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CastProposalVote {
+            payer: tx_sender.payer.pubkey(),
+            voting_wallet: tx_sender.authority.pubkey(),
+            proposal,
+            meta_merkle_proof,
+            consensus_result,
+            program_config: ProgramConfig::pda().0,
+            vote_record: VoteRecord::pda(&proposal, &meta_merkle_leaf.vote_account).0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::CastProposalVote { choice })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_cast_delegator_vote(
+    tx_sender: &TxSender,
+    proposal: Pubkey,
+    consensus_result: Pubkey,
+    meta_merkle_leaf: &MetaMerkleLeaf,
+    choice: VoteChoice,
+    stake_merkle_proof: Vec<[u8; 32]>,
+    stake_merkle_leaf: StakeMerkleLeaf,
+) -> Result<Signature, ClientError> {
+    let meta_merkle_proof =
+        MetaMerkleProof::pda(&consensus_result, &meta_merkle_leaf.vote_account).0;
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::CastDelegatorVote {
+            payer: tx_sender.payer.pubkey(),
+            voting_wallet: tx_sender.authority.pubkey(),
+            proposal,
+            meta_merkle_proof,
+            consensus_result,
+            program_config: ProgramConfig::pda().0,
+            vote_record: VoteRecord::pda(&proposal, &stake_merkle_leaf.stake_account).0,
+            validator_vote_record: VoteRecord::pda(&proposal, &meta_merkle_leaf.vote_account).0,
+            system_program: system_program::ID,
+        })
+        .args(instruction::CastDelegatorVote {
+            choice,
+            stake_merkle_proof,
+            stake_merkle_leaf,
+        })
+        .instructions()?;
+
+    tx_sender.send(ixs)
+}
+
+pub fn send_finalize_proposal(
+    tx_sender: &TxSender,
+    proposal_id: u64,
+) -> Result<Signature, ClientError> {
+    let ixs = tx_sender
+        .program
+        .request()
+        .accounts(accounts::FinalizeProposal {
+            proposal: Proposal::pda(proposal_id).0,
+        })
+        .args(instruction::FinalizeProposal {})
+        .instructions()?;
+
+    tx_sender.send_with_signers(ixs, &[tx_sender.payer])
+}
+
 pub fn send_finalize_proposed_authority(
     tx_sender: &TxSender,
 ) -> Result<Signature, ClientError> {
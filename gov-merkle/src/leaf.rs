@@ -0,0 +1,27 @@
+use solana_sha256_hasher::hashv;
+
+/// Hashes the fields of a MetaMerkleLeaf. The hash is the leaf content of the MetaMerkleTree.
+pub fn hash_meta_merkle_leaf(
+    voting_wallet: &[u8; 32],
+    vote_account: &[u8; 32],
+    stake_merkle_root: &[u8; 32],
+    active_stake: u64,
+) -> [u8; 32] {
+    hashv(&[
+        voting_wallet,
+        vote_account,
+        stake_merkle_root,
+        &active_stake.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hashes the fields of a StakeMerkleLeaf. The hash is the leaf content of the
+/// StakeMerkleTree of the vote account.
+pub fn hash_stake_merkle_leaf(
+    voting_wallet: &[u8; 32],
+    stake_account: &[u8; 32],
+    active_stake: u64,
+) -> [u8; 32] {
+    hashv(&[voting_wallet, stake_account, &active_stake.to_le_bytes()]).to_bytes()
+}
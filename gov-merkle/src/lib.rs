@@ -0,0 +1,36 @@
+//! Merkle trees of gov-v1 snapshots: leaf hashing, tree construction, proof generation and
+//! verification. Shared by the program, the CLI and the verifier service, so it builds
+//! without std and doesn't depend on anchor.
+#![no_std]
+
+extern crate alloc;
+
+pub mod leaf;
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
+pub mod tree;
+pub mod verify;
+
+pub use leaf::*;
+pub use tree::*;
+pub use verify::*;
+
+use solana_sha256_hasher::hashv;
+
+// We need to discern between leaf and intermediate nodes to prevent trivial second
+// pre-image attacks.
+// https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
+pub const LEAF_PREFIX: &[u8] = &[0];
+pub const INTERMEDIATE_PREFIX: &[u8] = &[1];
+
+/// Hashes a leaf node from the leaf content, e.g. the hash of a StakeMerkleLeaf.
+pub fn hash_leaf(leaf_content: &[u8]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, leaf_content]).to_bytes()
+}
+
+/// Hashes an intermediate node from its children in sorted order, so proofs don't need to
+/// encode on which side each sibling is.
+pub fn hash_intermediate(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[INTERMEDIATE_PREFIX, left, right]).to_bytes()
+}
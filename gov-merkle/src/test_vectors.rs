@@ -0,0 +1,220 @@
+//! Test vectors shared by the gov-merkle tests and its dependents, to check that trees,
+//! proofs and leaf hashes stay compatible with published snapshots. Enabled by the
+//! `test-vectors` feature. Generated with an independent implementation of the same tree,
+//! except for the `SNAPSHOT_` vectors, which are taken from a published snapshot.
+
+/// Voting wallet of the stake and meta merkle leaves.
+pub const VOTING_WALLET: [u8; 32] = [100; 32];
+/// Vote account of the meta merkle leaf.
+pub const VOTE_ACCOUNT: [u8; 32] = [200; 32];
+
+/// StakeMerkleLeaf of VOTING_WALLET and its expected hash.
+pub struct StakeLeafVector {
+    pub stake_account: [u8; 32],
+    pub active_stake: u64,
+    pub hash: [u8; 32],
+}
+
+/// Multiproof for the leaves at `indices` of the StakeMerkleTree.
+pub struct MultiproofVector {
+    pub indices: &'static [usize],
+    pub proof: &'static [[u8; 32]],
+    pub proof_flags: &'static [bool],
+}
+
+/// Leaves of the StakeMerkleTree of VOTE_ACCOUNT, in tree order.
+pub const STAKE_LEAVES: [StakeLeafVector; 5] = [
+    StakeLeafVector {
+        stake_account: [1; 32],
+        active_stake: 1_000_000_000,
+        hash: hex("e6b759c7f5e2dd1223627eab9a60bd26636a3c27a59017c32731fb60add92c8a"),
+    },
+    StakeLeafVector {
+        stake_account: [2; 32],
+        active_stake: 2_000_000_000,
+        hash: hex("e1b1acaed9607fcbb8019f2912e045dd906d561ea6396bdb8d1633c4250ebca9"),
+    },
+    StakeLeafVector {
+        stake_account: [3; 32],
+        active_stake: 3_000_000_000,
+        hash: hex("f2cfdf55996d3f5c9ef0f8aaae79ba258b28372a15686399b0d3bd7bc9bc00ee"),
+    },
+    StakeLeafVector {
+        stake_account: [4; 32],
+        active_stake: 4_000_000_000,
+        hash: hex("30c0dfc48e8249473b29f30385e17f8444162962dcedc2335437128a1bcba6a9"),
+    },
+    StakeLeafVector {
+        stake_account: [5; 32],
+        active_stake: 5_000_000_000,
+        hash: hex("80ae92d1e56ecf04c9dd868511b8f0aa4ea24081e41e8a9549d1e25ebf14933b"),
+    },
+];
+
+/// Root of the StakeMerkleTree of STAKE_LEAVES.
+pub const STAKE_MERKLE_ROOT: [u8; 32] =
+    hex("78f7497668ae364eab308918a15d1099dfa6f777f1ffe2968edcfae0b084829c");
+
+/// Hash of the MetaMerkleLeaf of VOTE_ACCOUNT, with STAKE_MERKLE_ROOT and the total active
+/// stake of STAKE_LEAVES.
+pub const META_MERKLE_LEAF_HASH: [u8; 32] =
+    hex("e17ee1ef2aa08b0e1377a0d97951369c9d4e0ad1970e3c870404a96c9cdc2211");
+
+/// Proofs of STAKE_LEAVES, by index.
+pub const STAKE_MERKLE_PROOFS: [&[[u8; 32]]; 5] = [
+    &[
+        hex("279adfafa27496ba90a0e587984e637d9fa3084c89b177fd12c219272badbb66"),
+        hex("73b2d347ba4009257bf912804fec6b342cdb5cead727ec5811dfc29da83c6b97"),
+        hex("bc47e2e74b68a71543eaf263ff871d333075462f8dd661c9b532f7b57268e682"),
+    ],
+    &[
+        hex("d6f5522fec83d3b274763848312e30bb31bf3685eca74068a660ceabbad35cb2"),
+        hex("73b2d347ba4009257bf912804fec6b342cdb5cead727ec5811dfc29da83c6b97"),
+        hex("bc47e2e74b68a71543eaf263ff871d333075462f8dd661c9b532f7b57268e682"),
+    ],
+    &[
+        hex("d0954c4ca93da4db5be15f426a7367b588123f43ddba67fbddb14a13297ef001"),
+        hex("8036fcfb665323255747d0b3d1911ae2f155374608a4b96dcbce92330919128a"),
+        hex("bc47e2e74b68a71543eaf263ff871d333075462f8dd661c9b532f7b57268e682"),
+    ],
+    &[
+        hex("0fd32b37f84924ef24f9591180baaf705cdb1e16e41009726642d6c50577927a"),
+        hex("8036fcfb665323255747d0b3d1911ae2f155374608a4b96dcbce92330919128a"),
+        hex("bc47e2e74b68a71543eaf263ff871d333075462f8dd661c9b532f7b57268e682"),
+    ],
+    &[
+        hex("1d2657ae1dce2de4d749d2935fbf5b79b78f9a5cb23e733935825713c022327c"),
+        hex("709685443fbb2ca226d86fec238cbab3166ee462b8c31d6b3aae9a2d50ae3ab2"),
+        hex("f5cf652615267bd213945d305eb380d3807051a3b7c5e057bf8b3b27cb0a16ec"),
+    ],
+];
+
+/// Multiproofs of subsets of STAKE_LEAVES.
+pub const STAKE_MERKLE_MULTIPROOFS: [MultiproofVector; 3] = [
+    MultiproofVector {
+        indices: &[0, 1],
+        proof: &[
+            hex("73b2d347ba4009257bf912804fec6b342cdb5cead727ec5811dfc29da83c6b97"),
+            hex("bc47e2e74b68a71543eaf263ff871d333075462f8dd661c9b532f7b57268e682"),
+        ],
+        proof_flags: &[true, false, false],
+    },
+    MultiproofVector {
+        indices: &[1, 3, 4],
+        proof: &[
+            hex("d6f5522fec83d3b274763848312e30bb31bf3685eca74068a660ceabbad35cb2"),
+            hex("0fd32b37f84924ef24f9591180baaf705cdb1e16e41009726642d6c50577927a"),
+            hex("1d2657ae1dce2de4d749d2935fbf5b79b78f9a5cb23e733935825713c022327c"),
+            hex("709685443fbb2ca226d86fec238cbab3166ee462b8c31d6b3aae9a2d50ae3ab2"),
+        ],
+        proof_flags: &[false, false, false, true, false, true],
+    },
+    MultiproofVector {
+        indices: &[0, 1, 2, 3, 4],
+        proof: &[
+            hex("1d2657ae1dce2de4d749d2935fbf5b79b78f9a5cb23e733935825713c022327c"),
+            hex("709685443fbb2ca226d86fec238cbab3166ee462b8c31d6b3aae9a2d50ae3ab2"),
+        ],
+        proof_flags: &[true, true, false, true, false, true],
+    },
+];
+
+/// Roots of trees with 1 to 8 leaves, where the content of leaf `i` is `[i; 32]`. Covers
+/// levels of odd size, whose last node is paired with itself.
+pub const SEQUENTIAL_ROOTS: [[u8; 32]; 8] = [
+    hex("7f9c9e31ac8256ca2f258583df262dbc7d6f68f2a03043d5c99a4ae5a7396ce9"),
+    hex("28fb81e496897e0ce886f08602392e9239b65c659041e5202163e58ad898f444"),
+    hex("9aa08d413285ecda667944ba9446d77c1f1712ecf946bfcf74453c731a38e7b7"),
+    hex("d6d377102fe5595f4e72b2039f94249acdc362284440e6355a83b470f82f7207"),
+    hex("c14a102362519d6fc96c0d929450c00f9e59016618c9fdce32bbfca4492cd3c3"),
+    hex("e5375205812eb52eafa99e1d279d554c71aef6ef2dc540ed745e64cfd5850ddf"),
+    hex("9d73f918fba5e5cc237692f2aeaf3a26fee463531fe3e504027f88fb0b4e3e55"),
+    hex("386112ef3d4c0baa2bea620822ca82369ac722f75a24d75bfe30ca91f988f419"),
+];
+
+/// StakeMerkleLeaf of a published snapshot and its expected hash.
+pub struct SnapshotStakeLeafVector {
+    pub voting_wallet: [u8; 32],
+    pub stake_account: [u8; 32],
+    pub active_stake: u64,
+    pub hash: [u8; 32],
+}
+
+/// Slot of the snapshot in `tests/src/fixtures/meta_merkle_340850340.zip`, generated with
+/// the meta-merkle-tree crate before gov-merkle replaced it.
+pub const SNAPSHOT_SLOT: u64 = 340_850_340;
+
+/// Root of the MetaMerkleTree of the snapshot.
+pub const SNAPSHOT_META_MERKLE_ROOT: [u8; 32] =
+    hex("f1ca4244451edb3ab239bd4cf95618558292ad55f80beeb8a3ad10f79bba05e2");
+
+/// Voting wallet of the MetaMerkleLeaf at index 4 of the snapshot.
+pub const SNAPSHOT_VOTING_WALLET: [u8; 32] =
+    hex("e13486bba39b8907ad8e2ea865ae73b32928e0108bd5dc16d0974120976bc5c6");
+/// Vote account of the MetaMerkleLeaf at index 4 of the snapshot.
+pub const SNAPSHOT_VOTE_ACCOUNT: [u8; 32] =
+    hex("09d0cf23c22b3cfdcba45edfb596d82a904231cf4c63f860efd26cbe37e4cee5");
+/// Active stake of the MetaMerkleLeaf at index 4 of the snapshot.
+pub const SNAPSHOT_ACTIVE_STAKE: u64 = 32_628_613_412_658;
+
+/// Leaves of the StakeMerkleTree of SNAPSHOT_VOTE_ACCOUNT, in tree order.
+pub const SNAPSHOT_STAKE_LEAVES: [SnapshotStakeLeafVector; 3] = [
+    SnapshotStakeLeafVector {
+        voting_wallet: hex("54e59e24c051a8a14a7b1e9797f0e12cdfa7490216653195a6a512a6f166602b"),
+        stake_account: hex("372659625947b536f59f3faff75dcf8cff4524a0ed482e9e50c63d34d224b4cd"),
+        active_stake: 1_000_000_000,
+        hash: hex("af20f7aed5d86b1daf81e38529d4cc15bee0c8b1da15eac0883f98776cfbcc3a"),
+    },
+    SnapshotStakeLeafVector {
+        voting_wallet: hex("e13486bba39b8907ad8e2ea865ae73b32928e0108bd5dc16d0974120976bc5c6"),
+        stake_account: hex("ae283d752e19ba3b19af02e16b70d2fe24dbc7427e9ac2751868713914031d49"),
+        active_stake: 9_997_717_120,
+        hash: hex("9fbba02b32f6c569cac6f2dfe66c213ff28b5d6fe3cf25f7c222211bc0e0ace3"),
+    },
+    SnapshotStakeLeafVector {
+        voting_wallet: hex("032cc591e181719e6f83be8e248e1bd52892555f0ace4fa3dc170476521c8573"),
+        stake_account: hex("fb196c8c52aade51969fd9b3b2a5013b852247a87835096bae3780eda803d2e4"),
+        active_stake: 12_955_073_031,
+        hash: hex("d7292d454225e306dc2af2b41ae99ecf53844523147af9e46ae739f671d7164e"),
+    },
+];
+
+/// Root of the StakeMerkleTree of SNAPSHOT_STAKE_LEAVES, as recorded in the MetaMerkleLeaf.
+pub const SNAPSHOT_STAKE_MERKLE_ROOT: [u8; 32] =
+    hex("5f8f5f845a59bc16da47b46b60939c333007f87398d7a5577c741e38799942b8");
+
+/// Hash of the MetaMerkleLeaf of SNAPSHOT_VOTE_ACCOUNT.
+pub const SNAPSHOT_META_MERKLE_LEAF_HASH: [u8; 32] =
+    hex("e087e9c910f46ab2cb1438b8752cbf337051fb1c56f28c1e924d0178abd27186");
+
+/// Proof of the MetaMerkleLeaf of SNAPSHOT_VOTE_ACCOUNT, as stored in the snapshot.
+pub const SNAPSHOT_META_MERKLE_PROOF: [[u8; 32]; 7] = [
+    hex("151b7f49375cc50366284b95229caf37e978988d4e2eb2fff0332763053f9a30"),
+    hex("3b81846adb5fb0445bf573c82a03cb49ecc8a179956d04dfa0e1062f46a0ea20"),
+    hex("8824604222c9bd9c0f54fc20e7aee31169d522ce6ba07f643de377e53d62c791"),
+    hex("dcd489b0f26c4a15077db4bdc517c5449c0b22e24f3a6b988e61924343439c23"),
+    hex("dbd13bac6b0a6a75ce0177b308fad0a8f37f00ee7b51db4272f4251d6806fa61"),
+    hex("0e33ec4b7c996d746d6079fa537adf8c0448638b49651f82b4d79dd0d8d5dd11"),
+    hex("a24e273c47b7677cb1f70082aef8c4dfb512d891aea5a42ebc0ed00aba01a09c"),
+];
+
+/// Decodes a hex string of 32 bytes at compile time.
+const fn hex(s: &str) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+
+    let s = s.as_bytes();
+    assert!(s.len() == 64, "expected 32 bytes");
+    let mut bytes = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        bytes[i] = (nibble(s[2 * i]) << 4) | nibble(s[2 * i + 1]);
+        i += 1;
+    }
+    bytes
+}
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+
+use crate::{hash_intermediate, hash_leaf};
+
+/// Merkle tree with sorted sibling hashes. The last node of an odd-sized level is paired
+/// with itself, so trees of existing meta merkle snapshots are reproduced unchanged.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerkleTree {
+    leaf_count: usize,
+    /// Nodes level by level, from the hashed leaves to the root.
+    nodes: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    /// Builds the tree from the leaf contents, e.g. the hashes of StakeMerkleLeaves.
+    pub fn new(leaf_contents: &[[u8; 32]]) -> Self {
+        let mut nodes: Vec<[u8; 32]> = leaf_contents
+            .iter()
+            .map(|leaf_content| hash_leaf(leaf_content))
+            .collect();
+        let mut level_start = 0;
+        let mut level_len = nodes.len();
+        while level_len > 1 {
+            let level_end = level_start + level_len;
+            for i in (level_start..level_end).step_by(2) {
+                let node = hash_intermediate(&nodes[i], &nodes[(i + 1).min(level_end - 1)]);
+                nodes.push(node);
+            }
+            level_start = level_end;
+            level_len = level_len.div_ceil(2);
+        }
+
+        Self {
+            leaf_count: leaf_contents.len(),
+            nodes,
+        }
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Returns the root of the tree. None if the tree has no leaves.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.nodes.last().copied()
+    }
+
+    /// Returns the sibling hashes from the leaf at `index` up to the root, as consumed by
+    /// `verify`. None if the index is out of range.
+    pub fn get_proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaf_count {
+            return None;
+        }
+
+        let mut index = index;
+        let proof = self
+            .levels()
+            .take_while(|level| level.len() > 1)
+            .map(|level| {
+                let sibling = *level.get(index ^ 1).unwrap_or(&level[index]);
+                index /= 2;
+                sibling
+            })
+            .collect();
+        Some(proof)
+    }
+
+    /// Returns the proof hashes and proof flags of a multiproof for the leaves at `indices`,
+    /// as consumed by `verify_multiproof`. None if an index is out of range.
+    ///
+    /// Leaves must be passed to the verifier in ascending index order. For each node known
+    /// to the verifier, level by level, a flag is set if its sibling is also known, otherwise
+    /// the sibling is appended to the proof.
+    pub fn get_multiproof(&self, indices: &[usize]) -> Option<(Vec<[u8; 32]>, Vec<bool>)> {
+        let mut known_indices = indices.to_vec();
+        known_indices.sort_unstable();
+        known_indices.dedup();
+        if known_indices
+            .last()
+            .is_some_and(|&index| index >= self.leaf_count)
+        {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        let mut proof_flags = Vec::new();
+        for level in self.levels().take_while(|level| level.len() > 1) {
+            let mut next_known_indices = Vec::with_capacity(known_indices.len());
+            let mut i = 0;
+            while i < known_indices.len() {
+                let index = known_indices[i];
+                // Known indices are sorted, so only the sibling of an even index can follow.
+                if known_indices.get(i + 1) == Some(&(index ^ 1)) {
+                    proof_flags.push(true);
+                    i += 2;
+                } else {
+                    proof.push(*level.get(index ^ 1).unwrap_or(&level[index]));
+                    proof_flags.push(false);
+                    i += 1;
+                }
+                next_known_indices.push(index / 2);
+            }
+            known_indices = next_known_indices;
+        }
+
+        Some((proof, proof_flags))
+    }
+
+    /// Levels of the tree, from the hashed leaves to the root.
+    fn levels(&self) -> impl Iterator<Item = &[[u8; 32]]> + '_ {
+        let mut level_start = 0;
+        let mut level_len = self.leaf_count;
+        core::iter::from_fn(move || {
+            if level_len == 0 {
+                return None;
+            }
+            let level = &self.nodes[level_start..level_start + level_len];
+            level_start += level_len;
+            level_len = if level_len == 1 {
+                0
+            } else {
+                level_len.div_ceil(2)
+            };
+            Some(level)
+        })
+    }
+}
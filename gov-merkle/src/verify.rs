@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+
+use crate::{hash_intermediate, hash_leaf};
+
+/// Computes the root reached from a leaf's content and its sibling hashes.
+///
+/// Hashes the leaf with a prefix, then hashes iteratively with each sorted sibling.
+pub fn compute_root(leaf_content: &[u8], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(hash_leaf(leaf_content), |node, sibling| {
+        hash_intermediate(&node, sibling)
+    })
+}
+
+/// Verifies a Merkle proof from a leaf's content and its sibling hashes against `root`.
+pub fn verify(leaf_content: &[u8], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    compute_root(leaf_content, proof) == *root
+}
+
+/// Computes the root reached from a Merkle multiproof for several leaves of the same tree.
+/// None if the multiproof is malformed.
+///
+/// Leaves must be in the order of the tree. Nodes are processed level by level from a
+/// queue of leaf hashes followed by computed hashes. For each flag, the first node is
+/// popped from the queue and its sibling is popped from the queue if the flag is set,
+/// or taken from `proof` otherwise. The last computed hash is the Merkle root.
+pub fn compute_multiproof_root(
+    leaf_contents: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+) -> Option<[u8; 32]> {
+    if leaf_contents.is_empty() || leaf_contents.len() + proof.len() != proof_flags.len() + 1 {
+        return None;
+    }
+
+    let leaves: Vec<[u8; 32]> = leaf_contents
+        .iter()
+        .map(|leaf_content| hash_leaf(leaf_content))
+        .collect();
+    let mut hashes: Vec<[u8; 32]> = Vec::with_capacity(proof_flags.len());
+    let mut leaf_pos = 0;
+    let mut hash_pos = 0;
+    let mut proof_pos = 0;
+
+    for &flag in proof_flags {
+        let node = pop_node(&leaves, &hashes, &mut leaf_pos, &mut hash_pos)?;
+        let sibling_node = if flag {
+            pop_node(&leaves, &hashes, &mut leaf_pos, &mut hash_pos)?
+        } else {
+            let sibling_node = *proof.get(proof_pos)?;
+            proof_pos += 1;
+            sibling_node
+        };
+        hashes.push(hash_intermediate(&node, &sibling_node));
+    }
+
+    // All proof hashes must be consumed.
+    if proof_pos != proof.len() {
+        return None;
+    }
+
+    Some(*hashes.last().unwrap_or(&leaves[0]))
+}
+
+/// Verifies a Merkle multiproof for several leaves of the same tree against `root`.
+pub fn verify_multiproof(
+    leaf_contents: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+    root: &[u8; 32],
+) -> bool {
+    compute_multiproof_root(leaf_contents, proof, proof_flags) == Some(*root)
+}
+
+/// Pops the next node from the queue of leaves followed by computed hashes.
+fn pop_node(
+    leaves: &[[u8; 32]],
+    hashes: &[[u8; 32]],
+    leaf_pos: &mut usize,
+    hash_pos: &mut usize,
+) -> Option<[u8; 32]> {
+    if *leaf_pos < leaves.len() {
+        *leaf_pos += 1;
+        Some(leaves[*leaf_pos - 1])
+    } else if *hash_pos < hashes.len() {
+        *hash_pos += 1;
+        Some(hashes[*hash_pos - 1])
+    } else {
+        None
+    }
+}
@@ -0,0 +1,159 @@
+use gov_merkle::{
+    compute_multiproof_root, hash_meta_merkle_leaf, hash_stake_merkle_leaf, test_vectors::*,
+    verify, verify_multiproof, MerkleTree,
+};
+
+fn stake_leaf_hashes() -> Vec<[u8; 32]> {
+    STAKE_LEAVES.iter().map(|leaf| leaf.hash).collect()
+}
+
+#[test]
+fn test_leaf_hashes() {
+    for leaf in STAKE_LEAVES.iter() {
+        let hash = hash_stake_merkle_leaf(&VOTING_WALLET, &leaf.stake_account, leaf.active_stake);
+        assert_eq!(hash, leaf.hash);
+    }
+
+    let active_stake = STAKE_LEAVES.iter().map(|leaf| leaf.active_stake).sum();
+    let hash = hash_meta_merkle_leaf(
+        &VOTING_WALLET,
+        &VOTE_ACCOUNT,
+        &STAKE_MERKLE_ROOT,
+        active_stake,
+    );
+    assert_eq!(hash, META_MERKLE_LEAF_HASH);
+}
+
+#[test]
+fn test_tree_roots() {
+    assert_eq!(MerkleTree::new(&[]).root(), None);
+    for (i, root) in SEQUENTIAL_ROOTS.iter().enumerate() {
+        let leaf_contents: Vec<[u8; 32]> = (0..=i as u8).map(|j| [j; 32]).collect();
+        let tree = MerkleTree::new(&leaf_contents);
+        assert_eq!(tree.leaf_count(), i + 1);
+        assert_eq!(tree.root(), Some(*root));
+    }
+
+    let tree = MerkleTree::new(&stake_leaf_hashes());
+    assert_eq!(tree.root(), Some(STAKE_MERKLE_ROOT));
+
+    // A single leaf is its own root.
+    let tree = MerkleTree::new(&[META_MERKLE_LEAF_HASH]);
+    assert_eq!(tree.get_proof(0), Some(vec![]));
+    assert!(verify(&META_MERKLE_LEAF_HASH, &[], &tree.root().unwrap()));
+}
+
+#[test]
+fn test_proofs() {
+    let leaf_hashes = stake_leaf_hashes();
+    let tree = MerkleTree::new(&leaf_hashes);
+    for (i, proof) in STAKE_MERKLE_PROOFS.iter().enumerate() {
+        assert_eq!(tree.get_proof(i).as_deref(), Some(*proof));
+        assert!(verify(&leaf_hashes[i], proof, &STAKE_MERKLE_ROOT));
+        // The proof of one leaf doesn't verify another.
+        assert!(!verify(
+            &leaf_hashes[(i + 1) % leaf_hashes.len()],
+            proof,
+            &STAKE_MERKLE_ROOT
+        ));
+    }
+    assert_eq!(tree.get_proof(leaf_hashes.len()), None);
+
+    // Leaf contents are hashed with a prefix, so an intermediate node is not a valid leaf.
+    let proof = STAKE_MERKLE_PROOFS[0];
+    assert!(!verify(&proof[0], &proof[1..], &STAKE_MERKLE_ROOT));
+}
+
+#[test]
+fn test_multiproofs() {
+    let leaf_hashes = stake_leaf_hashes();
+    let tree = MerkleTree::new(&leaf_hashes);
+    for multiproof in STAKE_MERKLE_MULTIPROOFS.iter() {
+        let (proof, proof_flags) = tree.get_multiproof(multiproof.indices).unwrap();
+        assert_eq!(proof, multiproof.proof);
+        assert_eq!(proof_flags, multiproof.proof_flags);
+
+        let leaves: Vec<[u8; 32]> = multiproof.indices.iter().map(|&i| leaf_hashes[i]).collect();
+        assert!(verify_multiproof(
+            &leaves,
+            multiproof.proof,
+            multiproof.proof_flags,
+            &STAKE_MERKLE_ROOT
+        ));
+
+        let mut tampered = leaves.clone();
+        tampered[0][0] ^= 1;
+        assert!(!verify_multiproof(
+            &tampered,
+            multiproof.proof,
+            multiproof.proof_flags,
+            &STAKE_MERKLE_ROOT
+        ));
+    }
+
+    // Indices are deduplicated and sorted, and must be in range.
+    let (proof, proof_flags) = tree.get_multiproof(&[4, 3, 1, 3]).unwrap();
+    assert_eq!(proof, STAKE_MERKLE_MULTIPROOFS[1].proof);
+    assert_eq!(proof_flags, STAKE_MERKLE_MULTIPROOFS[1].proof_flags);
+    assert_eq!(tree.get_multiproof(&[0, leaf_hashes.len()]), None);
+
+    // A multiproof of a single leaf is a regular proof.
+    let (proof, proof_flags) = tree.get_multiproof(&[4]).unwrap();
+    assert_eq!(proof, STAKE_MERKLE_PROOFS[4]);
+    assert!(proof_flags.iter().all(|&flag| !flag));
+
+    // Malformed multiproofs have no root.
+    let multiproof = &STAKE_MERKLE_MULTIPROOFS[0];
+    let leaves = [leaf_hashes[0], leaf_hashes[1]];
+    assert_eq!(
+        compute_multiproof_root(&[], multiproof.proof, multiproof.proof_flags),
+        None
+    );
+    assert_eq!(
+        compute_multiproof_root(&leaves, &multiproof.proof[1..], multiproof.proof_flags),
+        None
+    );
+    assert_eq!(
+        compute_multiproof_root(&leaves, multiproof.proof, &[false, false, false]),
+        None
+    );
+}
+
+#[test]
+fn test_snapshot_vectors() {
+    // Stake leaves and the stake merkle root recorded in the meta merkle leaf.
+    let leaf_hashes: Vec<[u8; 32]> = SNAPSHOT_STAKE_LEAVES
+        .iter()
+        .map(|leaf| {
+            let hash =
+                hash_stake_merkle_leaf(&leaf.voting_wallet, &leaf.stake_account, leaf.active_stake);
+            assert_eq!(hash, leaf.hash);
+            hash
+        })
+        .collect();
+    let tree = MerkleTree::new(&leaf_hashes);
+    assert_eq!(tree.root(), Some(SNAPSHOT_STAKE_MERKLE_ROOT));
+    for (i, leaf_hash) in leaf_hashes.iter().enumerate() {
+        let proof = tree.get_proof(i).unwrap();
+        assert!(verify(leaf_hash, &proof, &SNAPSHOT_STAKE_MERKLE_ROOT));
+    }
+
+    // The proof stored in the snapshot verifies the meta merkle leaf against its root.
+    let hash = hash_meta_merkle_leaf(
+        &SNAPSHOT_VOTING_WALLET,
+        &SNAPSHOT_VOTE_ACCOUNT,
+        &SNAPSHOT_STAKE_MERKLE_ROOT,
+        SNAPSHOT_ACTIVE_STAKE,
+    );
+    assert_eq!(hash, SNAPSHOT_META_MERKLE_LEAF_HASH);
+    assert!(verify(
+        &hash,
+        &SNAPSHOT_META_MERKLE_PROOF,
+        &SNAPSHOT_META_MERKLE_ROOT
+    ));
+    assert!(!verify(
+        &hash,
+        &SNAPSHOT_META_MERKLE_PROOF[1..],
+        &SNAPSHOT_META_MERKLE_ROOT
+    ));
+}
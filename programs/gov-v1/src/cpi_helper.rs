@@ -0,0 +1,52 @@
+use anchor_lang::{prelude::*, solana_program::program::get_return_data};
+
+use crate::{error::ErrorCode, VerifiedStake, VerifiedStakeBatch};
+
+/// Reads the `VerifiedStake` set as return data by `verify_merkle_proof`.
+///
+/// Must be called directly after the CPI, since any later instruction or CPI that sets
+/// return data overwrites it. Fails if the return data was not set by this program.
+pub fn get_verified_stake() -> Result<VerifiedStake> {
+    read_return_data()
+}
+
+/// Reads the `VerifiedStakeBatch` set as return data by `verify_stake_merkle_proofs_batch`.
+///
+/// Same constraints as `get_verified_stake` apply.
+pub fn get_verified_stake_batch() -> Result<VerifiedStakeBatch> {
+    read_return_data()
+}
+
+fn read_return_data<T: AnchorDeserialize>() -> Result<T> {
+    let (program_id, data) = get_return_data().ok_or(ErrorCode::InvalidReturnData)?;
+    require_keys_eq!(program_id, crate::ID, ErrorCode::InvalidReturnData);
+    T::try_from_slice(&data).map_err(|_| ErrorCode::InvalidReturnData.into())
+}
+
+/// Invokes `verify_merkle_proof` through CPI and returns the verified leaf data.
+#[cfg(feature = "cpi")]
+pub fn verify_merkle_proof<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, crate::cpi::accounts::VerifyMerkleProof<'info>>,
+    stake_merkle_proof: Option<Vec<[u8; 32]>>,
+    stake_merkle_leaf: Option<crate::StakeMerkleLeaf>,
+) -> Result<VerifiedStake> {
+    crate::cpi::verify_merkle_proof(ctx, stake_merkle_proof, stake_merkle_leaf)?;
+    get_verified_stake()
+}
+
+/// Invokes `verify_stake_merkle_proofs_batch` through CPI and returns the summed stake.
+#[cfg(feature = "cpi")]
+pub fn verify_stake_merkle_proofs_batch<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, crate::cpi::accounts::VerifyStakeMerkleProofsBatch<'info>>,
+    stake_merkle_leaves: Vec<crate::StakeMerkleLeaf>,
+    stake_merkle_proof: Vec<[u8; 32]>,
+    proof_flags: Vec<bool>,
+) -> Result<VerifiedStakeBatch> {
+    crate::cpi::verify_stake_merkle_proofs_batch(
+        ctx,
+        stake_merkle_leaves,
+        stake_merkle_proof,
+        proof_flags,
+    )?;
+    get_verified_stake_batch()
+}
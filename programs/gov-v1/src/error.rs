@@ -28,4 +28,120 @@ pub enum ErrorCode {
     OverlappingWhitelistEntries,
     #[msg("Invalid ballot index")]
     InvalidBallotIndex,
+    #[msg("Invalid operator weight")]
+    InvalidOperatorWeight,
+    #[msg("Signer is not the voting wallet")]
+    InvalidVotingWallet,
+    #[msg("Proposal has been finalized")]
+    ProposalFinalized,
+    #[msg("Vote already cast")]
+    VoteAlreadyCast,
+    #[msg("Invalid return data")]
+    InvalidReturnData,
+    #[msg("Stake accounts must be unique")]
+    DuplicateStakeAccount,
+    #[msg("Stake accounts have different voting wallets")]
+    MixedVotingWallets,
+    #[msg("MetaMerkleProof is not sealed")]
+    MetaMerkleProofNotSealed,
+    #[msg("MetaMerkleProof is sealed")]
+    MetaMerkleProofSealed,
+    #[msg("MetaMerkleProof is incomplete")]
+    MetaMerkleProofIncomplete,
+    #[msg("Signer is not authorized")]
+    Unauthorized,
+    #[msg("Ballot not finalized")]
+    BallotNotFinalized,
+    #[msg("Grace period not elapsed")]
+    GracePeriodNotElapsed,
+    #[msg("Multisig is enabled, use an admin proposal")]
+    MultisigEnabled,
+    #[msg("Signer is not an admin")]
+    NotAdmin,
+    #[msg("Invalid admin threshold")]
+    InvalidAdminThreshold,
+    #[msg("Duplicate admins")]
+    DuplicateAdmin,
+    #[msg("Admin has approved")]
+    AdminHasApproved,
+    #[msg("Admin threshold not met")]
+    AdminThresholdNotMet,
+    #[msg("Invalid ballot box")]
+    InvalidBallotBox,
+    #[msg("No pending config change")]
+    NoPendingConfig,
+    #[msg("Pending config change not effective")]
+    PendingConfigNotEffective,
+    #[msg("Invalid target slot")]
+    InvalidTargetSlot,
+    #[msg("Snapshot slot does not match target slot")]
+    SnapshotSlotMismatch,
+    #[msg("Scheduled ballot boxes are disabled")]
+    ScheduledBallotBoxDisabled,
+    #[msg("Target slot not reached")]
+    TargetSlotNotReached,
+    #[msg("Balloting is paused")]
+    BallotingPaused,
+    #[msg("Verification is paused")]
+    VerificationPaused,
+    #[msg("Ballot box is cancelled")]
+    BallotBoxCancelled,
+    #[msg("Ballot is finalized")]
+    BallotFinalized,
+    #[msg("Invalid vote expiry")]
+    InvalidVoteExpiry,
+    #[msg("Max vote duration exceeded")]
+    MaxVoteDurationExceeded,
+    #[msg("Ballot box requires commit-reveal")]
+    CommitRevealEnabled,
+    #[msg("Ballot box does not use commit-reveal")]
+    CommitRevealDisabled,
+    #[msg("Commit window has closed")]
+    CommitWindowClosed,
+    #[msg("Reveal window not open")]
+    RevealWindowNotOpen,
+    #[msg("Revealed ballot does not match commitment")]
+    InvalidReveal,
+    #[msg("Operator already voted for this ballot")]
+    BallotUnchanged,
+    #[msg("Ballot box is not fully allocated")]
+    BallotBoxNotAllocated,
+    #[msg("Ballot box already initialized")]
+    BallotBoxAlreadyInitialized,
+    #[msg("Account already migrated")]
+    AccountAlreadyMigrated,
+    #[msg("Account already at full size")]
+    AccountAtFullSize,
+    #[msg("Consensus threshold not configured")]
+    ConsensusThresholdNotSet,
+    #[msg("Min. votes exceeds number of eligible operators")]
+    MinVotesUnreachable,
+    #[msg("Operator already whitelisted")]
+    OperatorAlreadyWhitelisted,
+    #[msg("Operator stats already recorded for ballot box")]
+    OperatorStatsAlreadyRecorded,
+    #[msg("Not allowed by expiry resolution")]
+    InvalidExpiryResolution,
+    #[msg("Ballot box failed")]
+    BallotBoxFailed,
+    #[msg("Account type cannot be migrated")]
+    AccountNotMigratable,
+    #[msg("Admin set changed since the proposal was created")]
+    StaleAdminProposal,
+    #[msg("Scheduled ballot box already created for epoch")]
+    ScheduledBallotBoxCreated,
+    #[msg("Voting has not ended")]
+    VotingNotEnded,
+    #[msg("All open ballot boxes must be passed")]
+    OpenBallotBoxesMissing,
+    #[msg("Ballot boxes must be sorted and unique")]
+    BallotBoxesNotSorted,
+    #[msg("Ballot box must be closed before archiving")]
+    BallotBoxNotClosed,
+    #[msg("Account must be migrated first")]
+    AccountNotMigrated,
+    #[msg("Too many open ballot boxes to rotate operator key")]
+    TooManyOpenBallotBoxes,
+    #[msg("Operator has an unrevealed commitment")]
+    OperatorCommitOpen,
 }
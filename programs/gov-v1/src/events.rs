@@ -0,0 +1,527 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    AdminAction, Ballot, DecisionMethod, ExpiryResolution, PendingConfig, PendingWhitelist,
+    ProgramConfig, ProgramConfigUpdate, ProposalStatus, VoteChoice,
+};
+
+#[event]
+#[derive(Debug)]
+pub struct ProgramConfigInitialized {
+    /// Authority of the ProgramConfig
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConfigUpdated {
+    /// Authority to be set to upon finalization of proposal.
+    pub proposed_authority: Option<Pubkey>,
+    /// Min. percentage of votes required to finalize a ballot.
+    pub min_consensus_threshold_bps: u16,
+    /// Admin allowed to decide the winning ballot if vote expires before consensus.
+    pub tie_breaker_admin: Pubkey,
+    /// Duration for which ballot box will be opened for voting.
+    pub vote_duration: i64,
+    /// Duration after finalization before a BallotBox can be closed.
+    pub close_grace_period: i64,
+    /// Delay before queued config and whitelist changes can be applied.
+    pub config_change_delay: i64,
+    /// Slot offset within each epoch targeted by scheduled ballot boxes.
+    pub scheduled_slot_offset: Option<u64>,
+    /// Key allowed to pause and unpause the program alongside the authority.
+    pub guardian: Pubkey,
+    /// Max. total duration a ballot box can be open for, including vote extensions.
+    pub max_vote_duration: i64,
+    /// Duration of the commit window of new ballot boxes. 0 disables commit-reveal.
+    pub commit_duration: i64,
+    /// Min. number of operators that must vote before a ballot can reach consensus.
+    pub min_votes: u16,
+    /// How ballot boxes that expire without consensus are resolved.
+    pub expiry_resolution: ExpiryResolution,
+    /// Min. percentage of votes the highest ballot needs to win under Plurality.
+    pub plurality_floor_bps: u16,
+}
+
+impl ConfigUpdated {
+    pub fn new(program_config: &ProgramConfig) -> Self {
+        Self {
+            proposed_authority: program_config.proposed_authority,
+            min_consensus_threshold_bps: program_config.min_consensus_threshold_bps,
+            tie_breaker_admin: program_config.tie_breaker_admin,
+            vote_duration: program_config.vote_duration,
+            close_grace_period: program_config.close_grace_period,
+            config_change_delay: program_config.config_change_delay,
+            scheduled_slot_offset: program_config.scheduled_slot_offset,
+            guardian: program_config.guardian,
+            max_vote_duration: program_config.max_vote_duration,
+            commit_duration: program_config.commit_duration,
+            min_votes: program_config.min_votes,
+            expiry_resolution: program_config.expiry_resolution,
+            plurality_floor_bps: program_config.plurality_floor_bps,
+        }
+    }
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AuthorityTransferred {
+    /// Previous authority
+    pub old_authority: Pubkey,
+    /// New authority
+    pub new_authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorWhitelistUpdated {
+    /// Operators whitelisted after the update.
+    pub whitelisted_operators: Vec<Pubkey>,
+    /// Voting weight of each whitelisted operator, in the same order as whitelisted_operators.
+    pub operator_weights: Vec<u16>,
+}
+
+impl OperatorWhitelistUpdated {
+    pub fn new(program_config: &ProgramConfig) -> Self {
+        Self {
+            whitelisted_operators: program_config.whitelisted_operators.clone(),
+            operator_weights: program_config.operator_weights.clone(),
+        }
+    }
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConfigUpdateQueued {
+    /// Queued update, including fields of a previously pending update
+    pub update: ProgramConfigUpdate,
+    /// Timestamp after which the update can be applied
+    pub effective_at: i64,
+}
+
+impl ConfigUpdateQueued {
+    pub fn new(pending_config: &PendingConfig) -> Self {
+        Self {
+            update: pending_config.update.clone(),
+            effective_at: pending_config.effective_at,
+        }
+    }
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorWhitelistUpdateQueued {
+    /// Operators whitelisted once the update is applied.
+    pub whitelisted_operators: Vec<Pubkey>,
+    /// Voting weight of each operator, in the same order as whitelisted_operators.
+    pub operator_weights: Vec<u16>,
+    /// Timestamp after which the update can be applied
+    pub effective_at: i64,
+}
+
+impl OperatorWhitelistUpdateQueued {
+    pub fn new(pending_whitelist: &PendingWhitelist) -> Self {
+        Self {
+            whitelisted_operators: pending_whitelist.whitelisted_operators.clone(),
+            operator_weights: pending_whitelist.operator_weights.clone(),
+            effective_at: pending_whitelist.effective_at,
+        }
+    }
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorKeyRotationProposed {
+    /// Whitelisted operator key being replaced
+    pub old_operator: Pubkey,
+    /// Key replacing the old operator key once accepted
+    pub new_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorKeyRotationCancelled {
+    /// Whitelisted operator key that proposed the rotation
+    pub old_operator: Pubkey,
+    /// Key that was proposed
+    pub new_operator: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorKeyRotated {
+    /// Operator key that was replaced
+    pub old_operator: Pubkey,
+    /// Operator key now whitelisted in its place
+    pub new_operator: Pubkey,
+    /// IDs of the open ballot boxes the key was replaced in
+    pub ballot_ids: Vec<u64>,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct PendingConfigCancelled {
+    /// Cancelled config update
+    pub pending_config: Option<PendingConfig>,
+    /// Cancelled whitelist update
+    pub pending_whitelist: Option<PendingWhitelist>,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct PauseUpdated {
+    /// Authority or guardian that updated the pause flags
+    pub signer: Pubkey,
+    /// Whether balloting is paused after the update
+    pub balloting_paused: bool,
+    /// Whether verification is paused after the update
+    pub verification_paused: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AdminsUpdated {
+    /// Admins allowed to create and approve AdminProposals.
+    pub admins: Vec<Pubkey>,
+    /// Min. number of admin approvals required to execute an AdminProposal.
+    pub admin_threshold: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AdminProposalCreated {
+    /// AdminProposal ID
+    pub proposal_id: u64,
+    /// Admin that created the proposal
+    pub proposer: Pubkey,
+    /// Action executed once the proposal is approved
+    pub action: AdminAction,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AdminProposalApproved {
+    /// AdminProposal ID
+    pub proposal_id: u64,
+    /// Admin that approved the proposal
+    pub admin: Pubkey,
+    /// Number of approvals after this approval
+    pub approvals: u8,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AdminProposalExecuted {
+    /// AdminProposal ID
+    pub proposal_id: u64,
+    /// Executed action
+    pub action: AdminAction,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AdminProposalCancelled {
+    /// AdminProposal ID
+    pub proposal_id: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BallotBoxInitialized {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Operator that created the ballot box, or the payer of a scheduled ballot box
+    pub operator: Pubkey,
+    /// The epoch this ballot box is for
+    pub epoch: u64,
+    /// Slot of the snapshot operators vote on
+    pub target_slot: u64,
+    /// Timestamp when the commit window ends. 0 if commit-reveal is disabled.
+    pub commit_expiry_timestamp: i64,
+    /// Timestamp when voting ends
+    pub vote_expiry_timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteCast {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The operator that cast the vote
+    pub operator: Pubkey,
+    /// The ballot voted for
+    pub ballot: Ballot,
+    /// The index of the ballot in the ballot_tallies
+    pub ballot_index: u8,
+    /// Voting weight of the operator
+    pub weight: u32,
+    /// Tally of the ballot after the vote
+    pub tally: u32,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteChanged {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The operator that changed the vote
+    pub operator: Pubkey,
+    /// The index of the previous ballot in the ballot_tallies
+    pub previous_ballot_index: u8,
+    /// Tally of the previous ballot after the vote was moved
+    pub previous_tally: u32,
+    /// The ballot voted for
+    pub ballot: Ballot,
+    /// The index of the ballot in the ballot_tallies
+    pub ballot_index: u8,
+    /// Voting weight of the operator
+    pub weight: u32,
+    /// Tally of the ballot after the vote
+    pub tally: u32,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteCommitted {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The operator that committed
+    pub operator: Pubkey,
+    /// Commitment to the ballot, revealed after the commit window
+    pub commitment: [u8; 32],
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorCommitClosed {
+    /// BallotBox the commitment was for
+    pub ballot_box: Pubkey,
+    /// The operator that committed
+    pub operator: Pubkey,
+    /// Wallet that received the reclaimed rent
+    pub payer: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteRemoved {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The operator that removed the vote
+    pub operator: Pubkey,
+    /// The index of the ballot in the ballot_tallies
+    pub ballot_index: u8,
+    /// Voting weight of the operator
+    pub weight: u32,
+    /// Tally of the ballot after the vote was removed
+    pub tally: u32,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConsensusReached {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The winning ballot
+    pub ballot: Ballot,
+    /// Tally of the winning ballot when consensus was reached
+    pub tally: u32,
+    /// Slot when consensus was reached
+    pub slot: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct TieBreakerSet {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Admin that selected the winning ballot
+    pub tie_breaker_admin: Pubkey,
+    /// The index of the winning ballot in the ballot_tallies
+    pub ballot_index: u8,
+    /// The winning ballot
+    pub ballot: Ballot,
+    /// Slot when the tie breaker was set
+    pub slot: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteExpiryExtended {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Timestamp when voting ends after the extension
+    pub vote_expiry_timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ExpiredBallotFinalized {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Policy the ballot box was resolved with
+    pub expiry_resolution: ExpiryResolution,
+    /// The winning ballot, or None if the ballot box failed
+    pub ballot: Option<Ballot>,
+    /// Tally of the winning ballot. 0 if the ballot box failed.
+    pub tally: u32,
+    /// Slot when the ballot box was resolved
+    pub slot: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BallotBoxCancelled {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Timestamp when the ballot box was cancelled
+    pub cancelled_timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BallotFinalized {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The created ConsensusResult
+    pub consensus_result: Pubkey,
+    /// The winning ballot
+    pub ballot: Ballot,
+    /// Whether the ballot won by operator consensus or was selected by the tie breaker
+    pub decision_method: DecisionMethod,
+    /// Sum of weights of operators that voted for the ballot
+    pub tally: u32,
+    /// Tally as a percentage of the total weight, in basis points
+    pub tally_bps: u16,
+    /// Timestamp when the ConsensusResult was created
+    pub finalized_timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct OperatorStatsRecorded {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The operator whose stats recorded the finalized ballot box
+    pub operator: Pubkey,
+    /// Whether the operator voted for the winning ballot
+    pub voted_for_winning_ballot: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BallotBoxMigrated {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Account size after migration
+    pub size: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AccountMigrated {
+    /// Migrated account
+    pub account: Pubkey,
+    /// Layout version after migration
+    pub version: u8,
+    /// Account size after migration
+    pub size: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct BallotBoxClosed {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// Wallet that received the reclaimed rent
+    pub payer: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConsensusResultArchived {
+    /// Ballot ID
+    pub ballot_id: u64,
+    /// The archived ballot
+    pub ballot: Ballot,
+    /// Wallet that received the reclaimed rent
+    pub payer: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MetaMerkleProofInitialized {
+    /// ConsensusResult proof is created for.
+    pub consensus_result: Pubkey,
+    /// Validator's vote account.
+    pub vote_account: Pubkey,
+    /// Payer wallet
+    pub payer: Pubkey,
+    /// True if the proof was verified on initialization.
+    pub sealed: bool,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MetaMerkleProofSealed {
+    /// ConsensusResult proof is created for.
+    pub consensus_result: Pubkey,
+    /// Validator's vote account.
+    pub vote_account: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MetaMerkleProofClosed {
+    /// ConsensusResult proof was created for.
+    pub consensus_result: Pubkey,
+    /// Validator's vote account.
+    pub vote_account: Pubkey,
+    /// Wallet that received the reclaimed rent
+    pub payer: Pubkey,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalCreated {
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+    /// ConsensusResult whose snapshot determines voting weights.
+    pub consensus_result: Pubkey,
+    /// Timestamp when voting ends
+    pub vote_expiry_timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalVoteCast {
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Voting wallet that cast the vote
+    pub voting_wallet: Pubkey,
+    /// Vote account whose MetaMerkleLeaf was proven
+    pub vote_account: Pubkey,
+    /// Stake account whose StakeMerkleLeaf was proven. None if voted with the vote account.
+    pub stake_account: Option<Pubkey>,
+    /// Vote choice
+    pub choice: VoteChoice,
+    /// Stake counted towards the choice
+    pub stake: u64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalFinalized {
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Final status of the proposal
+    pub status: ProposalStatus,
+    /// Stake voting for the proposal
+    pub for_stake: u64,
+    /// Stake voting against the proposal
+    pub against_stake: u64,
+    /// Stake abstaining from the proposal
+    pub abstain_stake: u64,
+}
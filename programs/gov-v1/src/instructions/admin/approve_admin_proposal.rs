@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminProposal, AdminProposalApproved, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    let program_config = &ctx.accounts.program_config;
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+    admin_proposal.require_admin_set(program_config.admin_set_id)?;
+    admin_proposal.approve(program_config.admin_index(&admin)?)?;
+
+    emit!(AdminProposalApproved {
+        proposal_id: admin_proposal.proposal_id,
+        admin,
+        approvals: admin_proposal.approval_count(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminProposal, AdminProposalCancelled};
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    emit!(AdminProposalCancelled {
+        proposal_id: ctx.accounts.admin_proposal.proposal_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminAction, AdminProposal, AdminProposalCreated, ProgramConfig};
+
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct CreateAdminProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub proposer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"AdminProposal".as_ref(),
+            &program_config.next_admin_proposal_id.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + AdminProposal::init_space(&action)
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAdminProposal>, action: AdminAction) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let proposer = ctx.accounts.proposer.key();
+    let admin_index = program_config.admin_index(&proposer)?;
+
+    // The proposer approves the proposal on creation.
+    let admin_proposal = &mut ctx.accounts.admin_proposal;
+    admin_proposal.proposal_id = program_config.next_admin_proposal_id;
+    admin_proposal.bump = ctx.bumps.admin_proposal;
+    admin_proposal.proposer = proposer;
+    admin_proposal.action = action;
+    admin_proposal.admin_set_id = program_config.admin_set_id;
+    admin_proposal.approve(admin_index)?;
+
+    emit!(AdminProposalCreated {
+        proposal_id: admin_proposal.proposal_id,
+        proposer,
+        action: admin_proposal.action.clone(),
+    });
+
+    // Increment for next admin proposal
+    program_config.next_admin_proposal_id = program_config
+        .next_admin_proposal_id
+        .checked_add(1)
+        .unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    apply_effective_changes, error::ErrorCode, AdminAction, AdminProposal, AdminProposalExecuted,
+    AdminsUpdated, BallotBox, BallotBoxCancelled, ConfigUpdateQueued, ExpiryResolution,
+    OperatorWhitelistUpdateQueued, PendingConfigCancelled, ProgramConfig, TieBreakerSet,
+    VoteExpiryExtended,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteAdminProposal<'info> {
+    /// CHECK: Proposer of the AdminProposal, receives the lamports when the proposal is closed.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    /// Required only for actions on a ballot box.
+    #[account(mut)]
+    pub ballot_box: Option<AccountLoader<'info, BallotBox>>,
+}
+
+pub fn handler(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let admin_proposal = &ctx.accounts.admin_proposal;
+    require!(
+        program_config.is_multisig_enabled(),
+        ErrorCode::AdminThresholdNotMet
+    );
+    admin_proposal.require_admin_set(program_config.admin_set_id)?;
+    require_gte!(
+        admin_proposal.approval_count(),
+        program_config.admin_threshold,
+        ErrorCode::AdminThresholdNotMet
+    );
+
+    let clock = Clock::get()?;
+    match admin_proposal.action.clone() {
+        // Config changes are subject to the same delay as single authority changes.
+        AdminAction::UpdateOperatorWhitelist(update) => {
+            let pending_whitelist =
+                program_config.queue_operator_whitelist_update(update, clock.unix_timestamp)?;
+            emit!(OperatorWhitelistUpdateQueued::new(pending_whitelist));
+            apply_effective_changes(program_config, clock.unix_timestamp)?;
+        }
+        AdminAction::UpdateProgramConfig(update) => {
+            let pending_config =
+                program_config.queue_config_update(update, clock.unix_timestamp)?;
+            emit!(ConfigUpdateQueued::new(pending_config));
+            apply_effective_changes(program_config, clock.unix_timestamp)?;
+        }
+        AdminAction::CancelPendingConfig => {
+            require!(
+                program_config.has_pending_changes(),
+                ErrorCode::NoPendingConfig
+            );
+            emit!(PendingConfigCancelled {
+                pending_config: program_config.pending_config.take(),
+                pending_whitelist: program_config.pending_whitelist.take(),
+            });
+        }
+        AdminAction::SetTieBreaker {
+            ballot_id,
+            ballot_index,
+        } => {
+            program_config.require_expiry_resolution(ExpiryResolution::TieBreakerAdmin)?;
+            let ballot_box = &mut target_ballot_box(&ctx.accounts.ballot_box, ballot_id)?;
+            ballot_box.set_tie_breaker(ballot_index, &clock)?;
+            emit!(TieBreakerSet {
+                ballot_id,
+                tie_breaker_admin: admin_proposal.proposer,
+                ballot_index,
+                ballot: ballot_box.winning_ballot,
+                slot: clock.slot,
+            });
+        }
+        AdminAction::ExtendVoteExpiry {
+            ballot_id,
+            vote_expiry_timestamp,
+        } => {
+            let ballot_box = &mut target_ballot_box(&ctx.accounts.ballot_box, ballot_id)?;
+            ballot_box.extend_vote_expiry(
+                vote_expiry_timestamp,
+                program_config.max_vote_duration,
+                clock.unix_timestamp,
+            )?;
+            emit!(VoteExpiryExtended {
+                ballot_id,
+                vote_expiry_timestamp,
+            });
+        }
+        AdminAction::CancelBallotBox { ballot_id } => {
+            let ballot_box = &mut target_ballot_box(&ctx.accounts.ballot_box, ballot_id)?;
+            ballot_box.cancel(clock.unix_timestamp)?;
+            program_config.record_ballot_box_ended();
+            emit!(BallotBoxCancelled {
+                ballot_id,
+                cancelled_timestamp: ballot_box.cancelled_timestamp,
+            });
+        }
+        AdminAction::SetAdmins { admins, threshold } => {
+            program_config.set_admins(admins, threshold)?;
+            emit!(AdminsUpdated {
+                admins: program_config.admins.clone(),
+                admin_threshold: program_config.admin_threshold,
+            });
+        }
+    }
+
+    emit!(AdminProposalExecuted {
+        proposal_id: admin_proposal.proposal_id,
+        action: admin_proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+/// Returns the ballot box account, which must be the one targeted by the action.
+fn target_ballot_box<'a, 'info>(
+    ballot_box: &'a Option<AccountLoader<'info, BallotBox>>,
+    ballot_id: u64,
+) -> Result<RefMut<'a, BallotBox>> {
+    let ballot_box = ballot_box
+        .as_ref()
+        .ok_or(ErrorCode::InvalidBallotBox)?
+        .load_mut()?;
+    require!(
+        ballot_box.ballot_id == ballot_id,
+        ErrorCode::InvalidBallotBox
+    );
+    Ok(ballot_box)
+}
@@ -0,0 +1,11 @@
+pub mod approve_admin_proposal;
+pub mod cancel_admin_proposal;
+pub mod create_admin_proposal;
+pub mod execute_admin_proposal;
+pub mod set_admins;
+
+pub use approve_admin_proposal::*;
+pub use cancel_admin_proposal::*;
+pub use create_admin_proposal::*;
+pub use execute_admin_proposal::*;
+pub use set_admins::*;
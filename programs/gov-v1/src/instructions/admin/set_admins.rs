@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminsUpdated, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct SetAdmins<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<SetAdmins>, admins: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    // Once enabled, the admin set can only be changed through an AdminProposal.
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.require_multisig_disabled()?;
+    program_config.set_admins(admins, threshold)?;
+
+    emit!(AdminsUpdated {
+        admins: program_config.admins.clone(),
+        admin_threshold: program_config.admin_threshold,
+    });
+
+    Ok(())
+}
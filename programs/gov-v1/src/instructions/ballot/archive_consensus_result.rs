@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, ConsensusResult, ConsensusResultArchived, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct ArchiveConsensusResult<'info> {
+    pub authority: Signer<'info>,
+    /// Account to receive the reclaimed rent from ConsensusResult
+    /// CHECK: must match payer in ConsensusResult
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer
+    )]
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    /// CHECK: BallotBox of the consensus result, which must be closed
+    #[account(address = consensus_result.ballot_box @ ErrorCode::InvalidBallotBox)]
+    pub ballot_box: UncheckedAccount<'info>,
+    #[account(has_one = authority)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ArchiveConsensusResult>) -> Result<()> {
+    // Otherwise the ballot box could be finalized again once the consensus result is closed.
+    require!(
+        ctx.accounts.ballot_box.data_is_empty(),
+        ErrorCode::BallotBoxNotClosed
+    );
+
+    // Log the result so that it remains retrievable from transaction history once closed.
+    let consensus_result = &ctx.accounts.consensus_result;
+    emit!(ConsensusResultArchived {
+        ballot_id: consensus_result.ballot_id,
+        ballot: consensus_result.ballot,
+        payer: consensus_result.payer,
+    });
+
+    Ok(())
+}
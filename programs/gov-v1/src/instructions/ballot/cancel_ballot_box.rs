@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{BallotBox, BallotBoxCancelled, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct CancelBallotBox<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(mut, has_one = authority)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<CancelBallotBox>) -> Result<()> {
+    ctx.accounts.program_config.require_multisig_disabled()?;
+
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    ballot_box.cancel(Clock::get()?.unix_timestamp)?;
+    ctx.accounts.program_config.record_ballot_box_ended();
+
+    emit!(BallotBoxCancelled {
+        ballot_id: ballot_box.ballot_id,
+        cancelled_timestamp: ballot_box.cancelled_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,89 +1,93 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    error::ErrorCode,
-    state::ballot_box::{MAX_BALLOT_TALLIES, MAX_OPERATOR_VOTES},
-    Ballot, BallotBox, BallotTally, OperatorVote, ProgramConfig,
+    error::ErrorCode, Ballot, BallotBox, ConsensusReached, OperatorStats, ProgramConfig, VoteCast,
 };
 
 #[derive(Accounts)]
 pub struct CastVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     pub operator: Signer<'info>,
     #[account(mut)]
-    pub ballot_box: Box<Account<'info, BallotBox>>,
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        init_if_needed,
+        seeds = [b"OperatorStats".as_ref(), operator.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + OperatorStats::INIT_SPACE
+    )]
+    pub operator_stats: Box<Account<'info, OperatorStats>>,
+    #[account(constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused)]
     pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CastVote>, ballot: Ballot) -> Result<()> {
-    let operator = &ctx.accounts.operator.key();
-    let ballot_box = &mut ctx.accounts.ballot_box;
-    let program_config = &ctx.accounts.program_config;
-    program_config.contains_operator(operator)?;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    require!(
+        !ballot_box.is_commit_reveal(),
+        ErrorCode::CommitRevealEnabled
+    );
+
+    let operator_stats = &mut ctx.accounts.operator_stats;
+    operator_stats.operator = ctx.accounts.operator.key();
+
+    cast_vote_shared_handler(
+        ballot_box,
+        operator_stats,
+        &ctx.accounts.operator.key(),
+        ballot,
+        &Clock::get()?,
+    )
+}
+
+/// Records the operator's vote for the ballot and sets the winning ballot once the
+/// consensus threshold is reached. Shared by direct votes and commit-reveal reveals.
+pub fn cast_vote_shared_handler(
+    ballot_box: &mut BallotBox,
+    operator_stats: &mut OperatorStats,
+    operator: &Pubkey,
+    ballot: Ballot,
+    clock: &Clock,
+) -> Result<()> {
+    let weight = u32::from(ballot_box.operator_weight(operator)?);
 
-    let clock = Clock::get()?;
+    require!(!ballot_box.is_cancelled(), ErrorCode::BallotBoxCancelled);
+    require!(!ballot_box.is_finalized(), ErrorCode::BallotFinalized);
     require!(
         !ballot_box.has_vote_expired(clock.unix_timestamp),
         ErrorCode::VotingExpired
     );
-    require!(ballot.meta_merkle_root != [0; 32], ErrorCode::InvalidBallot);
+    ballot_box.validate_ballot(&ballot)?;
 
-    let operator_vote = ballot_box
-        .operator_votes
-        .iter()
-        .find(|vote| vote.operator == *operator);
-    require!(operator_vote.is_none(), ErrorCode::OperatorHasVoted);
+    require!(
+        ballot_box.operator_vote(operator).is_none(),
+        ErrorCode::OperatorHasVoted
+    );
 
-    // Look for ballot within ballot_tallies first. If ballot already exists,
-    // increment vote on ballot.
-    let mut ballot_index = 0;
-    let mut found = false;
-    let mut tally = 0;
-    for ballot_tally in &mut ballot_box.ballot_tallies {
-        if ballot_tally.ballot == ballot {
-            ballot_tally.tally = ballot_tally.tally.checked_add(1).unwrap();
-            ballot_index = ballot_tally.index;
-            tally = ballot_tally.tally;
-            found = true;
-            break;
-        }
-    }
+    let (ballot_index, tally) = ballot_box.add_tally(&ballot, weight)?;
+    ballot_box.record_vote(operator, ballot_index, clock.slot)?;
+    operator_stats.record_vote(clock.slot, ballot_box.slot_created);
 
-    // If ballot is new, create a new BallotTally.
-    if !found {
-        let new_ballot_tally = BallotTally {
-            index: ballot_box.ballot_tallies.len().try_into().unwrap(),
-            ballot: ballot.clone(),
-            tally: 1,
-        };
-        tally = 1;
-        ballot_index = new_ballot_tally.index;
-        ballot_box.ballot_tallies.push(new_ballot_tally);
-        require!(
-            ballot_box.ballot_tallies.len() <= MAX_BALLOT_TALLIES,
-            ErrorCode::VecFull
-        );
-    }
-
-    // Create a new operator vote for the ballot tally.
-    let new_operator_vote = OperatorVote {
-        operator: ctx.accounts.operator.key(),
-        slot_voted: clock.slot,
+    emit!(VoteCast {
+        ballot_id: ballot_box.ballot_id,
+        operator: *operator,
+        ballot,
         ballot_index,
-    };
-    ballot_box.operator_votes.push(new_operator_vote);
-    require!(
-        ballot_box.operator_votes.len() <= MAX_OPERATOR_VOTES,
-        ErrorCode::VecFull
-    );
+        weight,
+        tally,
+    });
 
-    // Set winning ballot if consensus threshold is reached (for first time).
-    if !ballot_box.has_consensus_reached() {
-        let tally_bps =
-            u64::from(tally) * 10000 / (program_config.whitelisted_operators.len() as u64);
-        if tally_bps >= ballot_box.min_consensus_threshold_bps.into() {
-            ballot_box.slot_consensus_reached = clock.slot;
-            ballot_box.winning_ballot = ballot;
-        }
+    // Set winning ballot if the quorum and consensus threshold are reached (for first time).
+    if let Some(winning_tally) = ballot_box.try_reach_consensus(clock.slot) {
+        emit!(ConsensusReached {
+            ballot_id: ballot_box.ballot_id,
+            ballot: ballot_box.winning_ballot,
+            tally: winning_tally,
+            slot: clock.slot,
+        });
     }
 
     Ok(())
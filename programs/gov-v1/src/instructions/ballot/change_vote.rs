@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Ballot, BallotBox, ConsensusReached, ProgramConfig, VoteChanged};
+
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub operator: Signer<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ChangeVote>, ballot: Ballot) -> Result<()> {
+    let operator = &ctx.accounts.operator.key();
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    let weight = u32::from(ballot_box.operator_weight(operator)?);
+    let clock = Clock::get()?;
+
+    require!(!ballot_box.is_cancelled(), ErrorCode::BallotBoxCancelled);
+    require!(
+        !ballot_box.is_commit_reveal(),
+        ErrorCode::CommitRevealEnabled
+    );
+    require!(
+        !ballot_box.has_vote_expired(clock.unix_timestamp),
+        ErrorCode::VotingExpired
+    );
+    require!(
+        !ballot_box.has_consensus_reached(),
+        ErrorCode::ConsensusReached
+    );
+    ballot_box.validate_ballot(&ballot)?;
+
+    let previous_ballot_index = ballot_box
+        .operator_vote(operator)
+        .ok_or(ErrorCode::OperatorHasNotVoted)?
+        .ballot_index;
+    require!(
+        ballot_box.ballot_tallies()[previous_ballot_index as usize].ballot != ballot,
+        ErrorCode::BallotUnchanged
+    );
+
+    // Remove the weight from the previous ballot first, so its BallotTally can be reused
+    // if the operator was its only voter.
+    ballot_box.clear_vote(operator)?;
+    let previous_tally = ballot_box.remove_tally(previous_ballot_index, weight);
+    let (ballot_index, tally) = ballot_box.add_tally(&ballot, weight)?;
+    ballot_box.record_vote(operator, ballot_index, clock.slot)?;
+
+    emit!(VoteChanged {
+        ballot_id: ballot_box.ballot_id,
+        operator: *operator,
+        previous_ballot_index,
+        previous_tally,
+        ballot,
+        ballot_index,
+        weight,
+        tally,
+    });
+
+    if let Some(winning_tally) = ballot_box.try_reach_consensus(clock.slot) {
+        emit!(ConsensusReached {
+            ballot_id: ballot_box.ballot_id,
+            ballot: ballot_box.winning_ballot,
+            tally: winning_tally,
+            slot: clock.slot,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BallotBox, BallotBoxClosed, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct CloseBallotBox<'info> {
+    /// Authority in ProgramConfig or payer in BallotBox
+    pub signer: Signer<'info>,
+    /// Account to receive the reclaimed rent from BallotBox
+    /// CHECK: must match payer in BallotBox
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer
+    )]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<CloseBallotBox>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.program_config.authority || signer == ctx.accounts.payer.key(),
+        ErrorCode::Unauthorized
+    );
+
+    // Cancelled and failed ballot boxes can be closed like finalized ones. A failed ballot
+    // box ended when voting expired.
+    let ballot_box = &ctx.accounts.ballot_box.load()?;
+    let ended_timestamp = if ballot_box.is_cancelled() {
+        ballot_box.cancelled_timestamp
+    } else if ballot_box.is_failed() {
+        ballot_box.vote_expiry_timestamp
+    } else {
+        require!(ballot_box.is_finalized(), ErrorCode::BallotNotFinalized);
+        ballot_box.finalized_timestamp
+    };
+
+    // Check that the grace period after the ballot box ended has elapsed.
+    let close_timestamp = ended_timestamp
+        .checked_add(ctx.accounts.program_config.close_grace_period)
+        .unwrap();
+    require_gte!(
+        Clock::get()?.unix_timestamp,
+        close_timestamp,
+        ErrorCode::GracePeriodNotElapsed
+    );
+
+    emit!(BallotBoxClosed {
+        ballot_id: ballot_box.ballot_id,
+        payer: ballot_box.payer,
+    });
+
+    Ok(())
+}
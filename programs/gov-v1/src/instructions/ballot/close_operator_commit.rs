@@ -0,0 +1,45 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{error::ErrorCode, BallotBox, OperatorCommit, OperatorCommitClosed};
+
+#[derive(Accounts)]
+pub struct CloseOperatorCommit<'info> {
+    /// Account to receive the reclaimed rent from OperatorCommit
+    /// CHECK: must match payer in OperatorCommit
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK: BallotBox of the commitment, which may already be closed. Read in the handler.
+    pub ballot_box: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        has_one = ballot_box
+    )]
+    pub operator_commit: Box<Account<'info, OperatorCommit>>,
+}
+
+/// Closes a commitment that was not revealed, returning its rent to the payer. Permissionless
+/// once voting on the ballot box has ended, since the commitment can no longer be revealed.
+pub fn handler(ctx: Context<CloseOperatorCommit>) -> Result<()> {
+    let ballot_box = &ctx.accounts.ballot_box;
+    let data = ballot_box.try_borrow_data()?;
+    // A closed ballot box has ended.
+    if ballot_box.owner == &crate::ID && data.starts_with(BallotBox::DISCRIMINATOR) {
+        require_eq!(data.len(), BallotBox::SIZE, ErrorCode::InvalidBallotBox);
+        let ballot_box: &BallotBox = bytemuck::from_bytes(&data[BallotBox::DISCRIMINATOR.len()..]);
+        require!(
+            ballot_box.has_voting_ended(Clock::get()?.unix_timestamp),
+            ErrorCode::VotingNotEnded
+        );
+    }
+
+    let operator_commit = &ctx.accounts.operator_commit;
+    emit!(OperatorCommitClosed {
+        ballot_box: operator_commit.ballot_box,
+        operator: operator_commit.operator,
+        payer: operator_commit.payer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, BallotBox, OperatorCommit, OperatorStats, ProgramConfig, VoteCommitted,
+};
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub operator: Signer<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        init,
+        seeds = [
+            b"OperatorCommit".as_ref(),
+            ballot_box.key().as_ref(),
+            operator.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + OperatorCommit::INIT_SPACE
+    )]
+    pub operator_commit: Box<Account<'info, OperatorCommit>>,
+    // Created here so that the reveal, which has no signing payer, can record the vote.
+    #[account(
+        init_if_needed,
+        seeds = [b"OperatorStats".as_ref(), operator.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + OperatorStats::INIT_SPACE
+    )]
+    pub operator_stats: Box<Account<'info, OperatorStats>>,
+    #[account(constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+    let operator = ctx.accounts.operator.key();
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    ballot_box.operator_weight(&operator)?;
+
+    let clock = Clock::get()?;
+    require!(!ballot_box.is_cancelled(), ErrorCode::BallotBoxCancelled);
+    require!(
+        ballot_box.is_commit_reveal(),
+        ErrorCode::CommitRevealDisabled
+    );
+    require!(
+        !ballot_box.has_commit_expired(clock.unix_timestamp),
+        ErrorCode::CommitWindowClosed
+    );
+
+    let operator_commit = &mut ctx.accounts.operator_commit;
+    operator_commit.ballot_box = ctx.accounts.ballot_box.key();
+    operator_commit.operator = operator;
+    operator_commit.payer = ctx.accounts.payer.key();
+    operator_commit.commitment = commitment;
+    operator_commit.slot_committed = clock.slot;
+    ballot_box.record_commit(&operator)?;
+    ctx.accounts.operator_stats.operator = operator;
+
+    emit!(VoteCommitted {
+        ballot_id: ballot_box.ballot_id,
+        operator,
+        commitment,
+    });
+
+    Ok(())
+}
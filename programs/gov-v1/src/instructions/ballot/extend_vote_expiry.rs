@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{BallotBox, ProgramConfig, VoteExpiryExtended};
+
+#[derive(Accounts)]
+pub struct ExtendVoteExpiry<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(has_one = authority)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ExtendVoteExpiry>, vote_expiry_timestamp: i64) -> Result<()> {
+    let program_config = &ctx.accounts.program_config;
+    program_config.require_multisig_disabled()?;
+
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    ballot_box.extend_vote_expiry(
+        vote_expiry_timestamp,
+        program_config.max_vote_duration,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    emit!(VoteExpiryExtended {
+        ballot_id: ballot_box.ballot_id,
+        vote_expiry_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,36 +1,66 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::ErrorCode, BallotBox, ConsensusResult};
+use crate::{error::ErrorCode, BallotBox, BallotFinalized, ConsensusResult, ProgramConfig};
 
 #[derive(Accounts)]
 pub struct FinalizeBallot<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    pub ballot_box: Box<Account<'info, BallotBox>>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
     #[account(
         init,
         seeds = [
             b"ConsensusResult".as_ref(),
-            &ballot_box.ballot_id.to_le_bytes()
+            &ballot_box.load()?.ballot_id.to_le_bytes()
         ],
         bump,
         payer = payer,
         space = 8 + ConsensusResult::INIT_SPACE
     )]
     pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(
+        mut,
+        constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<FinalizeBallot>) -> Result<()> {
-    let ballot_box = &ctx.accounts.ballot_box;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    require!(!ballot_box.is_cancelled(), ErrorCode::BallotBoxCancelled);
+    require!(!ballot_box.is_finalized(), ErrorCode::BallotFinalized);
     require!(
         ballot_box.has_consensus_reached(),
         ErrorCode::ConsensusNotReached
     );
+    ballot_box.finalized_timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.program_config.record_ballot_box_ended();
 
     let consensus_result = &mut ctx.accounts.consensus_result;
     consensus_result.ballot_id = ballot_box.ballot_id;
-    consensus_result.ballot = ballot_box.winning_ballot.clone();
+    consensus_result.ballot = ballot_box.winning_ballot;
+    consensus_result.payer = ctx.accounts.payer.key();
+    consensus_result.decision_method = ballot_box.decision_method();
+    consensus_result.epoch = ballot_box.epoch;
+    consensus_result.slot_consensus_reached = ballot_box.slot_consensus_reached;
+    consensus_result.tally = ballot_box.winning_tally();
+    consensus_result.total_weight = ballot_box.total_weight;
+    consensus_result.tally_bps = ballot_box.tally_bps(consensus_result.tally);
+    consensus_result.finalized_timestamp = ballot_box.finalized_timestamp;
+    consensus_result.version = ConsensusResult::VERSION;
+    consensus_result.ballot_box = ctx.accounts.ballot_box.key();
+
+    emit!(BallotFinalized {
+        ballot_id: ballot_box.ballot_id,
+        consensus_result: consensus_result.key(),
+        ballot: consensus_result.ballot,
+        decision_method: consensus_result.decision_method,
+        tally: consensus_result.tally,
+        tally_bps: consensus_result.tally_bps,
+        finalized_timestamp: ballot_box.finalized_timestamp,
+    });
 
     Ok(())
 }
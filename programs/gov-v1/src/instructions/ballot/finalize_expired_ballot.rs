@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BallotBox, ExpiredBallotFinalized, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct FinalizeExpiredBallot<'info> {
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        mut,
+        constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<FinalizeExpiredBallot>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    let clock = Clock::get()?;
+    let tally = ballot_box.finalize_expired(
+        program_config.expiry_resolution,
+        program_config.plurality_floor_bps,
+        program_config.close_grace_period,
+        &clock,
+    )?;
+    if ballot_box.is_failed() {
+        program_config.record_ballot_box_ended();
+    }
+
+    emit!(ExpiredBallotFinalized {
+        ballot_id: ballot_box.ballot_id,
+        expiry_resolution: program_config.expiry_resolution,
+        ballot: tally.map(|_| ballot_box.winning_ballot),
+        tally: tally.unwrap_or(0),
+        slot: clock.slot,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{BallotBox, ProgramConfig};
+use crate::{error::ErrorCode, BallotBox, BallotBoxInitialized, ProgramConfig};
 
 #[derive(Accounts)]
 pub struct InitBallotBox<'info> {
@@ -8,40 +8,50 @@ pub struct InitBallotBox<'info> {
     pub payer: Signer<'info>,
     pub operator: Signer<'info>,
     #[account(
-        init,
+        zero,
         seeds = [
             b"BallotBox".as_ref(),
             &program_config.next_ballot_id.to_le_bytes()
         ],
         bump,
-        payer = payer,
-        space = 8 + BallotBox::INIT_SPACE
+        constraint = ballot_box.to_account_info().data_len() == BallotBox::SIZE
+            @ ErrorCode::BallotBoxNotAllocated
+    )]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        mut,
+        constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused
     )]
-    pub ballot_box: Box<Account<'info, BallotBox>>,
-    #[account(mut)]
     pub program_config: Box<Account<'info, ProgramConfig>>,
-    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitBallotBox>) -> Result<()> {
+pub fn handler(ctx: Context<InitBallotBox>, target_slot: u64) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
     program_config.contains_operator(&ctx.accounts.operator.key())?;
 
-    let ballot_box = &mut ctx.accounts.ballot_box;
-    ballot_box.ballot_id = program_config.next_ballot_id;
-    ballot_box.bump = ctx.bumps.ballot_box;
-
+    let ballot_box = &mut ctx.accounts.ballot_box.load_init()?;
     let clock = Clock::get()?;
-    ballot_box.epoch = clock.epoch;
-    ballot_box.slot_created = clock.slot;
-    ballot_box.min_consensus_threshold_bps = program_config.min_consensus_threshold_bps;
-    ballot_box.vote_expiry_timestamp = clock
-        .unix_timestamp
-        .checked_add(program_config.vote_duration)
-        .unwrap();
+    ballot_box.init(
+        program_config.next_ballot_id,
+        ctx.bumps.ballot_box,
+        ctx.accounts.payer.key(),
+        target_slot,
+        program_config,
+        &clock,
+    )?;
+
+    emit!(BallotBoxInitialized {
+        ballot_id: ballot_box.ballot_id,
+        operator: ctx.accounts.operator.key(),
+        epoch: ballot_box.epoch,
+        target_slot,
+        commit_expiry_timestamp: ballot_box.commit_expiry_timestamp,
+        vote_expiry_timestamp: ballot_box.vote_expiry_timestamp,
+    });
 
     // Increment for next ballot box
     program_config.next_ballot_id = program_config.next_ballot_id.checked_add(1).unwrap();
+    program_config.record_ballot_box_opened();
 
     Ok(())
 }
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BallotBox, BallotBoxInitialized, ProgramConfig};
+
+#[derive(Accounts)]
+#[instruction(epoch: u64, target_slot: u64)]
+pub struct InitScheduledBallotBox<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        zero,
+        seeds = [
+            b"BallotBox".as_ref(),
+            &epoch.to_le_bytes(),
+            &target_slot.to_le_bytes()
+        ],
+        bump,
+        constraint = ballot_box.to_account_info().data_len() == BallotBox::SIZE
+            @ ErrorCode::BallotBoxNotAllocated
+    )]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        mut,
+        constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<InitScheduledBallotBox>, epoch: u64, target_slot: u64) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    let scheduled_slot_offset = program_config
+        .scheduled_slot_offset
+        .ok_or(ErrorCode::ScheduledBallotBoxDisabled)?;
+
+    // Only the scheduled slot of the epoch can be targeted, so there is a single
+    // scheduled ballot box per epoch.
+    require_gte!(
+        epoch,
+        program_config.next_scheduled_epoch,
+        ErrorCode::ScheduledBallotBoxCreated
+    );
+    let epoch_schedule = EpochSchedule::get()?;
+    require!(
+        epoch_schedule.get_epoch(target_slot) == epoch
+            && epoch_schedule
+                .get_first_slot_in_epoch(epoch)
+                .checked_add(scheduled_slot_offset)
+                == Some(target_slot),
+        ErrorCode::InvalidTargetSlot
+    );
+    let clock = Clock::get()?;
+    require_gt!(clock.slot, target_slot, ErrorCode::TargetSlotNotReached);
+
+    let ballot_box = &mut ctx.accounts.ballot_box.load_init()?;
+    ballot_box.init(
+        program_config.next_ballot_id,
+        ctx.bumps.ballot_box,
+        ctx.accounts.payer.key(),
+        target_slot,
+        program_config,
+        &clock,
+    )?;
+
+    emit!(BallotBoxInitialized {
+        ballot_id: ballot_box.ballot_id,
+        operator: ctx.accounts.payer.key(),
+        epoch: ballot_box.epoch,
+        target_slot,
+        commit_expiry_timestamp: ballot_box.commit_expiry_timestamp,
+        vote_expiry_timestamp: ballot_box.vote_expiry_timestamp,
+    });
+
+    // Scheduled ballot boxes share the ID sequence, so ConsensusResults stay keyed by ID.
+    program_config.next_ballot_id = program_config.next_ballot_id.checked_add(1).unwrap();
+    program_config.next_scheduled_epoch = epoch.checked_add(1).unwrap();
+    program_config.record_ballot_box_opened();
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, realloc_helper::grow_pda, BallotBox, BallotBoxMigrated, ConsensusResult,
+    LegacyBallotBox, ProgramConfig,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBallotBox<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// BallotBox in the legacy borsh layout. Only the owner and discriminator are checked
+    /// on load, the size is checked in the handler.
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    /// CHECK: ConsensusResult PDA of the ballot box, checked in the handler. Legacy ballot
+    /// boxes were finalized by creating it.
+    pub consensus_result: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Converts a legacy borsh BallotBox into the zero-copy layout in place. Permissionless,
+/// since the votes are unchanged. The payer funds the additional rent and receives the
+/// lamports when the ballot box is closed.
+///
+/// An instruction can only grow the account by MAX_PERMITTED_DATA_INCREASE, so the
+/// account is grown over several calls. The legacy data is kept until the call that
+/// reaches BallotBox::SIZE converts it.
+pub fn handler(ctx: Context<MigrateBallotBox>) -> Result<()> {
+    let ballot_box = ctx.accounts.ballot_box.to_account_info();
+    let legacy = {
+        let data = ballot_box.try_borrow_data()?;
+        require!(
+            data.len() < BallotBox::SIZE,
+            ErrorCode::AccountAlreadyMigrated
+        );
+        Box::new(LegacyBallotBox::deserialize(
+            &mut &data[BallotBox::DISCRIMINATOR.len()..],
+        )?)
+    };
+    let consensus_result = &ctx.accounts.consensus_result;
+    require_keys_eq!(
+        consensus_result.key(),
+        ConsensusResult::pda(legacy.ballot_id).0,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    grow_pda(
+        &ballot_box,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[],
+        BallotBox::SIZE,
+    )?;
+    if ballot_box.data_len() < BallotBox::SIZE {
+        return Ok(());
+    }
+    ballot_box.try_borrow_mut_data()?[BallotBox::DISCRIMINATOR.len()..].fill(0);
+
+    let program_config = &mut ctx.accounts.program_config;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    ballot_box.migrate(&legacy, &program_config.whitelisted_operators)?;
+    ballot_box.payer = ctx.accounts.payer.key();
+    // Legacy ballot boxes didn't record when they were created or finalized.
+    ballot_box.created_timestamp = legacy
+        .vote_expiry_timestamp
+        .saturating_sub(program_config.vote_duration);
+    if *consensus_result.owner == crate::ID {
+        ballot_box.finalized_timestamp = Clock::get()?.unix_timestamp;
+    }
+    if ballot_box.is_open() {
+        program_config.record_ballot_box_opened();
+    }
+
+    emit!(BallotBoxMigrated {
+        ballot_id: legacy.ballot_id,
+        size: BallotBox::SIZE as u64,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,37 @@
+pub mod archive_consensus_result;
+pub mod cancel_ballot_box;
 pub mod cast_vote;
+pub mod change_vote;
+pub mod close_ballot_box;
+pub mod close_operator_commit;
+pub mod commit_vote;
+pub mod extend_vote_expiry;
 pub mod finalize_ballot;
+pub mod finalize_expired_ballot;
 pub mod init_ballot_box;
+pub mod init_scheduled_ballot_box;
+pub mod migrate_ballot_box;
+pub mod realloc_ballot_box;
+pub mod record_operator_stats;
 pub mod remove_vote;
+pub mod reveal_vote;
 pub mod set_tie_breaker;
 
+pub use archive_consensus_result::*;
+pub use cancel_ballot_box::*;
 pub use cast_vote::*;
+pub use change_vote::*;
+pub use close_ballot_box::*;
+pub use close_operator_commit::*;
+pub use commit_vote::*;
+pub use extend_vote_expiry::*;
 pub use finalize_ballot::*;
+pub use finalize_expired_ballot::*;
 pub use init_ballot_box::*;
+pub use init_scheduled_ballot_box::*;
+pub use migrate_ballot_box::*;
+pub use realloc_ballot_box::*;
+pub use record_operator_stats::*;
 pub use remove_vote::*;
+pub use reveal_vote::*;
 pub use set_tie_breaker::*;
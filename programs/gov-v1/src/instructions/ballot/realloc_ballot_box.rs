@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, realloc_helper::grow_pda, BallotBox, BallotBoxSeeds};
+
+#[derive(Accounts)]
+pub struct ReallocBallotBox<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: PDA derived from the seeds, allocated here and initialized by init_ballot_box
+    /// or init_scheduled_ballot_box once it reaches BallotBox::SIZE.
+    #[account(mut)]
+    pub ballot_box: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or grows an uninitialized BallotBox account towards BallotBox::SIZE.
+pub fn handler(ctx: Context<ReallocBallotBox>, seeds: BallotBoxSeeds) -> Result<()> {
+    let seeds = seeds.seeds();
+    let mut signer_seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    let (ballot_box_pda, bump) = Pubkey::find_program_address(&signer_seeds, &crate::ID);
+    let ballot_box = ctx.accounts.ballot_box.to_account_info();
+    require_keys_eq!(
+        ballot_box.key(),
+        ballot_box_pda,
+        ErrorCode::InvalidBallotBox
+    );
+
+    // Initialized ballot boxes, including legacy ones awaiting migration, can't be grown.
+    require!(
+        ballot_box.try_borrow_data()?.iter().take(8).all(|byte| *byte == 0),
+        ErrorCode::BallotBoxAlreadyInitialized
+    );
+
+    let bump = [bump];
+    signer_seeds.push(&bump);
+    grow_pda(
+        &ballot_box,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &signer_seeds,
+        BallotBox::SIZE,
+    )
+}
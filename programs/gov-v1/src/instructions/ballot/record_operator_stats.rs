@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{BallotBox, OperatorStats, OperatorStatsRecorded};
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct RecordOperatorStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    // Operators that never voted have no OperatorStats yet.
+    #[account(
+        init_if_needed,
+        seeds = [b"OperatorStats".as_ref(), operator.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + OperatorStats::INIT_SPACE
+    )]
+    pub operator_stats: Box<Account<'info, OperatorStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records a finalized ballot box in the OperatorStats of one of its eligible operators.
+/// Permissionless, and each operator is counted once per ballot box.
+pub fn handler(ctx: Context<RecordOperatorStats>, operator: Pubkey) -> Result<()> {
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    ballot_box.record_operator_stats(&operator)?;
+
+    let operator_stats = &mut ctx.accounts.operator_stats;
+    operator_stats.operator = operator;
+    operator_stats.record_finalized_ballot(ballot_box);
+
+    emit!(OperatorStatsRecorded {
+        ballot_id: ballot_box.ballot_id,
+        operator,
+        voted_for_winning_ballot: ballot_box.voted_for_winning_ballot(&operator),
+    });
+
+    Ok(())
+}
@@ -1,21 +1,20 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::ErrorCode, BallotBox, ProgramConfig};
+use crate::{error::ErrorCode, BallotBox, VoteRemoved};
 
 #[derive(Accounts)]
 pub struct RemoveVote<'info> {
     pub operator: Signer<'info>,
     #[account(mut)]
-    pub ballot_box: Box<Account<'info, BallotBox>>,
-    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub ballot_box: AccountLoader<'info, BallotBox>,
 }
 
 pub fn handler(ctx: Context<RemoveVote>) -> Result<()> {
     let operator = &ctx.accounts.operator.key();
-    let ballot_box = &mut ctx.accounts.ballot_box;
-    let program_config = &ctx.accounts.program_config;
-    program_config.contains_operator(operator)?;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    let weight = u32::from(ballot_box.operator_weight(operator)?);
 
+    require!(!ballot_box.is_cancelled(), ErrorCode::BallotBoxCancelled);
     require!(
         !ballot_box.has_vote_expired(Clock::get()?.unix_timestamp),
         ErrorCode::VotingExpired
@@ -25,24 +24,19 @@ pub fn handler(ctx: Context<RemoveVote>) -> Result<()> {
         ErrorCode::ConsensusReached
     );
 
-    let operator_vote_idx = ballot_box
-        .operator_votes
-        .iter()
-        .position(|vote| vote.operator == *operator);
-
-    // Get operator's ballot index and remove operator from OperatorVotes.
-    let ballot_index: u8;
-    if let Some(idx) = operator_vote_idx {
-        ballot_index = ballot_box.operator_votes[idx].ballot_index;
-        ballot_box.operator_votes.remove(idx);
-    } else {
-        return err!(ErrorCode::OperatorHasNotVoted);
-    }
-
-    // Decrement tally on BallotTally. BallotTally is kept even when tally is 0 to maintain
-    // order of indices.
-    let ballot_tally = &mut ballot_box.ballot_tallies[ballot_index as usize];
-    ballot_tally.tally = ballot_tally.tally.checked_sub(1).unwrap();
+    // Get operator's ballot index and clear the operator's vote.
+    let ballot_index = ballot_box.clear_vote(operator)?;
+
+    // Decrement tally on BallotTally.
+    let tally = ballot_box.remove_tally(ballot_index, weight);
+
+    emit!(VoteRemoved {
+        ballot_id: ballot_box.ballot_id,
+        operator: *operator,
+        ballot_index,
+        weight,
+        tally,
+    });
 
     Ok(())
 }
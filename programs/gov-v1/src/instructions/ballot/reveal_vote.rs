@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    cast_vote_shared_handler, error::ErrorCode, Ballot, BallotBox, OperatorCommit, OperatorStats,
+    ProgramConfig,
+};
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    pub operator: Signer<'info>,
+    /// Account to receive the reclaimed rent from OperatorCommit
+    /// CHECK: must match payer in OperatorCommit
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub ballot_box: AccountLoader<'info, BallotBox>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        has_one = operator,
+        has_one = ballot_box
+    )]
+    pub operator_commit: Box<Account<'info, OperatorCommit>>,
+    #[account(
+        mut,
+        seeds = [b"OperatorStats".as_ref(), operator.key().as_ref()],
+        bump
+    )]
+    pub operator_stats: Box<Account<'info, OperatorStats>>,
+    #[account(constraint = !program_config.balloting_paused @ ErrorCode::BallotingPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<RevealVote>, ballot: Ballot, salt: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
+    require!(
+        ballot_box.has_commit_expired(clock.unix_timestamp),
+        ErrorCode::RevealWindowNotOpen
+    );
+    require!(
+        ballot.commitment(
+            &ctx.accounts.operator.key(),
+            &ctx.accounts.ballot_box.key(),
+            &salt
+        ) == ctx.accounts.operator_commit.commitment,
+        ErrorCode::InvalidReveal
+    );
+
+    ballot_box.clear_commit(&ctx.accounts.operator.key())?;
+
+    // Only revealed ballots are tallied, so consensus can't be reached during the commit window.
+    cast_vote_shared_handler(
+        ballot_box,
+        &mut ctx.accounts.operator_stats,
+        &ctx.accounts.operator.key(),
+        ballot,
+        &clock,
+    )
+}
@@ -1,36 +1,32 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::ErrorCode, BallotBox, ProgramConfig};
+use crate::{BallotBox, ExpiryResolution, ProgramConfig, TieBreakerSet};
 
 #[derive(Accounts)]
 pub struct SetTieBreaker<'info> {
     pub tie_breaker_admin: Signer<'info>,
     #[account(mut)]
-    pub ballot_box: Box<Account<'info, BallotBox>>,
+    pub ballot_box: AccountLoader<'info, BallotBox>,
     #[account(has_one = tie_breaker_admin)]
     pub program_config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn handler(ctx: Context<SetTieBreaker>, ballot_index: u8) -> Result<()> {
-    let ballot_box = &mut ctx.accounts.ballot_box;
+    // With multisig enabled, the tie breaker is set through an AdminProposal.
+    ctx.accounts.program_config.require_multisig_disabled()?;
+    ctx.accounts.program_config.require_expiry_resolution(ExpiryResolution::TieBreakerAdmin)?;
+
+    let ballot_box = &mut ctx.accounts.ballot_box.load_mut()?;
     let clock = Clock::get()?;
-    require!(
-        ballot_box.has_vote_expired(clock.unix_timestamp),
-        ErrorCode::VotingNotExpired
-    );
-    require!(
-        !ballot_box.has_consensus_reached(),
-        ErrorCode::ConsensusReached
-    );
-    require!(
-        (ballot_index as usize) < ballot_box.ballot_tallies.len(),
-        ErrorCode::InvalidBallotIndex
-    );
+    ballot_box.set_tie_breaker(ballot_index, &clock)?;
 
-    ballot_box.slot_consensus_reached = clock.slot;
-    ballot_box.winning_ballot = ballot_box.ballot_tallies[ballot_index as usize]
-        .ballot
-        .clone();
+    emit!(TieBreakerSet {
+        ballot_id: ballot_box.ballot_id,
+        tie_breaker_admin: ctx.accounts.tie_breaker_admin.key(),
+        ballot_index,
+        ballot: ballot_box.winning_ballot,
+        slot: clock.slot,
+    });
 
     Ok(())
 }
@@ -1,7 +1,11 @@
+pub mod admin;
 pub mod ballot;
 pub mod program_config;
+pub mod proposal;
 pub mod verify;
 
+pub use admin::*;
 pub use ballot::*;
 pub use program_config::*;
+pub use proposal::*;
 pub use verify::*;
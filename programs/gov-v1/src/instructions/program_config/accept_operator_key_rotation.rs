@@ -0,0 +1,154 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    error::ErrorCode, realloc_helper::grow_pda, BallotBox, OperatorKeyRotated, OperatorKeyRotation,
+    OperatorStats, ProgramConfig, MAX_ROTATION_BALLOT_BOXES,
+};
+
+/// All open ballot boxes are passed as writable remaining accounts, sorted by address.
+#[derive(Accounts)]
+pub struct AcceptOperatorKeyRotation<'info> {
+    pub new_operator: Signer<'info>,
+    /// CHECK: Receives the rent of the rotation, checked by has_one.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = new_operator,
+        has_one = payer,
+        close = payer
+    )]
+    pub operator_key_rotation: Box<Account<'info, OperatorKeyRotation>>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    /// CHECK: OperatorStats of the old key, which may not exist. Moved to the new key in the
+    /// handler.
+    #[account(
+        mut,
+        seeds = [b"OperatorStats".as_ref(), operator_key_rotation.old_operator.as_ref()],
+        bump
+    )]
+    pub old_operator_stats: UncheckedAccount<'info>,
+    /// CHECK: OperatorStats of the new key, created or merged into in the handler.
+    #[account(
+        mut,
+        seeds = [b"OperatorStats".as_ref(), new_operator.key().as_ref()],
+        bump
+    )]
+    pub new_operator_stats: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AcceptOperatorKeyRotation<'info>>,
+) -> Result<()> {
+    let old_operator = ctx.accounts.operator_key_rotation.old_operator;
+    let new_operator = ctx.accounts.new_operator.key();
+    ctx.accounts
+        .program_config
+        .rotate_operator_key(&old_operator, new_operator)?;
+
+    // Every open ballot box must be passed, so that the old key can't keep voting in one
+    // that was left out. Ascending addresses reject duplicates.
+    require_gte!(
+        MAX_ROTATION_BALLOT_BOXES,
+        ctx.accounts.program_config.open_ballot_boxes,
+        ErrorCode::TooManyOpenBallotBoxes
+    );
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        ctx.accounts.program_config.open_ballot_boxes as usize,
+        ErrorCode::OpenBallotBoxesMissing
+    );
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let mut last_key = None;
+    let mut ballot_ids = Vec::with_capacity(ctx.remaining_accounts.len());
+    for account_info in ctx.remaining_accounts.iter() {
+        if let Some(last_key) = last_key {
+            require_gt!(*account_info.key, last_key, ErrorCode::BallotBoxesNotSorted);
+        }
+        last_key = Some(*account_info.key);
+
+        // Replacing the key in place keeps the operator's recorded votes.
+        let ballot_box_loader = AccountLoader::<BallotBox>::try_from(account_info)?;
+        let ballot_box = &mut ballot_box_loader.load_mut()?;
+        require!(ballot_box.is_open(), ErrorCode::InvalidBallotBox);
+        if ballot_box.operator_index(&old_operator).is_ok() {
+            // Commitments are bound to the old key, so they couldn't be revealed after.
+            require!(
+                !ballot_box.has_open_commit(&old_operator, current_timestamp),
+                ErrorCode::OperatorCommitOpen
+            );
+            ballot_box.rotate_operator_key(&old_operator, new_operator)?;
+            ballot_ids.push(ballot_box.ballot_id);
+        }
+    }
+
+    rotate_operator_stats(&ctx, new_operator)?;
+
+    emit!(OperatorKeyRotated {
+        old_operator,
+        new_operator,
+        ballot_ids,
+    });
+
+    Ok(())
+}
+
+/// Moves the OperatorStats of the old key to the new key, so the operator's history carries
+/// over. A new account is funded with the rent of the old one. If the new key was an operator
+/// before, the stats are merged and the old rent goes to the payer.
+fn rotate_operator_stats(
+    ctx: &Context<AcceptOperatorKeyRotation>,
+    new_operator: Pubkey,
+) -> Result<()> {
+    let old_stats_info = ctx.accounts.old_operator_stats.to_account_info();
+    // The old key never voted.
+    if old_stats_info.owner != &crate::ID {
+        return Ok(());
+    }
+    let old_stats = OperatorStats::try_deserialize(&mut &old_stats_info.try_borrow_data()?[..])?;
+    let new_stats_info = ctx.accounts.new_operator_stats.to_account_info();
+    let is_new = new_stats_info.owner != &crate::ID;
+    let new_stats = if is_new {
+        OperatorStats {
+            operator: new_operator,
+            ..old_stats
+        }
+    } else {
+        let mut new_stats =
+            OperatorStats::try_deserialize(&mut &new_stats_info.try_borrow_data()?[..])?;
+        new_stats.merge(&old_stats);
+        new_stats
+    };
+
+    let rent_destination = if is_new {
+        new_stats_info.clone()
+    } else {
+        ctx.accounts.payer.to_account_info()
+    };
+    let lamports = rent_destination
+        .lamports()
+        .checked_add(old_stats_info.lamports())
+        .unwrap();
+    **rent_destination.try_borrow_mut_lamports()? = lamports;
+    **old_stats_info.try_borrow_mut_lamports()? = 0;
+    old_stats_info.assign(&system_program::ID);
+    old_stats_info.resize(0)?;
+
+    if is_new {
+        grow_pda(
+            &new_stats_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &[
+                b"OperatorStats",
+                new_operator.as_ref(),
+                &[ctx.bumps.new_operator_stats],
+            ],
+            8 + OperatorStats::INIT_SPACE,
+        )?;
+    }
+    new_stats.try_serialize(&mut &mut new_stats_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, ConfigUpdated, OperatorWhitelistUpdated, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct ApplyPendingConfig<'info> {
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<ApplyPendingConfig>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    require!(
+        program_config.has_pending_changes(),
+        ErrorCode::NoPendingConfig
+    );
+
+    let clock = Clock::get()?;
+    let applied = apply_effective_changes(program_config, clock.unix_timestamp)?;
+    require!(applied, ErrorCode::PendingConfigNotEffective);
+
+    Ok(())
+}
+
+/// Applies pending changes whose effective_at has passed and emits their events.
+/// Returns true if any change was applied.
+pub fn apply_effective_changes(program_config: &mut ProgramConfig, now: i64) -> Result<bool> {
+    let config_applied = program_config.apply_pending_config_update(now)?;
+    if config_applied {
+        emit!(ConfigUpdated::new(program_config));
+    }
+    let whitelist_applied = program_config.apply_pending_whitelist_update(now);
+    if whitelist_applied {
+        emit!(OperatorWhitelistUpdated::new(program_config));
+    }
+
+    Ok(config_applied || whitelist_applied)
+}
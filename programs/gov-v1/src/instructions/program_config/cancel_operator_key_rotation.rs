@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{OperatorKeyRotation, OperatorKeyRotationCancelled};
+
+#[derive(Accounts)]
+pub struct CancelOperatorKeyRotation<'info> {
+    /// Old operator key that proposed the rotation
+    pub operator: Signer<'info>,
+    /// CHECK: Receives the rent of the rotation, checked by has_one.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"OperatorKeyRotation".as_ref(), operator.key().as_ref()],
+        bump,
+        has_one = payer,
+        close = payer
+    )]
+    pub operator_key_rotation: Box<Account<'info, OperatorKeyRotation>>,
+}
+
+pub fn handler(ctx: Context<CancelOperatorKeyRotation>) -> Result<()> {
+    let operator_key_rotation = &ctx.accounts.operator_key_rotation;
+    emit!(OperatorKeyRotationCancelled {
+        old_operator: operator_key_rotation.old_operator,
+        new_operator: operator_key_rotation.new_operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, PendingConfigCancelled, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct CancelPendingConfig<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<CancelPendingConfig>) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.require_multisig_disabled()?;
+    require!(
+        program_config.has_pending_changes(),
+        ErrorCode::NoPendingConfig
+    );
+
+    emit!(PendingConfigCancelled {
+        pending_config: program_config.pending_config.take(),
+        pending_whitelist: program_config.pending_whitelist.take(),
+    });
+
+    Ok(())
+}
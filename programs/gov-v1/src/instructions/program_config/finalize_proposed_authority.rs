@@ -1,7 +1,7 @@
 
 use anchor_lang::prelude::*;
 
-use crate::ProgramConfig;
+use crate::{AuthorityTransferred, ProgramConfig};
 
 #[derive(Accounts)]
 pub struct FinalizeProposedAuthority<'info> {
@@ -17,8 +17,14 @@ pub fn handler(
     ctx: Context<FinalizeProposedAuthority>,
 ) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
+    let old_authority = program_config.authority;
     program_config.authority = ctx.accounts.authority.key();
     program_config.proposed_authority = None;
 
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: program_config.authority,
+    });
+
     Ok(())
 }
@@ -1,6 +1,8 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
 
-use crate::ProgramConfig;
+use crate::{
+    ConfigUpdated, ExpiryResolution, ProgramConfig, ProgramConfigInitialized, ProgramConfigUpdate,
+};
 
 #[derive(Accounts)]
 pub struct InitProgramConfig<'info> {
@@ -12,15 +14,54 @@ pub struct InitProgramConfig<'info> {
         seeds = [b"ProgramConfig".as_ref()],
         bump,
         payer = payer,
-        space = 8 + ProgramConfig::INIT_SPACE
+        space = ProgramConfig::SIZE.min(MAX_PERMITTED_DATA_INCREASE)
     )]
     pub program_config: Box<Account<'info, ProgramConfig>>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitProgramConfig>) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<InitProgramConfig>,
+    min_consensus_threshold_bps: u16,
+    min_votes: u16,
+    tie_breaker_admin: Pubkey,
+    vote_duration: i64,
+    close_grace_period: i64,
+    config_change_delay: i64,
+    scheduled_slot_offset: Option<u64>,
+    guardian: Pubkey,
+    max_vote_duration: i64,
+    commit_duration: i64,
+    expiry_resolution: ExpiryResolution,
+    plurality_floor_bps: u16,
+) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
     program_config.authority = ctx.accounts.authority.key();
+    program_config.version = ProgramConfig::VERSION;
+
+    // All parameters are set up front, with the same validation as config updates, so a
+    // ballot box can't be created with an unset consensus threshold.
+    program_config.update_config(ProgramConfigUpdate {
+        proposed_authority: None,
+        min_consensus_threshold_bps: Some(min_consensus_threshold_bps),
+        tie_breaker_admin: Some(tie_breaker_admin),
+        vote_duration: Some(vote_duration),
+        close_grace_period: Some(close_grace_period),
+        config_change_delay: Some(config_change_delay),
+        scheduled_slot_offset: Some(scheduled_slot_offset),
+        guardian: Some(guardian),
+        max_vote_duration: Some(max_vote_duration),
+        commit_duration: Some(commit_duration),
+        min_votes: Some(min_votes),
+        expiry_resolution: Some(expiry_resolution),
+        plurality_floor_bps: Some(plurality_floor_bps),
+    })?;
+
+    emit!(ProgramConfigInitialized {
+        authority: program_config.authority,
+    });
+    emit!(ConfigUpdated::new(program_config));
 
     Ok(())
 }
@@ -0,0 +1,151 @@
+use std::io::{self, Read};
+
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    error::ErrorCode, realloc_helper::grow_pda, AccountMigrated, ConsensusResult,
+    LegacyConsensusResult, LegacyProgramConfig, MetaMerkleProof, ProgramConfig,
+};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    /// CHECK: ProgramConfig, which can't be loaded before it is migrated. Loaded and checked
+    /// against the authority in the handler.
+    #[account(mut, seeds = [b"ProgramConfig".as_ref()], bump)]
+    pub program_config: UncheckedAccount<'info>,
+    /// CHECK: Account of this program in an older layout, checked in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a ProgramConfig, ConsensusResult or MetaMerkleProof created with an older layout
+/// to the current version, growing it to the current size. The payer funds the additional
+/// rent. Legacy borsh ballot boxes are converted with `migrate_ballot_box` instead.
+///
+/// The ProgramConfig is migrated first. It can be larger than a single instruction can
+/// allocate, so it is grown over several calls and its version is set once it reaches
+/// ProgramConfig::SIZE.
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    if account.key() == ctx.accounts.program_config.key() {
+        return migrate_program_config(&ctx);
+    }
+
+    let program_config_info = ctx.accounts.program_config.to_account_info();
+    let program_config =
+        ProgramConfig::try_deserialize(&mut &program_config_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        program_config.authority,
+        ctx.accounts.authority.key(),
+        anchor_lang::error::ErrorCode::ConstraintHasOne
+    );
+    let discriminator = account.try_borrow_data()?[..account.data_len().min(8)].to_vec();
+
+    let version = if discriminator == ConsensusResult::DISCRIMINATOR {
+        let mut consensus_result: ConsensusResult =
+            if account.data_len() == LegacyConsensusResult::SIZE {
+                let mut consensus_result: ConsensusResult =
+                    deserialize_zero_extended::<LegacyConsensusResult>(&account)?.into();
+                // Receives the rent when the consensus result is archived.
+                consensus_result.payer = ctx.accounts.payer.key();
+                consensus_result
+            } else {
+                deserialize_zero_extended(&account)?
+            };
+        require_gt!(
+            ConsensusResult::VERSION,
+            consensus_result.version,
+            ErrorCode::AccountAlreadyMigrated
+        );
+        consensus_result.version = ConsensusResult::VERSION;
+        grow(&ctx, 8 + ConsensusResult::INIT_SPACE)?;
+        consensus_result.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        ConsensusResult::VERSION
+    } else if discriminator == MetaMerkleProof::DISCRIMINATOR {
+        let mut merkle_proof: MetaMerkleProof = deserialize_zero_extended(&account)?;
+        require_gt!(
+            MetaMerkleProof::VERSION,
+            merkle_proof.version,
+            ErrorCode::AccountAlreadyMigrated
+        );
+        merkle_proof.migrate()?;
+        grow(
+            &ctx,
+            8 + MetaMerkleProof::init_space(merkle_proof.proof_len.into()),
+        )?;
+        merkle_proof.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        MetaMerkleProof::VERSION
+    } else {
+        return err!(ErrorCode::AccountNotMigratable);
+    };
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        version,
+        size: account.data_len() as u64,
+    });
+
+    Ok(())
+}
+
+/// Converts a legacy ProgramConfig in the first call, then grows it over the following calls.
+fn migrate_program_config(ctx: &Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.program_config.to_account_info();
+    let mut program_config: ProgramConfig = if account.data_len() == LegacyProgramConfig::SIZE {
+        let data = account.try_borrow_data()?;
+        require!(
+            data[..8] == *ProgramConfig::DISCRIMINATOR,
+            ErrorCode::AccountNotMigratable
+        );
+        LegacyProgramConfig::deserialize(&mut &data[8..])?.into()
+    } else {
+        let program_config = ProgramConfig::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        require_gt!(
+            ProgramConfig::VERSION,
+            program_config.version,
+            ErrorCode::AccountAlreadyMigrated
+        );
+        program_config
+    };
+    require_keys_eq!(
+        program_config.authority,
+        ctx.accounts.authority.key(),
+        anchor_lang::error::ErrorCode::ConstraintHasOne
+    );
+
+    grow(ctx, ProgramConfig::SIZE)?;
+    if account.data_len() == ProgramConfig::SIZE {
+        program_config.version = ProgramConfig::VERSION;
+    }
+    program_config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        version: program_config.version,
+        size: account.data_len() as u64,
+    });
+
+    Ok(())
+}
+
+/// Deserializes an account written with an older layout. Fields appended since then are
+/// read as zeros.
+fn deserialize_zero_extended<T: AnchorDeserialize>(account: &AccountInfo) -> Result<T> {
+    let data = account.try_borrow_data()?;
+    let mut reader = (&data[8..]).chain(io::repeat(0));
+    Ok(T::deserialize_reader(&mut reader)?)
+}
+
+fn grow(ctx: &Context<MigrateAccount>, target_len: usize) -> Result<()> {
+    grow_pda(
+        &ctx.accounts.account.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[],
+        target_len,
+    )
+}
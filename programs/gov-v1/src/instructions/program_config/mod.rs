@@ -1,9 +1,25 @@
+pub mod accept_operator_key_rotation;
+pub mod apply_pending_config;
+pub mod cancel_operator_key_rotation;
+pub mod cancel_pending_config;
 pub mod init_program_config;
+pub mod migrate_account;
+pub mod propose_operator_key_rotation;
+pub mod realloc_program_config;
+pub mod set_paused;
 pub mod update_operator_whitelist;
 pub mod update_program_config;
 pub mod finalize_proposed_authority;
 
+pub use accept_operator_key_rotation::*;
+pub use apply_pending_config::*;
+pub use cancel_operator_key_rotation::*;
+pub use cancel_pending_config::*;
 pub use init_program_config::*;
+pub use migrate_account::*;
+pub use propose_operator_key_rotation::*;
+pub use realloc_program_config::*;
+pub use set_paused::*;
 pub use update_operator_whitelist::*;
 pub use update_program_config::*;
-pub use finalize_proposed_authority::*;
\ No newline at end of file
+pub use finalize_proposed_authority::*;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, OperatorKeyRotation, OperatorKeyRotationProposed, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct ProposeOperatorKeyRotation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub operator: Signer<'info>,
+    // A pending rotation must be cancelled before proposing another key.
+    #[account(
+        init,
+        seeds = [b"OperatorKeyRotation".as_ref(), operator.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + OperatorKeyRotation::INIT_SPACE
+    )]
+    pub operator_key_rotation: Box<Account<'info, OperatorKeyRotation>>,
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeOperatorKeyRotation>, new_operator: Pubkey) -> Result<()> {
+    let old_operator = ctx.accounts.operator.key();
+    let program_config = &ctx.accounts.program_config;
+    program_config.contains_operator(&old_operator)?;
+    require!(
+        !program_config.whitelisted_operators.contains(&new_operator),
+        ErrorCode::OperatorAlreadyWhitelisted
+    );
+
+    let operator_key_rotation = &mut ctx.accounts.operator_key_rotation;
+    operator_key_rotation.old_operator = old_operator;
+    operator_key_rotation.new_operator = new_operator;
+    operator_key_rotation.payer = ctx.accounts.payer.key();
+    operator_key_rotation.proposed_timestamp = Clock::get()?.unix_timestamp;
+
+    emit!(OperatorKeyRotationProposed {
+        old_operator,
+        new_operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, realloc_helper::grow_pda, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct ReallocProgramConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"ProgramConfig".as_ref()],
+        bump
+    )]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows the ProgramConfig towards its full size, so the whitelist can hold
+/// MAX_OPERATOR_WHITELIST operators. Configs in an older layout are grown by `migrate_account`.
+pub fn handler(ctx: Context<ReallocProgramConfig>) -> Result<()> {
+    require_eq!(
+        ctx.accounts.program_config.version,
+        ProgramConfig::VERSION,
+        ErrorCode::AccountNotMigrated
+    );
+    let program_config = ctx.accounts.program_config.to_account_info();
+    require_gt!(
+        ProgramConfig::SIZE,
+        program_config.data_len(),
+        ErrorCode::AccountAtFullSize
+    );
+
+    grow_pda(
+        &program_config,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[b"ProgramConfig", &[ctx.bumps.program_config]],
+        ProgramConfig::SIZE,
+    )
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{PauseUpdated, ProgramConfig};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Authority or guardian in ProgramConfig
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<SetPaused>,
+    balloting_paused: Option<bool>,
+    verification_paused: Option<bool>,
+) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let program_config = &mut ctx.accounts.program_config;
+
+    // Pausing is meant for emergencies, so it is neither timelocked nor gated by multisig.
+    program_config.require_authority_or_guardian(&signer)?;
+    if let Some(balloting_paused) = balloting_paused {
+        program_config.balloting_paused = balloting_paused;
+    }
+    if let Some(verification_paused) = verification_paused {
+        program_config.verification_paused = verification_paused;
+    }
+
+    emit!(PauseUpdated {
+        signer,
+        balloting_paused: program_config.balloting_paused,
+        verification_paused: program_config.verification_paused,
+    });
+
+    Ok(())
+}
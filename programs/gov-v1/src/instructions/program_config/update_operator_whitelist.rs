@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::ErrorCode, ProgramConfig};
+use crate::{
+    apply_effective_changes, OperatorWeight, OperatorWhitelistUpdate,
+    OperatorWhitelistUpdateQueued, ProgramConfig,
+};
 
 #[derive(Accounts)]
 pub struct UpdateOperatorWhitelist<'info> {
@@ -16,18 +19,24 @@ pub fn handler(
     ctx: Context<UpdateOperatorWhitelist>,
     operators_to_add: Option<Vec<Pubkey>>,
     operators_to_remove: Option<Vec<Pubkey>>,
+    operator_weights: Option<Vec<OperatorWeight>>,
 ) -> Result<()> {
-    // Validate no overlap between add and remove lists.
-    if let (Some(add), Some(remove)) = (&operators_to_add, &operators_to_remove) {
-        let add_set: std::collections::HashSet<Pubkey> = add.iter().cloned().collect();
-        let remove_set: std::collections::HashSet<Pubkey> = remove.iter().cloned().collect();
-        let overlap = add_set.intersection(&remove_set).next().is_some();
-        require!(!overlap, ErrorCode::OverlappingWhitelistEntries);
-    }
-
     let program_config = &mut ctx.accounts.program_config;
-    program_config.remove_operators(operators_to_remove);
-    program_config.add_operators(operators_to_add)?;
+    program_config.require_multisig_disabled()?;
+
+    // Changes are queued and only applied once config_change_delay has elapsed.
+    let clock = Clock::get()?;
+    let pending_whitelist = program_config.queue_operator_whitelist_update(
+        OperatorWhitelistUpdate {
+            operators_to_add,
+            operators_to_remove,
+            operator_weights,
+        },
+        clock.unix_timestamp,
+    )?;
+    emit!(OperatorWhitelistUpdateQueued::new(pending_whitelist));
+
+    apply_effective_changes(program_config, clock.unix_timestamp)?;
 
     Ok(())
 }
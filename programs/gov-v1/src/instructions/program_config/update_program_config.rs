@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::ProgramConfig;
+use crate::{
+    apply_effective_changes, ConfigUpdateQueued, ExpiryResolution, ProgramConfig,
+    ProgramConfigUpdate,
+};
 
 #[derive(Accounts)]
 pub struct UpdateProgramConfig<'info> {
@@ -12,29 +15,49 @@ pub struct UpdateProgramConfig<'info> {
     pub program_config: Box<Account<'info, ProgramConfig>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpdateProgramConfig>,
     proposed_authority: Option<Pubkey>,
     min_consensus_threshold_bps: Option<u16>,
     tie_breaker_admin: Option<Pubkey>,
     vote_duration: Option<i64>,
+    close_grace_period: Option<i64>,
+    config_change_delay: Option<i64>,
+    scheduled_slot_offset: Option<Option<u64>>,
+    guardian: Option<Pubkey>,
+    max_vote_duration: Option<i64>,
+    commit_duration: Option<i64>,
+    min_votes: Option<u16>,
+    expiry_resolution: Option<ExpiryResolution>,
+    plurality_floor_bps: Option<u16>,
 ) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
-    if let Some(proposed_authority) = proposed_authority {
-        program_config.proposed_authority = Some(proposed_authority);
-    }
-    if let Some(min_consensus_threshold_bps) = min_consensus_threshold_bps {
-        require_gt!(min_consensus_threshold_bps, 0);
-        require_gte!(10000, min_consensus_threshold_bps);
-        program_config.min_consensus_threshold_bps = min_consensus_threshold_bps;
-    }
-    if let Some(tie_breaker_admin) = tie_breaker_admin {
-        program_config.tie_breaker_admin = tie_breaker_admin;
-    }
-    if let Some(vote_duration) = vote_duration {
-        require_gt!(vote_duration, 0);
-        program_config.vote_duration = vote_duration;
-    }
+    program_config.require_multisig_disabled()?;
+
+    // Changes are queued and only applied once config_change_delay has elapsed.
+    let clock = Clock::get()?;
+    let pending_config = program_config.queue_config_update(
+        ProgramConfigUpdate {
+            proposed_authority,
+            min_consensus_threshold_bps,
+            tie_breaker_admin,
+            vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            min_votes,
+            expiry_resolution,
+            plurality_floor_bps,
+        },
+        clock.unix_timestamp,
+    )?;
+    emit!(ConfigUpdateQueued::new(pending_config));
+
+    apply_effective_changes(program_config, clock.unix_timestamp)?;
 
     Ok(())
 }
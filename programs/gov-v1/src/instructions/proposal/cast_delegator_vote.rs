@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, verify_shared_handler, ConsensusResult, MetaMerkleProof, ProgramConfig,
+    Proposal, ProposalStatus, ProposalVoteCast, StakeMerkleLeaf, VoteChoice, VoteRecord,
+};
+
+#[derive(Accounts)]
+#[instruction(choice: VoteChoice, stake_merkle_proof: Vec<[u8; 32]>, stake_merkle_leaf: StakeMerkleLeaf)]
+pub struct CastDelegatorVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub voting_wallet: Signer<'info>,
+    #[account(mut, has_one = consensus_result)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        has_one = consensus_result,
+        constraint = meta_merkle_proof.sealed @ ErrorCode::MetaMerkleProofNotSealed
+    )]
+    pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    // Seeded by the stake account so that it can only be counted once.
+    #[account(
+        init,
+        seeds = [
+            b"VoteRecord".as_ref(),
+            proposal.key().as_ref(),
+            stake_merkle_leaf.stake_account.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+    // Tracks overridden stake of the vote account the stake is delegated to,
+    // regardless of whether the vote account has voted yet.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"VoteRecord".as_ref(),
+            proposal.key().as_ref(),
+            meta_merkle_proof.meta_merkle_leaf.vote_account.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE
+    )]
+    pub validator_vote_record: Box<Account<'info, VoteRecord>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CastDelegatorVote>,
+    choice: VoteChoice,
+    stake_merkle_proof: Vec<[u8; 32]>,
+    stake_merkle_leaf: StakeMerkleLeaf,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == ProposalStatus::Voting,
+        ErrorCode::ProposalFinalized
+    );
+    require!(
+        !proposal.has_vote_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::VotingExpired
+    );
+
+    // Verify that the stake account exists in the snapshot of the consensus result.
+    verify_shared_handler(
+        &ctx.accounts.meta_merkle_proof,
+        &ctx.accounts.consensus_result,
+        Some(stake_merkle_proof),
+        Some(stake_merkle_leaf.clone()),
+    )?;
+    require_keys_eq!(
+        stake_merkle_leaf.voting_wallet,
+        ctx.accounts.voting_wallet.key(),
+        ErrorCode::InvalidVotingWallet
+    );
+
+    let stake = stake_merkle_leaf.active_stake;
+    let meta_merkle_leaf = &ctx.accounts.meta_merkle_proof.meta_merkle_leaf;
+
+    // Move the delegated stake out of the vote account's vote, if it has voted.
+    let validator_vote_record = &mut ctx.accounts.validator_vote_record;
+    if let Some(validator_choice) = validator_vote_record.choice {
+        proposal.sub_stake(validator_choice, stake);
+        validator_vote_record.stake = validator_vote_record.stake.checked_sub(stake).unwrap();
+    }
+    validator_vote_record.overridden_stake = validator_vote_record
+        .overridden_stake
+        .checked_add(stake)
+        .unwrap();
+    validator_vote_record.proposal = proposal.key();
+    validator_vote_record.voting_wallet = meta_merkle_leaf.voting_wallet;
+    validator_vote_record.vote_account = meta_merkle_leaf.vote_account;
+
+    proposal.add_stake(choice, stake);
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voting_wallet = stake_merkle_leaf.voting_wallet;
+    vote_record.vote_account = meta_merkle_leaf.vote_account;
+    vote_record.stake_account = Some(stake_merkle_leaf.stake_account);
+    vote_record.stake = stake;
+    vote_record.choice = Some(choice);
+
+    emit!(ProposalVoteCast {
+        proposal_id: proposal.proposal_id,
+        voting_wallet: vote_record.voting_wallet,
+        vote_account: vote_record.vote_account,
+        stake_account: vote_record.stake_account,
+        choice,
+        stake,
+    });
+
+    Ok(())
+}
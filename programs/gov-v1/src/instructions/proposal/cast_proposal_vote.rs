@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, verify_shared_handler, ConsensusResult, MetaMerkleProof, ProgramConfig,
+    Proposal, ProposalStatus, ProposalVoteCast, VoteChoice, VoteRecord,
+};
+
+#[derive(Accounts)]
+pub struct CastProposalVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub voting_wallet: Signer<'info>,
+    #[account(mut, has_one = consensus_result)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(
+        has_one = consensus_result,
+        constraint = meta_merkle_proof.sealed @ ErrorCode::MetaMerkleProofNotSealed
+    )]
+    pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    // May already exist if a delegator of the vote account voted first.
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"VoteRecord".as_ref(),
+            proposal.key().as_ref(),
+            meta_merkle_proof.meta_merkle_leaf.vote_account.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CastProposalVote>, choice: VoteChoice) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == ProposalStatus::Voting,
+        ErrorCode::ProposalFinalized
+    );
+    require!(
+        !proposal.has_vote_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::VotingExpired
+    );
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    require!(vote_record.choice.is_none(), ErrorCode::VoteAlreadyCast);
+
+    // Verify that the vote account exists in the snapshot of the consensus result.
+    verify_shared_handler(
+        &ctx.accounts.meta_merkle_proof,
+        &ctx.accounts.consensus_result,
+        None,
+        None,
+    )?;
+
+    let meta_merkle_leaf = &ctx.accounts.meta_merkle_proof.meta_merkle_leaf;
+    require_keys_eq!(
+        meta_merkle_leaf.voting_wallet,
+        ctx.accounts.voting_wallet.key(),
+        ErrorCode::InvalidVotingWallet
+    );
+
+    // Stake of delegators that already voted is excluded from the vote account's vote.
+    let stake = meta_merkle_leaf
+        .active_stake
+        .checked_sub(vote_record.overridden_stake)
+        .unwrap();
+    proposal.add_stake(choice, stake);
+
+    vote_record.proposal = proposal.key();
+    vote_record.voting_wallet = meta_merkle_leaf.voting_wallet;
+    vote_record.vote_account = meta_merkle_leaf.vote_account;
+    vote_record.stake_account = None;
+    vote_record.stake = stake;
+    vote_record.choice = Some(choice);
+
+    emit!(ProposalVoteCast {
+        proposal_id: proposal.proposal_id,
+        voting_wallet: vote_record.voting_wallet,
+        vote_account: vote_record.vote_account,
+        stake_account: None,
+        choice,
+        stake,
+    });
+
+    Ok(())
+}
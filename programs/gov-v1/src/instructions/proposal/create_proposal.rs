@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{ConsensusResult, ProgramConfig, Proposal, ProposalCreated, ProposalStatus};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub proposer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"Proposal".as_ref(),
+            &program_config.next_proposal_id.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + Proposal::INIT_SPACE
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(mut)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    description_hash: [u8; 32],
+    vote_duration: i64,
+    quorum_stake: u64,
+    pass_threshold_bps: u16,
+) -> Result<()> {
+    require_gt!(vote_duration, 0);
+    require_gt!(pass_threshold_bps, 0);
+    require_gte!(10000, pass_threshold_bps);
+
+    let program_config = &mut ctx.accounts.program_config;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = program_config.next_proposal_id;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.consensus_result = ctx.accounts.consensus_result.key();
+    proposal.description_hash = description_hash;
+
+    let clock = Clock::get()?;
+    proposal.vote_start_timestamp = clock.unix_timestamp;
+    proposal.vote_expiry_timestamp = clock.unix_timestamp.checked_add(vote_duration).unwrap();
+    proposal.quorum_stake = quorum_stake;
+    proposal.pass_threshold_bps = pass_threshold_bps;
+    proposal.status = ProposalStatus::Voting;
+
+    emit!(ProposalCreated {
+        proposal_id: proposal.proposal_id,
+        proposer: proposal.proposer,
+        consensus_result: proposal.consensus_result,
+        vote_expiry_timestamp: proposal.vote_expiry_timestamp,
+    });
+
+    // Increment for next proposal
+    program_config.next_proposal_id = program_config.next_proposal_id.checked_add(1).unwrap();
+
+    Ok(())
+}
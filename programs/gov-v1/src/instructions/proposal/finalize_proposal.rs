@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Proposal, ProposalFinalized, ProposalStatus};
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+pub fn handler(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == ProposalStatus::Voting,
+        ErrorCode::ProposalFinalized
+    );
+    require!(
+        proposal.has_vote_expired(Clock::get()?.unix_timestamp),
+        ErrorCode::VotingNotExpired
+    );
+
+    proposal.status = if proposal.has_passed() {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    emit!(ProposalFinalized {
+        proposal_id: proposal.proposal_id,
+        status: proposal.status,
+        for_stake: proposal.for_stake,
+        against_stake: proposal.against_stake,
+        abstain_stake: proposal.abstain_stake,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod cast_delegator_vote;
+pub mod cast_proposal_vote;
+pub mod create_proposal;
+pub mod finalize_proposal;
+
+pub use cast_delegator_vote::*;
+pub use cast_proposal_vote::*;
+pub use create_proposal::*;
+pub use finalize_proposal::*;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, MetaMerkleProof};
+
+#[derive(Accounts)]
+pub struct AppendMetaMerkleProof<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut, has_one = payer)]
+    pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+}
+
+pub fn handler(ctx: Context<AppendMetaMerkleProof>, proof_chunk: Vec<[u8; 32]>) -> Result<()> {
+    let meta_merkle_proof = &mut ctx.accounts.meta_merkle_proof;
+    require!(!meta_merkle_proof.sealed, ErrorCode::MetaMerkleProofSealed);
+
+    // Check that the chunk fits in the declared proof length.
+    let new_len = meta_merkle_proof
+        .meta_merkle_proof
+        .len()
+        .checked_add(proof_chunk.len())
+        .unwrap();
+    require_gte!(
+        usize::from(meta_merkle_proof.proof_len),
+        new_len,
+        ErrorCode::VecFull
+    );
+    meta_merkle_proof.meta_merkle_proof.extend(proof_chunk);
+
+    Ok(())
+}
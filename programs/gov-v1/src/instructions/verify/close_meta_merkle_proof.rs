@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::MetaMerkleProof;
+use crate::{MetaMerkleProof, MetaMerkleProofClosed};
 
 #[derive(Accounts)]
 pub struct CloseMetaMerkleProof<'info> {
@@ -28,5 +28,11 @@ pub fn handler(ctx: Context<CloseMetaMerkleProof>) -> Result<()> {
         )
     }
 
+    emit!(MetaMerkleProofClosed {
+        consensus_result: meta_merkle_proof.consensus_result,
+        vote_account: meta_merkle_proof.meta_merkle_leaf.vote_account,
+        payer: meta_merkle_proof.payer,
+    });
+
     Ok(())
 }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, ConsensusResult, MetaMerkleLeaf, MetaMerkleProof, MetaMerkleProofInitialized,
+    ProgramConfig,
+};
+
+#[derive(Accounts)]
+#[instruction(meta_merkle_leaf: MetaMerkleLeaf, proof_len: u8)]
+pub struct InitChunkedMetaMerkleProof<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"MetaMerkleProof".as_ref(),
+            &consensus_result.key().as_ref(),
+            meta_merkle_leaf.vote_account.as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + MetaMerkleProof::init_space(proof_len.into())
+    )]
+    pub merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitChunkedMetaMerkleProof>,
+    meta_merkle_leaf: MetaMerkleLeaf,
+    proof_len: u8,
+    close_timestamp: i64,
+) -> Result<()> {
+    // Proof is appended with `append_meta_merkle_proof` and verified when sealed.
+    let merkle_proof = &mut ctx.accounts.merkle_proof;
+    merkle_proof.payer = ctx.accounts.payer.key();
+    merkle_proof.consensus_result = ctx.accounts.consensus_result.key();
+    merkle_proof.meta_merkle_leaf = meta_merkle_leaf;
+    merkle_proof.meta_merkle_proof = Vec::with_capacity(proof_len.into());
+    merkle_proof.close_timestamp = close_timestamp;
+    merkle_proof.proof_len = proof_len;
+    merkle_proof.sealed = false;
+    merkle_proof.version = MetaMerkleProof::VERSION;
+
+    emit!(MetaMerkleProofInitialized {
+        consensus_result: merkle_proof.consensus_result,
+        vote_account: merkle_proof.meta_merkle_leaf.vote_account,
+        payer: merkle_proof.payer,
+        sealed: false,
+    });
+
+    Ok(())
+}
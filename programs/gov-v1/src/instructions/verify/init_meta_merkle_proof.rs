@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{verify_shared_handler, ConsensusResult, MetaMerkleLeaf, MetaMerkleProof};
+use crate::{
+    error::ErrorCode, verify_shared_handler, ConsensusResult, MetaMerkleLeaf, MetaMerkleProof,
+    MetaMerkleProofInitialized, ProgramConfig,
+};
 
 #[derive(Accounts)]
 #[instruction(meta_merkle_leaf: MetaMerkleLeaf, meta_merkle_proof: Vec<[u8; 32]>)]
@@ -16,10 +19,12 @@ pub struct InitMetaMerkleProof<'info> {
         ],
         bump,
         payer = payer,
-        space = 8 + MetaMerkleProof::init_space(meta_merkle_proof)
+        space = 8 + MetaMerkleProof::init_space(meta_merkle_proof.len())
     )]
     pub merkle_proof: Box<Account<'info, MetaMerkleProof>>,
     pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -33,8 +38,14 @@ pub fn handler(
     merkle_proof.payer = ctx.accounts.payer.key();
     merkle_proof.consensus_result = ctx.accounts.consensus_result.key();
     merkle_proof.meta_merkle_leaf = meta_merkle_leaf;
+    merkle_proof.proof_len = meta_merkle_proof
+        .len()
+        .try_into()
+        .map_err(|_| ErrorCode::VecFull)?;
     merkle_proof.meta_merkle_proof = meta_merkle_proof;
     merkle_proof.close_timestamp = close_timestamp;
+    merkle_proof.sealed = true;
+    merkle_proof.version = MetaMerkleProof::VERSION;
 
     // Verify using the provided proof that the leaf exists in consensus result root.
     verify_shared_handler(
@@ -44,5 +55,13 @@ pub fn handler(
         None,
     )?;
 
+    let merkle_proof = &ctx.accounts.merkle_proof;
+    emit!(MetaMerkleProofInitialized {
+        consensus_result: merkle_proof.consensus_result,
+        vote_account: merkle_proof.meta_merkle_leaf.vote_account,
+        payer: merkle_proof.payer,
+        sealed: true,
+    });
+
     Ok(())
 }
@@ -1,7 +1,15 @@
+pub mod append_meta_merkle_proof;
 pub mod close_meta_merkle_proof;
+pub mod init_chunked_meta_merkle_proof;
 pub mod init_meta_merkle_proof;
+pub mod seal_meta_merkle_proof;
 pub mod verify_merkle_proof;
+pub mod verify_stake_merkle_proofs_batch;
 
+pub use append_meta_merkle_proof::*;
 pub use close_meta_merkle_proof::*;
+pub use init_chunked_meta_merkle_proof::*;
 pub use init_meta_merkle_proof::*;
+pub use seal_meta_merkle_proof::*;
 pub use verify_merkle_proof::*;
+pub use verify_stake_merkle_proofs_batch::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode, verify_shared_handler, ConsensusResult, MetaMerkleProof,
+    MetaMerkleProofSealed, ProgramConfig,
+};
+
+#[derive(Accounts)]
+pub struct SealMetaMerkleProof<'info> {
+    #[account(mut, has_one = consensus_result)]
+    pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(ctx: Context<SealMetaMerkleProof>) -> Result<()> {
+    let meta_merkle_proof = &ctx.accounts.meta_merkle_proof;
+    require!(!meta_merkle_proof.sealed, ErrorCode::MetaMerkleProofSealed);
+    require!(
+        meta_merkle_proof.is_complete(),
+        ErrorCode::MetaMerkleProofIncomplete
+    );
+
+    // Verify using the appended proof that the leaf exists in consensus result root.
+    verify_shared_handler(
+        meta_merkle_proof,
+        &ctx.accounts.consensus_result,
+        None,
+        None,
+    )?;
+    ctx.accounts.meta_merkle_proof.sealed = true;
+
+    emit!(MetaMerkleProofSealed {
+        consensus_result: ctx.accounts.consensus_result.key(),
+        vote_account: ctx.accounts.meta_merkle_proof.meta_merkle_leaf.vote_account,
+    });
+
+    Ok(())
+}
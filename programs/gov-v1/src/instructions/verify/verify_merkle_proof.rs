@@ -1,15 +1,23 @@
-use anchor_lang::{prelude::*, solana_program::hash::Hash};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::Hash, program::set_return_data},
+};
 
 use crate::{
     error::ErrorCode, merkle_helper::verify_helper, ConsensusResult, MetaMerkleProof,
-    StakeMerkleLeaf,
+    ProgramConfig, StakeMerkleLeaf, VerifiedStake,
 };
 
 #[derive(Accounts)]
 pub struct VerifyMerkleProof<'info> {
-    #[account(has_one = consensus_result)]
+    #[account(
+        has_one = consensus_result,
+        constraint = meta_merkle_proof.sealed @ ErrorCode::MetaMerkleProofNotSealed
+    )]
     pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
     pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
 }
 
 pub fn verify_shared_handler<'info>(
@@ -59,6 +67,29 @@ pub fn handler(
         meta_merkle_proof,
         consensus_result,
         stake_merkle_proof,
-        stake_merkle_leaf,
-    )
+        stake_merkle_leaf.clone(),
+    )?;
+
+    // Return the verified leaf so that CPI callers get an authenticated voting wallet
+    // and stake weight without re-deserializing MetaMerkleProof.
+    let meta_merkle_leaf = &meta_merkle_proof.meta_merkle_leaf;
+    let verified_stake = match stake_merkle_leaf {
+        Some(stake_merkle_leaf) => VerifiedStake {
+            voting_wallet: stake_merkle_leaf.voting_wallet,
+            vote_account: meta_merkle_leaf.vote_account,
+            stake_account: Some(stake_merkle_leaf.stake_account),
+            active_stake: stake_merkle_leaf.active_stake,
+            ballot_id: consensus_result.ballot_id,
+        },
+        None => VerifiedStake {
+            voting_wallet: meta_merkle_leaf.voting_wallet,
+            vote_account: meta_merkle_leaf.vote_account,
+            stake_account: None,
+            active_stake: meta_merkle_leaf.active_stake,
+            ballot_id: consensus_result.ballot_id,
+        },
+    };
+    set_return_data(&verified_stake.try_to_vec()?);
+
+    Ok(())
 }
@@ -0,0 +1,84 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+use crate::{
+    error::ErrorCode, merkle_helper::verify_multiproof_helper, verify_shared_handler,
+    ConsensusResult, MetaMerkleProof, ProgramConfig, StakeMerkleLeaf, VerifiedStakeBatch,
+};
+
+#[derive(Accounts)]
+pub struct VerifyStakeMerkleProofsBatch<'info> {
+    #[account(
+        has_one = consensus_result,
+        constraint = meta_merkle_proof.sealed @ ErrorCode::MetaMerkleProofNotSealed
+    )]
+    pub meta_merkle_proof: Box<Account<'info, MetaMerkleProof>>,
+    pub consensus_result: Box<Account<'info, ConsensusResult>>,
+    #[account(constraint = !program_config.verification_paused @ ErrorCode::VerificationPaused)]
+    pub program_config: Box<Account<'info, ProgramConfig>>,
+}
+
+pub fn handler(
+    ctx: Context<VerifyStakeMerkleProofsBatch>,
+    stake_merkle_leaves: Vec<StakeMerkleLeaf>,
+    stake_merkle_proof: Vec<[u8; 32]>,
+    proof_flags: Vec<bool>,
+) -> Result<()> {
+    let meta_merkle_proof = &ctx.accounts.meta_merkle_proof;
+    let consensus_result = &ctx.accounts.consensus_result;
+    require!(
+        !stake_merkle_leaves.is_empty(),
+        ErrorCode::InvalidMerkleInputs
+    );
+
+    // Leaves are passed in StakeMerkleTree order, which isn't sorted by stake account, so
+    // duplicates are checked on a sorted copy.
+    let mut stake_accounts: Vec<Pubkey> = stake_merkle_leaves
+        .iter()
+        .map(|leaf| leaf.stake_account)
+        .collect();
+    stake_accounts.sort_unstable();
+    require!(
+        stake_accounts
+            .windows(2)
+            .all(|stake_accounts| stake_accounts[0] != stake_accounts[1]),
+        ErrorCode::DuplicateStakeAccount
+    );
+    let voting_wallet = stake_merkle_leaves[0].voting_wallet;
+    require!(
+        stake_merkle_leaves
+            .iter()
+            .all(|leaf| leaf.voting_wallet == voting_wallet),
+        ErrorCode::MixedVotingWallets
+    );
+
+    // Check that the root generated by the multiproof and StakeMerkleLeaves matches
+    // root in MetaMerkleLeaf.
+    let leaf_contents: Vec<[u8; 32]> = stake_merkle_leaves
+        .iter()
+        .map(|leaf| leaf.hash().to_bytes())
+        .collect();
+    verify_multiproof_helper(
+        &leaf_contents,
+        &stake_merkle_proof,
+        &proof_flags,
+        meta_merkle_proof.meta_merkle_leaf.stake_merkle_root.into(),
+    )?;
+
+    // Check that MetaMerkleLeaf exists in root stored in ConsensusResult.
+    verify_shared_handler(meta_merkle_proof, consensus_result, None, None)?;
+
+    let active_stake = stake_merkle_leaves
+        .iter()
+        .try_fold(0u64, |sum, leaf| sum.checked_add(leaf.active_stake))
+        .unwrap();
+    let verified_stake_batch = VerifiedStakeBatch {
+        voting_wallet,
+        vote_account: meta_merkle_proof.meta_merkle_leaf.vote_account,
+        stake_account_count: stake_merkle_leaves.len() as u32,
+        active_stake,
+        ballot_id: consensus_result.ballot_id,
+    };
+    set_return_data(&verified_stake_batch.try_to_vec()?);
+
+    Ok(())
+}
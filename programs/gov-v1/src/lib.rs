@@ -1,13 +1,17 @@
 #![allow(ambiguous_glob_reexports)]
 #![allow(unexpected_cfgs)] // See: https://solana.stackexchange.com/a/19845
 
+pub mod cpi_helper;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod merkle_helper;
+pub mod realloc_helper;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -17,24 +21,77 @@ declare_id!("Dx6SGfGoXipHA4bmtGxZ6DQXztRoKmuX6fx3EMouigHX");
 pub mod gov_v1 {
     use super::*;
 
-    pub fn init_program_config(ctx: Context<InitProgramConfig>) -> Result<()> {
-        init_program_config::handler(ctx)
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_program_config(
+        ctx: Context<InitProgramConfig>,
+        min_consensus_threshold_bps: u16,
+        min_votes: u16,
+        tie_breaker_admin: Pubkey,
+        vote_duration: i64,
+        close_grace_period: i64,
+        config_change_delay: i64,
+        scheduled_slot_offset: Option<u64>,
+        guardian: Pubkey,
+        max_vote_duration: i64,
+        commit_duration: i64,
+        expiry_resolution: ExpiryResolution,
+        plurality_floor_bps: u16,
+    ) -> Result<()> {
+        init_program_config::handler(
+            ctx,
+            min_consensus_threshold_bps,
+            min_votes,
+            tie_breaker_admin,
+            vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            expiry_resolution,
+            plurality_floor_bps,
+        )
+    }
+
+    pub fn realloc_program_config(ctx: Context<ReallocProgramConfig>) -> Result<()> {
+        realloc_program_config::handler(ctx)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        migrate_account::handler(ctx)
     }
 
     pub fn update_operator_whitelist(
         ctx: Context<UpdateOperatorWhitelist>,
         operators_to_add: Option<Vec<Pubkey>>,
         operators_to_remove: Option<Vec<Pubkey>>,
+        operator_weights: Option<Vec<OperatorWeight>>,
     ) -> Result<()> {
-        update_operator_whitelist::handler(ctx, operators_to_add, operators_to_remove)
+        update_operator_whitelist::handler(
+            ctx,
+            operators_to_add,
+            operators_to_remove,
+            operator_weights,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_program_config(
         ctx: Context<UpdateProgramConfig>,
         proposed_authority: Option<Pubkey>,
         min_consensus_threshold_bps: Option<u16>,
         tie_breaker_admin: Option<Pubkey>,
         vote_duration: Option<i64>,
+        close_grace_period: Option<i64>,
+        config_change_delay: Option<i64>,
+        scheduled_slot_offset: Option<Option<u64>>,
+        guardian: Option<Pubkey>,
+        max_vote_duration: Option<i64>,
+        commit_duration: Option<i64>,
+        min_votes: Option<u16>,
+        expiry_resolution: Option<ExpiryResolution>,
+        plurality_floor_bps: Option<u16>,
     ) -> Result<()> {
         update_program_config::handler(
             ctx,
@@ -42,21 +99,121 @@ pub mod gov_v1 {
             min_consensus_threshold_bps,
             tie_breaker_admin,
             vote_duration,
+            close_grace_period,
+            config_change_delay,
+            scheduled_slot_offset,
+            guardian,
+            max_vote_duration,
+            commit_duration,
+            min_votes,
+            expiry_resolution,
+            plurality_floor_bps,
         )
     }
 
+    pub fn propose_operator_key_rotation(
+        ctx: Context<ProposeOperatorKeyRotation>,
+        new_operator: Pubkey,
+    ) -> Result<()> {
+        propose_operator_key_rotation::handler(ctx, new_operator)
+    }
+
+    pub fn accept_operator_key_rotation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptOperatorKeyRotation<'info>>,
+    ) -> Result<()> {
+        accept_operator_key_rotation::handler(ctx)
+    }
+
+    pub fn cancel_operator_key_rotation(ctx: Context<CancelOperatorKeyRotation>) -> Result<()> {
+        cancel_operator_key_rotation::handler(ctx)
+    }
+
+    pub fn apply_pending_config(ctx: Context<ApplyPendingConfig>) -> Result<()> {
+        apply_pending_config::handler(ctx)
+    }
+
+    pub fn cancel_pending_config(ctx: Context<CancelPendingConfig>) -> Result<()> {
+        cancel_pending_config::handler(ctx)
+    }
+
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        balloting_paused: Option<bool>,
+        verification_paused: Option<bool>,
+    ) -> Result<()> {
+        set_paused::handler(ctx, balloting_paused, verification_paused)
+    }
+
     pub fn finalize_proposed_authority(ctx: Context<FinalizeProposedAuthority>) -> Result<()> {
         finalize_proposed_authority::handler(ctx)
     }
 
-    pub fn init_ballot_box(ctx: Context<InitBallotBox>) -> Result<()> {
-        init_ballot_box::handler(ctx)
+    pub fn set_admins(ctx: Context<SetAdmins>, admins: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        set_admins::handler(ctx, admins, threshold)
+    }
+
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction,
+    ) -> Result<()> {
+        create_admin_proposal::handler(ctx, action)
+    }
+
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+        approve_admin_proposal::handler(ctx)
+    }
+
+    pub fn execute_admin_proposal(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+        execute_admin_proposal::handler(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        cancel_admin_proposal::handler(ctx)
+    }
+
+    pub fn realloc_ballot_box(
+        ctx: Context<ReallocBallotBox>,
+        seeds: BallotBoxSeeds,
+    ) -> Result<()> {
+        realloc_ballot_box::handler(ctx, seeds)
+    }
+
+    pub fn init_ballot_box(ctx: Context<InitBallotBox>, target_slot: u64) -> Result<()> {
+        init_ballot_box::handler(ctx, target_slot)
+    }
+
+    pub fn init_scheduled_ballot_box(
+        ctx: Context<InitScheduledBallotBox>,
+        epoch: u64,
+        target_slot: u64,
+    ) -> Result<()> {
+        init_scheduled_ballot_box::handler(ctx, epoch, target_slot)
+    }
+
+    pub fn migrate_ballot_box(ctx: Context<MigrateBallotBox>) -> Result<()> {
+        migrate_ballot_box::handler(ctx)
     }
 
     pub fn cast_vote(ctx: Context<CastVote>, ballot: Ballot) -> Result<()> {
         cast_vote::handler(ctx, ballot)
     }
 
+    pub fn change_vote(ctx: Context<ChangeVote>, ballot: Ballot) -> Result<()> {
+        change_vote::handler(ctx, ballot)
+    }
+
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        commit_vote::handler(ctx, commitment)
+    }
+
+    pub fn reveal_vote(ctx: Context<RevealVote>, ballot: Ballot, salt: [u8; 32]) -> Result<()> {
+        reveal_vote::handler(ctx, ballot, salt)
+    }
+
+    pub fn close_operator_commit(ctx: Context<CloseOperatorCommit>) -> Result<()> {
+        close_operator_commit::handler(ctx)
+    }
+
     pub fn remove_vote(ctx: Context<RemoveVote>) -> Result<()> {
         remove_vote::handler(ctx)
     }
@@ -65,10 +222,40 @@ pub mod gov_v1 {
         set_tie_breaker::handler(ctx, ballot_index)
     }
 
+    pub fn extend_vote_expiry(
+        ctx: Context<ExtendVoteExpiry>,
+        vote_expiry_timestamp: i64,
+    ) -> Result<()> {
+        extend_vote_expiry::handler(ctx, vote_expiry_timestamp)
+    }
+
+    pub fn cancel_ballot_box(ctx: Context<CancelBallotBox>) -> Result<()> {
+        cancel_ballot_box::handler(ctx)
+    }
+
     pub fn finalize_ballot(ctx: Context<FinalizeBallot>) -> Result<()> {
         finalize_ballot::handler(ctx)
     }
 
+    pub fn record_operator_stats(
+        ctx: Context<RecordOperatorStats>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        record_operator_stats::handler(ctx, operator)
+    }
+
+    pub fn finalize_expired_ballot(ctx: Context<FinalizeExpiredBallot>) -> Result<()> {
+        finalize_expired_ballot::handler(ctx)
+    }
+
+    pub fn close_ballot_box(ctx: Context<CloseBallotBox>) -> Result<()> {
+        close_ballot_box::handler(ctx)
+    }
+
+    pub fn archive_consensus_result(ctx: Context<ArchiveConsensusResult>) -> Result<()> {
+        archive_consensus_result::handler(ctx)
+    }
+
     pub fn init_meta_merkle_proof(
         ctx: Context<InitMetaMerkleProof>,
         meta_merkle_leaf: MetaMerkleLeaf,
@@ -78,10 +265,63 @@ pub mod gov_v1 {
         init_meta_merkle_proof::handler(ctx, meta_merkle_leaf, meta_merkle_proof, close_timestamp)
     }
 
+    pub fn init_chunked_meta_merkle_proof(
+        ctx: Context<InitChunkedMetaMerkleProof>,
+        meta_merkle_leaf: MetaMerkleLeaf,
+        proof_len: u8,
+        close_timestamp: i64,
+    ) -> Result<()> {
+        init_chunked_meta_merkle_proof::handler(ctx, meta_merkle_leaf, proof_len, close_timestamp)
+    }
+
+    pub fn append_meta_merkle_proof(
+        ctx: Context<AppendMetaMerkleProof>,
+        proof_chunk: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        append_meta_merkle_proof::handler(ctx, proof_chunk)
+    }
+
+    pub fn seal_meta_merkle_proof(ctx: Context<SealMetaMerkleProof>) -> Result<()> {
+        seal_meta_merkle_proof::handler(ctx)
+    }
+
     pub fn close_meta_merkle_proof(ctx: Context<CloseMetaMerkleProof>) -> Result<()> {
         close_meta_merkle_proof::handler(ctx)
     }
 
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        description_hash: [u8; 32],
+        vote_duration: i64,
+        quorum_stake: u64,
+        pass_threshold_bps: u16,
+    ) -> Result<()> {
+        create_proposal::handler(
+            ctx,
+            description_hash,
+            vote_duration,
+            quorum_stake,
+            pass_threshold_bps,
+        )
+    }
+
+    pub fn cast_proposal_vote(ctx: Context<CastProposalVote>, choice: VoteChoice) -> Result<()> {
+        cast_proposal_vote::handler(ctx, choice)
+    }
+
+    pub fn cast_delegator_vote(
+        ctx: Context<CastDelegatorVote>,
+        choice: VoteChoice,
+        stake_merkle_proof: Vec<[u8; 32]>,
+        stake_merkle_leaf: StakeMerkleLeaf,
+    ) -> Result<()> {
+        cast_delegator_vote::handler(ctx, choice, stake_merkle_proof, stake_merkle_leaf)
+    }
+
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        finalize_proposal::handler(ctx)
+    }
+
     pub fn verify_merkle_proof(
         ctx: Context<VerifyMerkleProof>,
         stake_merkle_proof: Option<Vec<[u8; 32]>>,
@@ -89,4 +329,18 @@ pub mod gov_v1 {
     ) -> Result<()> {
         verify_merkle_proof::handler(ctx, stake_merkle_proof, stake_merkle_leaf)
     }
+
+    pub fn verify_stake_merkle_proofs_batch(
+        ctx: Context<VerifyStakeMerkleProofsBatch>,
+        stake_merkle_leaves: Vec<StakeMerkleLeaf>,
+        stake_merkle_proof: Vec<[u8; 32]>,
+        proof_flags: Vec<bool>,
+    ) -> Result<()> {
+        verify_stake_merkle_proofs_batch::handler(
+            ctx,
+            stake_merkle_leaves,
+            stake_merkle_proof,
+            proof_flags,
+        )
+    }
 }
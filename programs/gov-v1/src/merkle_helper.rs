@@ -1,29 +1,11 @@
 use anchor_lang::{
     err,
     prelude::{msg, Result},
-    solana_program::hash::{hashv, Hash},
+    solana_program::hash::Hash,
 };
 
 use crate::error::ErrorCode;
 
-macro_rules! hash_leaf {
-    {$d:ident} => {
-        hashv(&[LEAF_PREFIX, $d])
-    }
-}
-
-macro_rules! hash_intermediate {
-    {$l:ident, $r:ident} => {
-        hashv(&[INTERMEDIATE_PREFIX, $l.as_ref(), $r.as_ref()])
-    }
-}
-
-// We need to discern between leaf and intermediate nodes to prevent trivial second
-// pre-image attacks.
-// https://flawed.net.nz/2018/02/21/attacking-merkle-trees-with-a-second-preimage-attack
-const LEAF_PREFIX: &[u8] = &[0];
-const INTERMEDIATE_PREFIX: &[u8] = &[1];
-
 /// Verifies a Merkle proof from a leaf's content and its sibling hashes.
 ///
 /// Hashes the leaf with a prefix, then hashes iteratively with sorted sibling,
@@ -31,18 +13,32 @@ const INTERMEDIATE_PREFIX: &[u8] = &[1];
 ///
 /// Compares the Merkle root with the expected `root` and returns an error if it doesnt match.
 pub fn verify_helper(leaf_content: &[u8], proof: &[[u8; 32]], root: Hash) -> Result<()> {
-    let mut node = hash_leaf!(leaf_content);
+    let node = Hash::new_from_array(gov_merkle::compute_root(leaf_content, proof));
 
-    for &p in proof {
-        let sibling_node = Hash::from(p);
-
-        if node <= sibling_node {
-            node = hash_intermediate!(node, sibling_node)
-        } else {
-            node = hash_intermediate!(sibling_node, node)
-        }
+    if root != node {
+        msg!("Root {:?} != Node {:?}", root, node);
+        return err!(ErrorCode::InvalidMerkleProof);
     }
 
+    Ok(())
+}
+
+/// Verifies a Merkle multiproof for several leaves of the same tree, as generated by
+/// `gov_merkle::MerkleTree::get_multiproof`.
+///
+/// Compares the Merkle root with the expected `root` and returns an error if it doesnt match
+/// or the multiproof is malformed.
+pub fn verify_multiproof_helper(
+    leaf_contents: &[[u8; 32]],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+    root: Hash,
+) -> Result<()> {
+    let Some(node) = gov_merkle::compute_multiproof_root(leaf_contents, proof, proof_flags) else {
+        return err!(ErrorCode::InvalidMerkleProof);
+    };
+    let node = Hash::new_from_array(node);
+
     if root != node {
         msg!("Root {:?} != Node {:?}", root, node);
         return err!(ErrorCode::InvalidMerkleProof);
@@ -0,0 +1,79 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    system_program::{self, Allocate, Assign, CreateAccount, Transfer},
+};
+
+/// Grows a PDA of this program towards `target_len`, creating it if it doesn't exist yet.
+///
+/// An instruction can only grow an account by `MAX_PERMITTED_DATA_INCREASE` bytes, so larger
+/// accounts are allocated over several instructions. Rent is topped up from `payer`.
+pub fn grow_pda<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    target_len: usize,
+) -> Result<()> {
+    let current_len = account.data_len();
+    let new_len = target_len.min(current_len.saturating_add(MAX_PERMITTED_DATA_INCREASE));
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let is_created = *account.owner != system_program::ID;
+
+    if !is_created && account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            new_len as u64,
+            &crate::ID,
+        );
+    }
+
+    let lamports = rent.saturating_sub(account.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    if is_created {
+        account.resize(new_len)?;
+    } else {
+        // Pre-funded PDA, which can't be created with create_account.
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            new_len as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            &crate::ID,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, OperatorWhitelistUpdate, ProgramConfigUpdate};
+
+#[account]
+#[derive(Debug)]
+pub struct AdminProposal {
+    /// ID
+    pub proposal_id: u64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Admin that created the proposal. Receives the lamports when closed.
+    pub proposer: Pubkey,
+    /// Action executed once the proposal has enough approvals.
+    pub action: AdminAction,
+    /// ProgramConfig admin_set_id when the proposal was created.
+    pub admin_set_id: u64,
+    /// Bitmap of approvals, bit i set if the admin at index i of the admin set approved.
+    /// Wide enough for MAX_ADMINS.
+    pub approvals: u16,
+}
+
+impl AdminProposal {
+    pub fn pda(proposal_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"AdminProposal", &proposal_id.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+
+    pub fn init_space(action: &AdminAction) -> usize {
+        let action_len = action.try_to_vec().map(|data| data.len()).unwrap_or(0);
+        41 + action_len + 8 + 2
+    }
+
+    /// Fails if the admin set changed since the proposal was created.
+    pub fn require_admin_set(&self, admin_set_id: u64) -> Result<()> {
+        require_eq!(
+            self.admin_set_id,
+            admin_set_id,
+            ErrorCode::StaleAdminProposal
+        );
+        Ok(())
+    }
+
+    /// Records the approval of the admin at `admin_index` of the admin set.
+    pub fn approve(&mut self, admin_index: usize) -> Result<()> {
+        let bit = 1 << admin_index;
+        require!(self.approvals & bit == 0, ErrorCode::AdminHasApproved);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+/// Config change executed by an AdminProposal.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum AdminAction {
+    /// Same as `update_operator_whitelist`.
+    UpdateOperatorWhitelist(OperatorWhitelistUpdate),
+    /// Same as `update_program_config`.
+    UpdateProgramConfig(ProgramConfigUpdate),
+    /// Same as `set_tie_breaker` for the ballot box of the given ID.
+    SetTieBreaker { ballot_id: u64, ballot_index: u8 },
+    /// Same as `extend_vote_expiry` for the ballot box of the given ID.
+    ExtendVoteExpiry {
+        ballot_id: u64,
+        vote_expiry_timestamp: i64,
+    },
+    /// Same as `cancel_ballot_box` for the ballot box of the given ID.
+    CancelBallotBox { ballot_id: u64 },
+    /// Same as `cancel_pending_config`.
+    CancelPendingConfig,
+    /// Replaces the admin set. An empty set with a threshold of 0 disables multisig.
+    SetAdmins { admins: Vec<Pubkey>, threshold: u8 },
+}
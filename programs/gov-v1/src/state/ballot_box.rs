@@ -1,41 +1,229 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    error::ErrorCode, state::program_config::MAX_OPERATOR_WHITELIST, DecisionMethod,
+    ExpiryResolution, ProgramConfig,
+};
 
-pub const MAX_OPERATOR_VOTES: usize = 64;
 pub const MAX_BALLOT_TALLIES: usize = 64;
+/// Bytes reserved at the end of BallotBox for fields added in later versions.
+const RESERVED_SPACE: usize = 192;
 
-#[account]
-#[derive(InitSpace, Debug)]
+/// Zero-copy ballot box. Votes are indexed by the operator's position in eligible_operators,
+/// so voting only touches the operator's own entry and the ballot tallies.
+#[account(zero_copy)]
+#[derive(Debug)]
 pub struct BallotBox {
     /// ID
     pub ballot_id: u64,
-    /// Bump seed for the PDA
-    pub bump: u8,
     /// The epoch this ballot box is for
     pub epoch: u64,
     /// Slot when this ballot box was created
     pub slot_created: u64,
+    /// Slot of the snapshot operators vote on. Ballots must carry the same slot.
+    pub target_slot: u64,
     /// Slot when consensus was reached
     pub slot_consensus_reached: u64,
-    /// Min. percentage of votes required to finalize for this ballot box.
-    pub min_consensus_threshold_bps: u16,
+    /// Timestamp when voting ends. If no consensus is reached by then, the ballot box is
+    /// resolved according to the ExpiryResolution of the ProgramConfig.
+    pub vote_expiry_timestamp: i64,
+    /// Timestamp when the ConsensusResult was created. 0 if not finalized.
+    pub finalized_timestamp: i64,
+    /// Timestamp when the ballot box was created. Vote extensions are bounded relative to it.
+    pub created_timestamp: i64,
+    /// Timestamp when the ballot box was cancelled by the authority. 0 if not cancelled.
+    pub cancelled_timestamp: i64,
+    /// Timestamp when the commit window ends and reveals open. 0 if commit-reveal is
+    /// disabled, in which case ballots are cast directly.
+    pub commit_expiry_timestamp: i64,
     /// The ballot that got at least min_consensus_threshold of votes
     pub winning_ballot: Ballot,
-    /// Operator votes
-    #[max_len(MAX_OPERATOR_VOTES)]
-    pub operator_votes: Vec<OperatorVote>,
+    /// Wallet that paid rent for the ballot box. Receives the lamports when closed.
+    pub payer: Pubkey,
+    /// Sum of eligible_operator_weights. Used as the denominator for consensus.
+    pub total_weight: u32,
+    /// Min. percentage of votes required to finalize for this ballot box.
+    pub min_consensus_threshold_bps: u16,
+    /// Number of eligible operators
+    pub operator_count: u16,
+    /// Number of entries in ballot_tallies
+    pub ballot_tally_count: u16,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// DecisionMethod of the winning ballot, set when consensus is reached.
+    decision_method: u8,
+    /// Min. number of operators that must vote before a ballot can reach consensus.
+    pub min_votes: u16,
+    /// Non-zero if voting expired without a result and no ballot can win.
+    failed: u8,
+    /// Layout version.
+    pub version: u8,
+    /// Operators eligible to vote, copied from the whitelist at creation. Changes to the
+    /// whitelist after creation do not affect this ballot box.
+    eligible_operators: [Pubkey; MAX_OPERATOR_WHITELIST],
+    /// Voting weight of each eligible operator, in the same order as eligible_operators.
+    eligible_operator_weights: [u16; MAX_OPERATOR_WHITELIST],
+    /// Bitmap of operators that voted, indexed by position in eligible_operators.
+    voted_operators: [u8; MAX_OPERATOR_WHITELIST / 8],
+    /// Operator votes, indexed by position in eligible_operators.
+    operator_votes: [OperatorVote; MAX_OPERATOR_WHITELIST],
     /// Mapping of ballots votes to stake weight
-    #[max_len(MAX_BALLOT_TALLIES)]
-    pub ballot_tallies: Vec<BallotTally>,
-    /// Timestamp when voting ends. Tie breaker admin will decide the results
-    /// if no consensus is reached by then.
-    pub vote_expiry_timestamp: i64,
+    ballot_tallies: [BallotTally; MAX_BALLOT_TALLIES],
+    /// Bitmap of operators with an unrevealed commitment, indexed by position in
+    /// eligible_operators.
+    committed_operators: [u8; MAX_OPERATOR_WHITELIST / 8],
+    /// Bitmap of operators whose OperatorStats recorded this ballot box once it was
+    /// finalized, indexed by position in eligible_operators.
+    stats_recorded_operators: [u8; MAX_OPERATOR_WHITELIST / 8],
+    _reserved: [u8; RESERVED_SPACE],
 }
 
 impl BallotBox {
+    /// Account size including the discriminator. Larger than a single instruction can
+    /// allocate, so the account is grown by `realloc_ballot_box` before initialization.
+    pub const SIZE: usize = 8 + std::mem::size_of::<BallotBox>();
+    pub const VERSION: u8 = 1;
+
     pub fn pda(ballot_id: u64) -> (Pubkey, u8) {
         Pubkey::find_program_address(&[b"BallotBox", &ballot_id.to_le_bytes()], &crate::ID)
     }
 
+    /// PDA of a scheduled ballot box, unique per epoch and target slot.
+    pub fn scheduled_pda(epoch: u64, target_slot: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"BallotBox",
+                &epoch.to_le_bytes(),
+                &target_slot.to_le_bytes(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Initializes the ballot box for the snapshot at target_slot, copying the consensus
+    /// threshold, min. votes and operator whitelist from the ProgramConfig.
+    pub fn init(
+        &mut self,
+        ballot_id: u64,
+        bump: u8,
+        payer: Pubkey,
+        target_slot: u64,
+        program_config: &ProgramConfig,
+        clock: &Clock,
+    ) -> Result<()> {
+        // The target slot must be in the current or previous epoch, so that snapshots taken
+        // near the end of an epoch can still be voted on after the epoch boundary.
+        let target_epoch = EpochSchedule::get()?.get_epoch(target_slot);
+        require!(
+            target_epoch <= clock.epoch && target_epoch >= clock.epoch.saturating_sub(1),
+            ErrorCode::InvalidTargetSlot
+        );
+        // No snapshot exists yet for a later slot.
+        require_gte!(clock.slot, target_slot, ErrorCode::TargetSlotNotReached);
+        require_gt!(
+            program_config.min_consensus_threshold_bps,
+            0,
+            ErrorCode::ConsensusThresholdNotSet
+        );
+        require_gte!(
+            program_config.whitelisted_operators.len(),
+            usize::from(program_config.min_votes),
+            ErrorCode::MinVotesUnreachable
+        );
+
+        self.version = Self::VERSION;
+        self.ballot_id = ballot_id;
+        self.bump = bump;
+        self.payer = payer;
+        self.epoch = target_epoch;
+        self.slot_created = clock.slot;
+        self.target_slot = target_slot;
+        self.min_consensus_threshold_bps = program_config.min_consensus_threshold_bps;
+        self.min_votes = program_config.min_votes;
+        let operator_count = program_config.whitelisted_operators.len();
+        self.operator_count = operator_count.try_into().unwrap();
+        self.eligible_operators[..operator_count]
+            .copy_from_slice(&program_config.whitelisted_operators);
+        self.eligible_operator_weights[..operator_count]
+            .copy_from_slice(&program_config.operator_weights);
+        self.total_weight = program_config
+            .operator_weights
+            .iter()
+            .map(|weight| u32::from(*weight))
+            .sum();
+        self.created_timestamp = clock.unix_timestamp;
+
+        // With commit-reveal, the reveal window of vote_duration starts after the commit window.
+        let voting_start_timestamp = if program_config.commit_duration > 0 {
+            self.commit_expiry_timestamp = clock
+                .unix_timestamp
+                .checked_add(program_config.commit_duration)
+                .unwrap();
+            self.commit_expiry_timestamp
+        } else {
+            clock.unix_timestamp
+        };
+        self.vote_expiry_timestamp = voting_start_timestamp
+            .checked_add(program_config.vote_duration)
+            .unwrap();
+        Ok(())
+    }
+
+    /// Copies a legacy borsh ballot box into the zero-copy layout. Legacy ballot boxes
+    /// counted votes against the current whitelist, so the eligible operators are the
+    /// whitelisted operators followed by any voters removed from it since, each with a
+    /// weight of 1. Votes are moved to the position of their operator in eligible_operators.
+    pub fn migrate(
+        &mut self,
+        legacy: &LegacyBallotBox,
+        whitelisted_operators: &[Pubkey],
+    ) -> Result<()> {
+        self.version = Self::VERSION;
+        self.ballot_id = legacy.ballot_id;
+        self.bump = legacy.bump;
+        self.epoch = legacy.epoch;
+        self.slot_created = legacy.slot_created;
+        self.slot_consensus_reached = legacy.slot_consensus_reached;
+        self.min_consensus_threshold_bps = legacy.min_consensus_threshold_bps;
+        self.winning_ballot = legacy.winning_ballot.into();
+        self.vote_expiry_timestamp = legacy.vote_expiry_timestamp;
+
+        let voters = legacy.operator_votes.iter().map(|vote| vote.operator);
+        let mut operator_count = 0;
+        for operator in whitelisted_operators.iter().copied().chain(voters) {
+            if self.eligible_operators[..operator_count].contains(&operator) {
+                continue;
+            }
+            require!(operator_count < MAX_OPERATOR_WHITELIST, ErrorCode::VecFull);
+            self.eligible_operators[operator_count] = operator;
+            self.eligible_operator_weights[operator_count] = 1;
+            operator_count += 1;
+        }
+        self.operator_count = operator_count.try_into().unwrap();
+        self.total_weight = operator_count.try_into().unwrap();
+
+        for (idx, ballot_tally) in legacy.ballot_tallies.iter().enumerate() {
+            self.ballot_tallies[idx] = BallotTally::new(
+                ballot_tally.index,
+                ballot_tally.ballot.into(),
+                ballot_tally.tally.into(),
+            );
+        }
+        self.ballot_tally_count = legacy.ballot_tallies.len().try_into().unwrap();
+        for vote in legacy.operator_votes.iter() {
+            self.record_vote(&vote.operator, vote.ballot_index, vote.slot_voted)?;
+        }
+        // Legacy ballot boxes didn't record the decision method. A winning ballot below the
+        // threshold can only have been set by the tie breaker.
+        if self.has_consensus_reached()
+            && self.tally_bps(self.winning_tally()) < self.min_consensus_threshold_bps
+        {
+            self.decision_method = DecisionMethod::TieBreaker as u8;
+        }
+        Ok(())
+    }
+
     pub fn has_vote_expired(&self, current_timestamp: i64) -> bool {
         current_timestamp >= self.vote_expiry_timestamp
     }
@@ -43,35 +231,637 @@ impl BallotBox {
     pub fn has_consensus_reached(&self) -> bool {
         self.slot_consensus_reached != 0
     }
+
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_timestamp != 0
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_timestamp != 0
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.failed != 0
+    }
+
+    /// Returns true until the ballot box is finalized, cancelled or failed.
+    pub fn is_open(&self) -> bool {
+        !self.is_finalized() && !self.is_cancelled() && !self.is_failed()
+    }
+
+    /// True once the ballot box is finalized, cancelled or its vote expired, after which no
+    /// votes can be cast or revealed.
+    pub fn has_voting_ended(&self, current_timestamp: i64) -> bool {
+        self.is_finalized() || self.is_cancelled() || self.has_vote_expired(current_timestamp)
+    }
+
+    pub fn is_commit_reveal(&self) -> bool {
+        self.commit_expiry_timestamp != 0
+    }
+
+    pub fn has_commit_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.commit_expiry_timestamp
+    }
+
+    /// Moves vote_expiry_timestamp later on an open ballot box. The total voting duration
+    /// since creation can't exceed max_vote_duration.
+    pub fn extend_vote_expiry(
+        &mut self,
+        vote_expiry_timestamp: i64,
+        max_vote_duration: i64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        require!(!self.is_cancelled(), ErrorCode::BallotBoxCancelled);
+        require!(
+            !self.has_vote_expired(current_timestamp),
+            ErrorCode::VotingExpired
+        );
+        require!(!self.has_consensus_reached(), ErrorCode::ConsensusReached);
+        require_gt!(
+            vote_expiry_timestamp,
+            self.vote_expiry_timestamp,
+            ErrorCode::InvalidVoteExpiry
+        );
+        require_gte!(
+            self.created_timestamp.checked_add(max_vote_duration).unwrap(),
+            vote_expiry_timestamp,
+            ErrorCode::MaxVoteDurationExceeded
+        );
+
+        self.vote_expiry_timestamp = vote_expiry_timestamp;
+        Ok(())
+    }
+
+    /// Cancels the ballot box, so that it can no longer be voted on or finalized.
+    pub fn cancel(&mut self, current_timestamp: i64) -> Result<()> {
+        require!(!self.is_cancelled(), ErrorCode::BallotBoxCancelled);
+        require!(!self.is_finalized(), ErrorCode::BallotFinalized);
+        require!(!self.is_failed(), ErrorCode::BallotBoxFailed);
+
+        self.cancelled_timestamp = current_timestamp;
+        Ok(())
+    }
+
+    /// Selects the winning ballot from ballot_tallies after voting expired without consensus.
+    pub fn set_tie_breaker(&mut self, ballot_index: u8, clock: &Clock) -> Result<()> {
+        require!(!self.is_cancelled(), ErrorCode::BallotBoxCancelled);
+        require!(!self.is_failed(), ErrorCode::BallotBoxFailed);
+        require!(
+            self.has_vote_expired(clock.unix_timestamp),
+            ErrorCode::VotingNotExpired
+        );
+        require!(!self.has_consensus_reached(), ErrorCode::ConsensusReached);
+        require!(
+            (ballot_index as usize) < self.ballot_tallies().len(),
+            ErrorCode::InvalidBallotIndex
+        );
+
+        self.slot_consensus_reached = clock.slot;
+        self.winning_ballot = self.ballot_tallies[ballot_index as usize].ballot;
+        self.decision_method = DecisionMethod::TieBreaker as u8;
+        Ok(())
+    }
+
+    /// Resolves a ballot box whose voting expired without consensus. With Plurality, the
+    /// ballot with the highest tally wins if no other ballot has the same tally and it reaches
+    /// plurality_floor_bps. Otherwise, and with NoResult, the ballot box is marked as failed.
+    /// With TieBreakerAdmin, it is marked as failed if the tie breaker admin didn't set a
+    /// winner within close_grace_period of expiry. Returns the winning tally, or None if the
+    /// ballot box failed.
+    pub fn finalize_expired(
+        &mut self,
+        expiry_resolution: ExpiryResolution,
+        plurality_floor_bps: u16,
+        close_grace_period: i64,
+        clock: &Clock,
+    ) -> Result<Option<u32>> {
+        require!(!self.is_cancelled(), ErrorCode::BallotBoxCancelled);
+        require!(!self.is_failed(), ErrorCode::BallotBoxFailed);
+        require!(!self.is_finalized(), ErrorCode::BallotFinalized);
+        require!(
+            self.has_vote_expired(clock.unix_timestamp),
+            ErrorCode::VotingNotExpired
+        );
+        require!(!self.has_consensus_reached(), ErrorCode::ConsensusReached);
+
+        let winner = match expiry_resolution {
+            // Failing the ballot box once the tie breaker admin had its chance keeps it from
+            // staying open indefinitely.
+            ExpiryResolution::TieBreakerAdmin => {
+                require_gte!(
+                    clock.unix_timestamp,
+                    self.vote_expiry_timestamp
+                        .checked_add(close_grace_period)
+                        .unwrap(),
+                    ErrorCode::InvalidExpiryResolution
+                );
+                None
+            }
+            ExpiryResolution::Plurality => self.plurality_winner(plurality_floor_bps),
+            ExpiryResolution::NoResult => None,
+        };
+
+        match winner {
+            Some(winner) => {
+                self.slot_consensus_reached = clock.slot;
+                self.winning_ballot = winner.ballot;
+                self.decision_method = DecisionMethod::Plurality as u8;
+                Ok(Some(winner.tally))
+            }
+            None => {
+                self.failed = 1;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the BallotTally with the highest tally if it is unique and reaches floor_bps.
+    fn plurality_winner(&self, floor_bps: u16) -> Option<BallotTally> {
+        let winner = self
+            .ballot_tallies()
+            .iter()
+            .max_by_key(|ballot_tally| ballot_tally.tally)?;
+        let is_unique = self.ballot_tallies().iter().all(|ballot_tally| {
+            ballot_tally.index == winner.index || ballot_tally.tally < winner.tally
+        });
+        (winner.tally > 0 && is_unique && self.tally_bps(winner.tally) >= floor_bps)
+            .then_some(*winner)
+    }
+
+    /// How the winning ballot was decided. Only meaningful once consensus is reached.
+    pub fn decision_method(&self) -> DecisionMethod {
+        if self.decision_method == DecisionMethod::TieBreaker as u8 {
+            DecisionMethod::TieBreaker
+        } else if self.decision_method == DecisionMethod::Plurality as u8 {
+            DecisionMethod::Plurality
+        } else {
+            DecisionMethod::Consensus
+        }
+    }
+
+    /// Current tally of the winning ballot, including votes cast after consensus.
+    pub fn winning_tally(&self) -> u32 {
+        self.ballot_tallies()
+            .iter()
+            .find(|ballot_tally| ballot_tally.ballot == self.winning_ballot)
+            .map_or(0, |ballot_tally| ballot_tally.tally)
+    }
+
+    /// Returns the tally as a percentage of total_weight, in basis points.
+    pub fn tally_bps(&self, tally: u32) -> u16 {
+        (u64::from(tally) * 10000 / u64::from(self.total_weight))
+            .try_into()
+            .unwrap()
+    }
+
+    pub fn eligible_operators(&self) -> &[Pubkey] {
+        &self.eligible_operators[..usize::from(self.operator_count)]
+    }
+
+    pub fn eligible_operator_weights(&self) -> &[u16] {
+        &self.eligible_operator_weights[..usize::from(self.operator_count)]
+    }
+
+    pub fn ballot_tallies(&self) -> &[BallotTally] {
+        &self.ballot_tallies[..usize::from(self.ballot_tally_count)]
+    }
+
+    /// Returns the position of an eligible operator in eligible_operators.
+    pub fn operator_index(&self, operator: &Pubkey) -> Result<usize> {
+        let idx = self
+            .eligible_operators()
+            .iter()
+            .position(|op| op == operator)
+            .ok_or(ErrorCode::OperatorNotWhitelisted)?;
+        Ok(idx)
+    }
+
+    /// Replaces an eligible operator key. Votes are indexed by position in eligible_operators,
+    /// so the operator's vote and weight carry over to the new key.
+    pub fn rotate_operator_key(
+        &mut self,
+        old_operator: &Pubkey,
+        new_operator: Pubkey,
+    ) -> Result<()> {
+        require!(
+            self.operator_index(&new_operator).is_err(),
+            ErrorCode::OperatorAlreadyWhitelisted
+        );
+        let idx = self.operator_index(old_operator)?;
+        self.eligible_operators[idx] = new_operator;
+        Ok(())
+    }
+
+    /// Returns the voting weight of an eligible operator.
+    pub fn operator_weight(&self, operator: &Pubkey) -> Result<u16> {
+        Ok(self.eligible_operator_weights[self.operator_index(operator)?])
+    }
+
+    fn has_voted(&self, operator_index: usize) -> bool {
+        self.voted_operators[operator_index / 8] & (1 << (operator_index % 8)) != 0
+    }
+
+    /// Returns the vote of an operator, or None if it has not voted.
+    pub fn operator_vote(&self, operator: &Pubkey) -> Option<&OperatorVote> {
+        let idx = self.operator_index(operator).ok()?;
+        self.has_voted(idx).then(|| &self.operator_votes[idx])
+    }
+
+    /// Returns true if the operator's vote is for the winning ballot.
+    pub fn voted_for_winning_ballot(&self, operator: &Pubkey) -> bool {
+        self.operator_vote(operator).is_some_and(|vote| {
+            self.ballot_tallies[usize::from(vote.ballot_index)].ballot == self.winning_ballot
+        })
+    }
+
+    /// Returns the operators that voted with their votes, in the order of eligible_operators.
+    pub fn operator_votes(&self) -> impl Iterator<Item = (&Pubkey, &OperatorVote)> {
+        self.eligible_operators()
+            .iter()
+            .zip(self.operator_votes.iter())
+            .enumerate()
+            .filter(|(idx, _)| self.has_voted(*idx))
+            .map(|(_, vote)| vote)
+    }
+
+    /// Number of operators that voted.
+    pub fn vote_count(&self) -> usize {
+        self.voted_operators
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+
+    /// Records the operator's vote for the BallotTally at ballot_index.
+    pub fn record_vote(&mut self, operator: &Pubkey, ballot_index: u8, slot: u64) -> Result<()> {
+        let idx = self.operator_index(operator)?;
+        require!(!self.has_voted(idx), ErrorCode::OperatorHasVoted);
+
+        self.voted_operators[idx / 8] |= 1 << (idx % 8);
+        self.operator_votes[idx] = OperatorVote::new(slot, ballot_index);
+        Ok(())
+    }
+
+    fn has_committed(&self, operator_index: usize) -> bool {
+        self.committed_operators[operator_index / 8] & (1 << (operator_index % 8)) != 0
+    }
+
+    /// Records that the operator committed to a ballot that is yet to be revealed.
+    pub fn record_commit(&mut self, operator: &Pubkey) -> Result<()> {
+        let idx = self.operator_index(operator)?;
+        self.committed_operators[idx / 8] |= 1 << (idx % 8);
+        Ok(())
+    }
+
+    /// Clears the operator's commitment once it is revealed.
+    pub fn clear_commit(&mut self, operator: &Pubkey) -> Result<()> {
+        let idx = self.operator_index(operator)?;
+        self.committed_operators[idx / 8] &= !(1 << (idx % 8));
+        Ok(())
+    }
+
+    /// Returns true if the operator has a commitment that can still be revealed.
+    pub fn has_open_commit(&self, operator: &Pubkey, current_timestamp: i64) -> bool {
+        self.operator_index(operator)
+            .is_ok_and(|idx| self.has_committed(idx))
+            && !self.has_voting_ended(current_timestamp)
+    }
+
+    /// Returns true if the ballot box is recorded in the operator's OperatorStats.
+    pub fn has_operator_stats_recorded(&self, operator_index: usize) -> bool {
+        self.stats_recorded_operators[operator_index / 8] & (1 << (operator_index % 8)) != 0
+    }
+
+    /// Marks the finalized ballot box as recorded in the operator's OperatorStats, which
+    /// can only happen once per operator.
+    pub fn record_operator_stats(&mut self, operator: &Pubkey) -> Result<()> {
+        require!(self.is_finalized(), ErrorCode::BallotNotFinalized);
+        let idx = self.operator_index(operator)?;
+        require!(
+            !self.has_operator_stats_recorded(idx),
+            ErrorCode::OperatorStatsAlreadyRecorded
+        );
+        self.stats_recorded_operators[idx / 8] |= 1 << (idx % 8);
+        Ok(())
+    }
+
+    /// Clears the operator's vote and returns the index of the ballot it voted for.
+    pub fn clear_vote(&mut self, operator: &Pubkey) -> Result<u8> {
+        let idx = self.operator_index(operator)?;
+        require!(self.has_voted(idx), ErrorCode::OperatorHasNotVoted);
+
+        self.voted_operators[idx / 8] &= !(1 << (idx % 8));
+        let ballot_index = self.operator_votes[idx].ballot_index;
+        self.operator_votes[idx] = OperatorVote::zeroed();
+        Ok(ballot_index)
+    }
+
+    /// Checks that the ballot is valid for this ballot box.
+    pub fn validate_ballot(&self, ballot: &Ballot) -> Result<()> {
+        require!(ballot.meta_merkle_root != [0; 32], ErrorCode::InvalidBallot);
+        require!(
+            ballot.snapshot_slot == self.target_slot,
+            ErrorCode::SnapshotSlotMismatch
+        );
+        Ok(())
+    }
+
+    /// Adds weight to the tally of the ballot and returns its index and new tally. A new
+    /// ballot reuses a BallotTally whose tally dropped to 0 before appending one.
+    pub fn add_tally(&mut self, ballot: &Ballot, weight: u32) -> Result<(u8, u32)> {
+        let idx = match self.ballot_tallies().iter().position(|t| t.ballot == *ballot) {
+            Some(idx) => idx,
+            // Operator weights are non-zero, so no operator vote points to a tally of 0.
+            None => match self.ballot_tallies().iter().position(|t| t.tally == 0) {
+                Some(idx) => {
+                    self.ballot_tallies[idx].ballot = *ballot;
+                    idx
+                }
+                None => {
+                    let idx = self.ballot_tallies().len();
+                    require!(idx < MAX_BALLOT_TALLIES, ErrorCode::VecFull);
+                    self.ballot_tallies[idx] =
+                        BallotTally::new(idx.try_into().unwrap(), *ballot, 0);
+                    self.ballot_tally_count += 1;
+                    idx
+                }
+            },
+        };
+
+        let ballot_tally = &mut self.ballot_tallies[idx];
+        ballot_tally.tally = ballot_tally.tally.checked_add(weight).unwrap();
+        Ok((ballot_tally.index, ballot_tally.tally))
+    }
+
+    /// Subtracts weight from the tally at ballot_index and returns the new tally. The
+    /// BallotTally is kept when its tally drops to 0 to maintain the order of indices.
+    pub fn remove_tally(&mut self, ballot_index: u8, weight: u32) -> u32 {
+        let ballot_tally = &mut self.ballot_tallies[ballot_index as usize];
+        ballot_tally.tally = ballot_tally.tally.checked_sub(weight).unwrap();
+        ballot_tally.tally
+    }
+
+    /// Sets the winning ballot if, for the first time, min_votes operators have voted and a
+    /// tally reaches the consensus threshold. Returns the winning tally if consensus was reached.
+    pub fn try_reach_consensus(&mut self, slot: u64) -> Option<u32> {
+        if self.has_consensus_reached() || self.vote_count() < usize::from(self.min_votes) {
+            return None;
+        }
+
+        // Once the quorum is met, more than one ballot can be above a threshold of 50% or
+        // less. The highest tally wins, and the lowest index on equal tallies.
+        let winner = self
+            .ballot_tallies()
+            .iter()
+            .filter(|ballot_tally| {
+                self.tally_bps(ballot_tally.tally) >= self.min_consensus_threshold_bps
+            })
+            .max_by(|a, b| a.tally.cmp(&b.tally).then(b.index.cmp(&a.index)))
+            .copied()?;
+
+        self.slot_consensus_reached = slot;
+        self.winning_ballot = winner.ballot;
+        Some(winner.tally)
+    }
 }
 
 /// Inner struct of BallotBox
-#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Default)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Default)]
+#[derive(Pod, Zeroable)]
+#[repr(C)]
 pub struct Ballot {
     /// The merkle root of the meta merkle tree
     pub meta_merkle_root: [u8; 32],
     /// SHA256 hash of borsh serialized snapshot. Optional.
     pub snapshot_hash: [u8; 32],
+    /// Slot the snapshot was taken at
+    pub snapshot_slot: u64,
+    /// Sum of active_stake of all MetaMerkleLeafs in the snapshot
+    pub total_active_stake: u64,
+    /// Number of MetaMerkleLeafs (vote accounts) in the snapshot
+    pub vote_account_count: u32,
+    /// Number of StakeMerkleLeafs (stake accounts) in the snapshot
+    pub stake_account_count: u32,
+}
+
+impl Ballot {
+    /// Commitment to the ballot in commit-reveal mode: SHA256 of the operator, the ballot box,
+    /// the borsh serialized ballot and the salt. Binding the operator and ballot box prevents
+    /// a commitment from being copied and revealed by another operator or in another box.
+    pub fn commitment(&self, operator: &Pubkey, ballot_box: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[
+            operator.as_ref(),
+            ballot_box.as_ref(),
+            &self.try_to_vec().unwrap(),
+            salt,
+        ])
+        .to_bytes()
+    }
 }
 
 /// Inner struct of BallotBox
-#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
+#[zero_copy]
+#[derive(Debug, PartialEq)]
 pub struct OperatorVote {
-    /// The operator that cast the vote
-    pub operator: Pubkey,
     /// The slot the operator voted
     pub slot_voted: u64,
     /// The index of the ballot in the ballot_tallies
     pub ballot_index: u8,
+    _padding: [u8; 7],
+}
+
+impl OperatorVote {
+    pub fn new(slot_voted: u64, ballot_index: u8) -> Self {
+        Self {
+            slot_voted,
+            ballot_index,
+            _padding: [0; 7],
+        }
+    }
 }
 
 /// Inner struct of BallotBox
-#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
+#[zero_copy]
+#[derive(Debug, PartialEq)]
 pub struct BallotTally {
-    /// Index of the tally within the ballot_tallies
-    pub index: u8,
     /// The ballot being tallied
     pub ballot: Ballot,
-    /// The number of votes for this ballot. Each vote is equally weighted.
+    /// The sum of weights of operators that voted for this ballot.
+    pub tally: u32,
+    /// Index of the tally within the ballot_tallies
+    pub index: u8,
+    _padding: [u8; 3],
+}
+
+impl BallotTally {
+    pub fn new(index: u8, ballot: Ballot, tally: u32) -> Self {
+        Self {
+            ballot,
+            tally,
+            index,
+            _padding: [0; 3],
+        }
+    }
+}
+
+/// Seeds of a BallotBox PDA, used to allocate the account before it is initialized.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum BallotBoxSeeds {
+    /// Ballot box created with `init_ballot_box`, keyed by ID.
+    Id(u64),
+    /// Ballot box created with `init_scheduled_ballot_box`, keyed by epoch and target slot.
+    Scheduled { epoch: u64, target_slot: u64 },
+}
+
+impl BallotBoxSeeds {
+    pub fn seeds(&self) -> Vec<Vec<u8>> {
+        match self {
+            BallotBoxSeeds::Id(ballot_id) => {
+                vec![b"BallotBox".to_vec(), ballot_id.to_le_bytes().to_vec()]
+            }
+            BallotBoxSeeds::Scheduled { epoch, target_slot } => vec![
+                b"BallotBox".to_vec(),
+                epoch.to_le_bytes().to_vec(),
+                target_slot.to_le_bytes().to_vec(),
+            ],
+        }
+    }
+}
+
+/// Borsh layout of BallotBox before it was made zero-copy. Accounts in this layout are
+/// smaller than BallotBox::SIZE and are converted by `migrate_ballot_box`.
+#[derive(Debug, AnchorDeserialize)]
+pub struct LegacyBallotBox {
+    pub ballot_id: u64,
+    pub bump: u8,
+    pub epoch: u64,
+    pub slot_created: u64,
+    pub slot_consensus_reached: u64,
+    pub min_consensus_threshold_bps: u16,
+    pub winning_ballot: LegacyBallot,
+    pub operator_votes: Vec<LegacyOperatorVote>,
+    pub ballot_tallies: Vec<LegacyBallotTally>,
+    pub vote_expiry_timestamp: i64,
+}
+
+/// Inner struct of LegacyBallotBox
+#[derive(Debug, AnchorDeserialize)]
+pub struct LegacyOperatorVote {
+    pub operator: Pubkey,
+    pub slot_voted: u64,
+    pub ballot_index: u8,
+}
+
+/// Inner struct of LegacyBallotBox. Each vote counted as 1.
+#[derive(Debug, AnchorDeserialize)]
+pub struct LegacyBallotTally {
+    pub index: u8,
+    pub ballot: LegacyBallot,
     pub tally: u8,
 }
+
+/// Inner struct of LegacyBallotBox and LegacyConsensusResult, without the snapshot slot
+/// and totals.
+#[derive(Debug, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct LegacyBallot {
+    pub meta_merkle_root: [u8; 32],
+    pub snapshot_hash: [u8; 32],
+}
+
+impl From<LegacyBallot> for Ballot {
+    fn from(ballot: LegacyBallot) -> Self {
+        Self {
+            meta_merkle_root: ballot.meta_merkle_root,
+            snapshot_hash: ballot.snapshot_hash,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account size of a BallotBox in the baseline borsh layout, which was allocated for
+    /// 64 operator votes and ballot tallies.
+    const LEGACY_SIZE: usize = 8 + 35 + 64 + (4 + 64 * 41) + (4 + 64 * 66) + 8;
+
+    fn encode_legacy_ballot(data: &mut Vec<u8>, ballot: &Ballot) {
+        data.extend_from_slice(&ballot.meta_merkle_root);
+        data.extend_from_slice(&ballot.snapshot_hash);
+    }
+
+    #[test]
+    fn test_migrate_legacy_ballot_box() {
+        let operators: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let ballot_a = Ballot {
+            meta_merkle_root: [1; 32],
+            snapshot_hash: [2; 32],
+            ..Default::default()
+        };
+        let ballot_b = Ballot {
+            meta_merkle_root: [3; 32],
+            snapshot_hash: [4; 32],
+            ..Default::default()
+        };
+
+        // Operators 0 and 1 voted for ballot A, operator 3 for ballot B before it was
+        // removed from the whitelist. The tie breaker selected ballot A.
+        let mut data = BallotBox::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.push(254);
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&2_000u64.to_le_bytes());
+        data.extend_from_slice(&6_667u16.to_le_bytes());
+        encode_legacy_ballot(&mut data, &ballot_a);
+        data.extend_from_slice(&3u32.to_le_bytes());
+        for (operator, ballot_index) in [(operators[0], 0u8), (operators[1], 0), (operators[3], 1)]
+        {
+            data.extend_from_slice(operator.as_ref());
+            data.extend_from_slice(&1_500u64.to_le_bytes());
+            data.push(ballot_index);
+        }
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for (index, ballot, tally) in [(0u8, &ballot_a, 2u8), (1, &ballot_b, 1)] {
+            data.push(index);
+            encode_legacy_ballot(&mut data, ballot);
+            data.push(tally);
+        }
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        data.resize(LEGACY_SIZE, 0);
+
+        let legacy = LegacyBallotBox::deserialize(&mut &data[8..]).unwrap();
+        let mut account = vec![0u64; std::mem::size_of::<BallotBox>() / 8];
+        let ballot_box: &mut BallotBox =
+            bytemuck::from_bytes_mut(bytemuck::cast_slice_mut(account.as_mut_slice()));
+        ballot_box.migrate(&legacy, &operators[..3]).unwrap();
+
+        assert_eq!(ballot_box.version, BallotBox::VERSION);
+        assert_eq!(ballot_box.ballot_id, 7);
+        assert_eq!(ballot_box.bump, 254);
+        assert_eq!(ballot_box.epoch, 100);
+        assert_eq!(ballot_box.slot_created, 1_000);
+        assert_eq!(ballot_box.slot_consensus_reached, 2_000);
+        assert_eq!(ballot_box.min_consensus_threshold_bps, 6_667);
+        assert_eq!(ballot_box.vote_expiry_timestamp, 86_400);
+        assert_eq!(ballot_box.eligible_operators(), &operators[..]);
+        assert_eq!(ballot_box.eligible_operator_weights(), &[1; 4]);
+        assert_eq!(ballot_box.total_weight, 4);
+        assert_eq!(ballot_box.vote_count(), 3);
+        assert!(ballot_box.operator_vote(&operators[2]).is_none());
+        assert_eq!(
+            ballot_box.operator_vote(&operators[3]),
+            Some(&OperatorVote::new(1_500, 1))
+        );
+        assert_eq!(
+            ballot_box.ballot_tallies(),
+            &[
+                BallotTally::new(0, ballot_a, 2),
+                BallotTally::new(1, ballot_b, 1)
+            ]
+        );
+        assert_eq!(ballot_box.winning_ballot, ballot_a);
+        assert_eq!(ballot_box.decision_method(), DecisionMethod::TieBreaker);
+    }
+}
@@ -1,18 +1,91 @@
 use anchor_lang::prelude::*;
 
-use crate::Ballot;
+use crate::{Ballot, BallotBox, LegacyBallot};
+
+/// Bytes reserved at the end of ConsensusResult for fields added in later versions.
+const RESERVED_SPACE: usize = 32;
 
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct ConsensusResult {
     /// Ballot ID
     pub ballot_id: u64,
-    /// Ballot
+    /// Ballot, including the snapshot slot, total active stake and leaf counts
     pub ballot: Ballot,
+    /// Wallet that paid rent for the consensus result. Receives the lamports when archived.
+    pub payer: Pubkey,
+    /// Whether the ballot won by operator consensus, tie breaker or plurality
+    pub decision_method: DecisionMethod,
+    /// The epoch of the snapshot
+    pub epoch: u64,
+    /// Slot when consensus was reached or the tie breaker was set
+    pub slot_consensus_reached: u64,
+    /// Sum of weights of operators that voted for the ballot at finalization
+    pub tally: u32,
+    /// Sum of weights of operators eligible to vote
+    pub total_weight: u32,
+    /// Tally as a percentage of total_weight, in basis points
+    pub tally_bps: u16,
+    /// Timestamp when the ballot was finalized
+    pub finalized_timestamp: i64,
+    /// Layout version. 0 for consensus results created before versioning.
+    pub version: u8,
+    /// BallotBox the ballot was finalized in. Must be closed before the consensus result
+    /// can be archived.
+    pub ballot_box: Pubkey,
+    _reserved: [u8; RESERVED_SPACE],
 }
 
 impl ConsensusResult {
+    pub const VERSION: u8 = 1;
+
     pub fn pda(ballot_id: u64) -> (Pubkey, u8) {
         Pubkey::find_program_address(&[b"ConsensusResult", &ballot_id.to_le_bytes()], &crate::ID)
     }
 }
+
+/// Layout of ConsensusResult before the decision fields and snapshot totals were added.
+#[derive(Debug, AnchorDeserialize, InitSpace)]
+pub struct LegacyConsensusResult {
+    pub ballot_id: u64,
+    pub ballot: LegacyBallot,
+}
+
+impl LegacyConsensusResult {
+    /// Account size including the discriminator.
+    pub const SIZE: usize = 8 + LegacyConsensusResult::INIT_SPACE;
+}
+
+impl From<LegacyConsensusResult> for ConsensusResult {
+    fn from(legacy: LegacyConsensusResult) -> Self {
+        Self {
+            ballot_id: legacy.ballot_id,
+            ballot: legacy.ballot.into(),
+            // Set by the migration, since the payer wasn't recorded.
+            payer: Pubkey::default(),
+            // Only consensus was recorded before the decision method was added.
+            decision_method: DecisionMethod::Consensus,
+            epoch: 0,
+            slot_consensus_reached: 0,
+            tally: 0,
+            total_weight: 0,
+            tally_bps: 0,
+            finalized_timestamp: 0,
+            version: 0,
+            // Legacy ballot boxes were keyed by ID.
+            ballot_box: BallotBox::pda(legacy.ballot_id).0,
+            _reserved: [0; RESERVED_SPACE],
+        }
+    }
+}
+
+/// How the winning ballot of a ballot box was decided
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub enum DecisionMethod {
+    /// The ballot reached min_consensus_threshold_bps of operator votes.
+    Consensus,
+    /// Voting expired without consensus and the tie breaker selected the ballot.
+    TieBreaker,
+    /// Voting expired without consensus and the ballot won by plurality.
+    Plurality,
+}
@@ -1,9 +1,19 @@
+pub mod admin_proposal;
 pub mod ballot_box;
 pub mod consensus_result;
+pub mod operator_commit;
+pub mod operator_key_rotation;
+pub mod operator_stats;
 pub mod program_config;
 pub mod proof;
+pub mod proposal;
 
+pub use admin_proposal::*;
 pub use ballot_box::*;
 pub use consensus_result::*;
+pub use operator_commit::*;
+pub use operator_key_rotation::*;
+pub use operator_stats::*;
 pub use program_config::*;
 pub use proof::*;
+pub use proposal::*;
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct OperatorCommit {
+    /// BallotBox the commitment is for
+    pub ballot_box: Pubkey,
+    /// Operator that committed
+    pub operator: Pubkey,
+    /// Wallet that paid rent for the commitment. Receives the lamports when revealed or
+    /// closed.
+    pub payer: Pubkey,
+    /// SHA256 hash of the borsh serialized Ballot followed by a salt, see `Ballot::commitment`.
+    pub commitment: [u8; 32],
+    /// Slot when the commitment was made
+    pub slot_committed: u64,
+}
+
+impl OperatorCommit {
+    pub fn pda(ballot_box: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"OperatorCommit", ballot_box.as_ref(), operator.as_ref()],
+            &crate::ID,
+        )
+    }
+}
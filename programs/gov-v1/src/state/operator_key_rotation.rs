@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Max. number of open ballot boxes when a rotation is accepted. Every open ballot box is
+/// passed to the accepting transaction, which can't hold many more accounts.
+pub const MAX_ROTATION_BALLOT_BOXES: u32 = 20;
+
+/// Rotation of a whitelisted operator key, proposed by the old key and awaiting acceptance
+/// by the new key.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct OperatorKeyRotation {
+    /// Whitelisted operator key being replaced
+    pub old_operator: Pubkey,
+    /// Key replacing the old operator key once accepted
+    pub new_operator: Pubkey,
+    /// Wallet that paid rent for the rotation. Receives the lamports when accepted or
+    /// cancelled.
+    pub payer: Pubkey,
+    /// Timestamp when the rotation was proposed
+    pub proposed_timestamp: i64,
+}
+
+impl OperatorKeyRotation {
+    pub fn pda(old_operator: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"OperatorKeyRotation", old_operator.as_ref()],
+            &crate::ID,
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::BallotBox;
+
+/// Participation and performance of an operator across ballot boxes.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct OperatorStats {
+    /// Operator the stats are for
+    pub operator: Pubkey,
+    /// Finalized ballot boxes the operator was eligible in
+    pub ballots_seen: u64,
+    /// Votes cast or revealed, including recasts after remove_vote
+    pub votes_cast: u64,
+    /// Finalized ballot boxes in which the operator's vote matched the winning ballot
+    pub votes_matching_consensus: u64,
+    /// Sum of slots between ballot box creation and each vote cast
+    pub total_vote_latency_slots: u64,
+}
+
+impl OperatorStats {
+    pub fn pda(operator: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"OperatorStats", operator.as_ref()], &crate::ID)
+    }
+
+    pub fn record_vote(&mut self, slot_voted: u64, slot_created: u64) {
+        self.votes_cast = self.votes_cast.checked_add(1).unwrap();
+        self.total_vote_latency_slots = self
+            .total_vote_latency_slots
+            .checked_add(slot_voted.saturating_sub(slot_created))
+            .unwrap();
+    }
+
+    pub fn record_finalized_ballot(&mut self, ballot_box: &BallotBox) {
+        self.ballots_seen = self.ballots_seen.checked_add(1).unwrap();
+        if ballot_box.voted_for_winning_ballot(&self.operator) {
+            self.votes_matching_consensus = self.votes_matching_consensus.checked_add(1).unwrap();
+        }
+    }
+
+    /// Adds the stats recorded under a previous key of the operator.
+    pub fn merge(&mut self, previous: &OperatorStats) {
+        self.ballots_seen = self
+            .ballots_seen
+            .checked_add(previous.ballots_seen)
+            .unwrap();
+        self.votes_cast = self.votes_cast.checked_add(previous.votes_cast).unwrap();
+        self.votes_matching_consensus = self
+            .votes_matching_consensus
+            .checked_add(previous.votes_matching_consensus)
+            .unwrap();
+        self.total_vote_latency_slots = self
+            .total_vote_latency_slots
+            .checked_add(previous.total_vote_latency_slots)
+            .unwrap();
+    }
+
+    /// Average slots between ballot box creation and the operator's votes. 0 if no votes.
+    pub fn average_vote_latency_slots(&self) -> u64 {
+        self.total_vote_latency_slots
+            .checked_div(self.votes_cast)
+            .unwrap_or(0)
+    }
+}
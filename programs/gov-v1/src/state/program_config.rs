@@ -3,7 +3,12 @@ use std::collections::HashSet;
 
 use anchor_lang::prelude::*;
 
-const MAX_OPERATOR_WHITELIST: usize = 64;
+pub const MAX_OPERATOR_WHITELIST: usize = 256;
+pub const MAX_ADMINS: usize = 10;
+/// Weight assigned to operators that are added without an explicit weight.
+pub const DEFAULT_OPERATOR_WEIGHT: u16 = 1;
+/// Bytes reserved at the end of ProgramConfig for fields added in later versions.
+const RESERVED_SPACE: usize = 236;
 
 #[derive(InitSpace, Debug)]
 #[account]
@@ -15,6 +20,9 @@ pub struct ProgramConfig {
     /// Operators whitelisted to participate in voting.
     #[max_len(MAX_OPERATOR_WHITELIST)]
     pub whitelisted_operators: Vec<Pubkey>,
+    /// Voting weight of each whitelisted operator, in the same order as whitelisted_operators.
+    #[max_len(MAX_OPERATOR_WHITELIST)]
+    pub operator_weights: Vec<u16>,
     /// Min. percentage of votes required to finalize a ballot. Used during BallotBox creation.
     pub min_consensus_threshold_bps: u16,
     /// Admin allowed to decide the winning ballot if vote expires before consensus.
@@ -23,9 +31,67 @@ pub struct ProgramConfig {
     pub next_ballot_id: u64,
     /// Duration for which ballot box will be opened for voting.
     pub vote_duration: i64,
+    /// ID for next Proposal
+    pub next_proposal_id: u64,
+    /// Duration after finalization before a BallotBox can be closed.
+    pub close_grace_period: i64,
+    /// Admins allowed to create and approve AdminProposals.
+    #[max_len(MAX_ADMINS)]
+    pub admins: Vec<Pubkey>,
+    /// Min. number of admin approvals required to execute an AdminProposal. 0 if multisig
+    /// is disabled, in which case the authority and tie breaker admin act alone.
+    pub admin_threshold: u8,
+    /// ID for next AdminProposal
+    pub next_admin_proposal_id: u64,
+    /// Delay before queued config and whitelist changes can be applied. 0 applies changes
+    /// immediately.
+    pub config_change_delay: i64,
+    /// Queued config change, applied by `apply_pending_config` after its effective_at.
+    pub pending_config: Option<PendingConfig>,
+    /// Queued whitelist change, applied by `apply_pending_config` after its effective_at.
+    pub pending_whitelist: Option<PendingWhitelist>,
+    /// Slot offset within each epoch of the snapshot targeted by scheduled ballot boxes,
+    /// which anyone can create once the slot has passed. None disables scheduled ballot boxes.
+    pub scheduled_slot_offset: Option<u64>,
+    /// Key allowed to pause and unpause the program alongside the authority.
+    pub guardian: Pubkey,
+    /// Pauses ballot box creation, voting and ballot finalization.
+    pub balloting_paused: bool,
+    /// Pauses creation, sealing and verification of merkle proofs.
+    pub verification_paused: bool,
+    /// Max. total duration a ballot box can be open for, including vote extensions.
+    pub max_vote_duration: i64,
+    /// Duration of the commit window of new ballot boxes, followed by a reveal window of
+    /// vote_duration. 0 disables commit-reveal.
+    pub commit_duration: i64,
+    /// Min. number of operators that must vote before a ballot can reach consensus. Used
+    /// during BallotBox creation.
+    pub min_votes: u16,
+    /// How ballot boxes that expire without consensus are resolved.
+    pub expiry_resolution: ExpiryResolution,
+    /// Min. percentage of votes the highest ballot needs to win under
+    /// ExpiryResolution::Plurality.
+    pub plurality_floor_bps: u16,
+    /// Layout version. 0 for configs created before versioning.
+    pub version: u8,
+    /// Incremented whenever the admin set changes. AdminProposals created under a previous
+    /// admin set can no longer be approved or executed.
+    pub admin_set_id: u64,
+    /// Min. epoch of the next scheduled ballot box. Each epoch's scheduled ballot box can be
+    /// created once, even after it is closed.
+    pub next_scheduled_epoch: u64,
+    /// Number of initialized ballot boxes that are not finalized, cancelled or failed.
+    /// Accepting an operator key rotation requires all of them.
+    pub open_ballot_boxes: u32,
+    _reserved: [u8; RESERVED_SPACE],
 }
 
 impl ProgramConfig {
+    /// Account size including the discriminator. Larger than a single instruction can
+    /// allocate, so the account is grown by `realloc_program_config` after initialization.
+    pub const SIZE: usize = 8 + ProgramConfig::INIT_SPACE;
+    pub const VERSION: u8 = 1;
+
     pub fn pda() -> (Pubkey, u8) {
         Pubkey::find_program_address(&[b"ProgramConfig"], &crate::ID)
     }
@@ -33,12 +99,18 @@ impl ProgramConfig {
     pub fn remove_operators(&mut self, operators_to_remove: Option<Vec<Pubkey>>) {
         if let Some(operators) = operators_to_remove {
             let remove_set: HashSet<Pubkey> = operators.into_iter().collect();
-            self.whitelisted_operators
-                .retain(|op| !remove_set.contains(op));
+            let (operators, weights): (Vec<Pubkey>, Vec<u16>) = self
+                .whitelisted_operators
+                .iter()
+                .zip(self.operator_weights.iter())
+                .filter(|(op, _)| !remove_set.contains(op))
+                .unzip();
+            self.whitelisted_operators = operators;
+            self.operator_weights = weights;
         }
     }
 
-    // Add operators to the whitelist. Duplicate operators are ignored.
+    // Add operators to the whitelist with the default weight. Duplicate operators are ignored.
     pub fn add_operators(&mut self, operators_to_add: Option<Vec<Pubkey>>) -> Result<()> {
         if let Some(new_operators) = operators_to_add {
             let mut existing_set: HashSet<Pubkey> =
@@ -46,6 +118,7 @@ impl ProgramConfig {
             for op in new_operators.into_iter() {
                 if existing_set.insert(op) {
                     self.whitelisted_operators.push(op);
+                    self.operator_weights.push(DEFAULT_OPERATOR_WEIGHT);
                 }
             }
             require!(
@@ -56,6 +129,258 @@ impl ProgramConfig {
         Ok(())
     }
 
+    // Set weights for operators that are already whitelisted.
+    pub fn set_operator_weights(
+        &mut self,
+        operator_weights: Option<Vec<OperatorWeight>>,
+    ) -> Result<()> {
+        if let Some(operator_weights) = operator_weights {
+            for OperatorWeight { operator, weight } in operator_weights.into_iter() {
+                require_gt!(weight, 0, ErrorCode::InvalidOperatorWeight);
+                let idx = self
+                    .whitelisted_operators
+                    .iter()
+                    .position(|op| *op == operator)
+                    .ok_or(ErrorCode::OperatorNotWhitelisted)?;
+                self.operator_weights[idx] = weight;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies an update to the operator whitelist. Removals are applied before additions,
+    /// and weights last so newly added operators can be weighted in the same update.
+    pub fn update_operator_whitelist(&mut self, update: OperatorWhitelistUpdate) -> Result<()> {
+        // Validate no overlap between add and remove lists.
+        if let (Some(add), Some(remove)) = (&update.operators_to_add, &update.operators_to_remove)
+        {
+            let add_set: HashSet<Pubkey> = add.iter().cloned().collect();
+            let remove_set: HashSet<Pubkey> = remove.iter().cloned().collect();
+            let overlap = add_set.intersection(&remove_set).next().is_some();
+            require!(!overlap, ErrorCode::OverlappingWhitelistEntries);
+        }
+
+        self.remove_operators(update.operators_to_remove);
+        self.add_operators(update.operators_to_add)?;
+        self.set_operator_weights(update.operator_weights)
+    }
+
+    pub fn update_config(&mut self, update: ProgramConfigUpdate) -> Result<()> {
+        update.validate()?;
+        if let Some(proposed_authority) = update.proposed_authority {
+            self.proposed_authority = Some(proposed_authority);
+        }
+        if let Some(min_consensus_threshold_bps) = update.min_consensus_threshold_bps {
+            self.min_consensus_threshold_bps = min_consensus_threshold_bps;
+        }
+        if let Some(tie_breaker_admin) = update.tie_breaker_admin {
+            self.tie_breaker_admin = tie_breaker_admin;
+        }
+        if let Some(vote_duration) = update.vote_duration {
+            self.vote_duration = vote_duration;
+        }
+        if let Some(close_grace_period) = update.close_grace_period {
+            self.close_grace_period = close_grace_period;
+        }
+        if let Some(config_change_delay) = update.config_change_delay {
+            self.config_change_delay = config_change_delay;
+        }
+        if let Some(scheduled_slot_offset) = update.scheduled_slot_offset {
+            self.scheduled_slot_offset = scheduled_slot_offset;
+        }
+        if let Some(guardian) = update.guardian {
+            self.guardian = guardian;
+        }
+        if let Some(max_vote_duration) = update.max_vote_duration {
+            self.max_vote_duration = max_vote_duration;
+        }
+        if let Some(commit_duration) = update.commit_duration {
+            self.commit_duration = commit_duration;
+        }
+        if let Some(min_votes) = update.min_votes {
+            self.min_votes = min_votes;
+        }
+        if let Some(expiry_resolution) = update.expiry_resolution {
+            self.expiry_resolution = expiry_resolution;
+        }
+        if let Some(plurality_floor_bps) = update.plurality_floor_bps {
+            self.plurality_floor_bps = plurality_floor_bps;
+        }
+        Ok(())
+    }
+
+    /// Queues a config update to be applied after config_change_delay. Fields set in the
+    /// update override those of an already pending update, and the delay restarts.
+    pub fn queue_config_update(
+        &mut self,
+        update: ProgramConfigUpdate,
+        now: i64,
+    ) -> Result<&PendingConfig> {
+        update.validate()?;
+        let update = match self.pending_config.take() {
+            Some(pending) => pending.update.merge(update),
+            None => update,
+        };
+        Ok(self.pending_config.insert(PendingConfig {
+            update,
+            effective_at: now.checked_add(self.config_change_delay).unwrap(),
+        }))
+    }
+
+    /// Queues a whitelist update to be applied after config_change_delay. The update is
+    /// applied on top of an already pending whitelist, and the delay restarts.
+    pub fn queue_operator_whitelist_update(
+        &mut self,
+        update: OperatorWhitelistUpdate,
+        now: i64,
+    ) -> Result<&PendingWhitelist> {
+        let mut next = self.clone();
+        if let Some(pending) = self.pending_whitelist.take() {
+            next.whitelisted_operators = pending.whitelisted_operators;
+            next.operator_weights = pending.operator_weights;
+        }
+        next.update_operator_whitelist(update)?;
+
+        Ok(self.pending_whitelist.insert(PendingWhitelist {
+            whitelisted_operators: next.whitelisted_operators,
+            operator_weights: next.operator_weights,
+            effective_at: now.checked_add(self.config_change_delay).unwrap(),
+        }))
+    }
+
+    /// Applies the pending config update if it is effective. Returns true if applied.
+    pub fn apply_pending_config_update(&mut self, now: i64) -> Result<bool> {
+        match self.pending_config.take() {
+            Some(pending) if pending.effective_at <= now => {
+                self.update_config(pending.update)?;
+                Ok(true)
+            }
+            pending => {
+                self.pending_config = pending;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Applies the pending whitelist update if it is effective. Returns true if applied.
+    pub fn apply_pending_whitelist_update(&mut self, now: i64) -> bool {
+        match self.pending_whitelist.take() {
+            Some(pending) if pending.effective_at <= now => {
+                self.whitelisted_operators = pending.whitelisted_operators;
+                self.operator_weights = pending.operator_weights;
+                true
+            }
+            pending => {
+                self.pending_whitelist = pending;
+                false
+            }
+        }
+    }
+
+    pub fn has_pending_changes(&self) -> bool {
+        self.pending_config.is_some() || self.pending_whitelist.is_some()
+    }
+
+    /// Counts a ballot box that was initialized or migrated while open.
+    pub fn record_ballot_box_opened(&mut self) {
+        self.open_ballot_boxes = self.open_ballot_boxes.checked_add(1).unwrap();
+    }
+
+    /// Counts an open ballot box that was finalized, cancelled or failed.
+    pub fn record_ballot_box_ended(&mut self) {
+        self.open_ballot_boxes = self.open_ballot_boxes.checked_sub(1).unwrap();
+    }
+
+    /// Sets the admin set. An empty set with a threshold of 0 disables multisig.
+    pub fn set_admins(&mut self, admins: Vec<Pubkey>, admin_threshold: u8) -> Result<()> {
+        require!(admins.len() <= MAX_ADMINS, ErrorCode::VecFull);
+        let unique: HashSet<&Pubkey> = admins.iter().collect();
+        require!(unique.len() == admins.len(), ErrorCode::DuplicateAdmin);
+        if admins.is_empty() {
+            require_eq!(admin_threshold, 0, ErrorCode::InvalidAdminThreshold);
+        } else {
+            require!(
+                admin_threshold > 0 && usize::from(admin_threshold) <= admins.len(),
+                ErrorCode::InvalidAdminThreshold
+            );
+        }
+        self.admins = admins;
+        self.admin_threshold = admin_threshold;
+        self.admin_set_id = self.admin_set_id.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    pub fn is_multisig_enabled(&self) -> bool {
+        self.admin_threshold != 0
+    }
+
+    /// Fails if config changes must go through an AdminProposal.
+    pub fn require_multisig_disabled(&self) -> Result<()> {
+        require!(!self.is_multisig_enabled(), ErrorCode::MultisigEnabled);
+        Ok(())
+    }
+
+    /// Fails if the signer is neither the authority nor the guardian.
+    pub fn require_authority_or_guardian(&self, signer: &Pubkey) -> Result<()> {
+        require!(
+            *signer == self.authority || *signer == self.guardian,
+            ErrorCode::Unauthorized
+        );
+        Ok(())
+    }
+
+    /// Fails if expired ballot boxes are not resolved by the given policy.
+    pub fn require_expiry_resolution(&self, expiry_resolution: ExpiryResolution) -> Result<()> {
+        require!(
+            self.expiry_resolution == expiry_resolution,
+            ErrorCode::InvalidExpiryResolution
+        );
+        Ok(())
+    }
+
+    /// Returns the index of the admin in the admin set.
+    pub fn admin_index(&self, admin: &Pubkey) -> Result<usize> {
+        self.admins
+            .iter()
+            .position(|a| a == admin)
+            .ok_or(error!(ErrorCode::NotAdmin))
+    }
+
+    /// Replaces an operator key in the whitelist and in the pending whitelist, keeping its
+    /// position and weight.
+    pub fn rotate_operator_key(
+        &mut self,
+        old_operator: &Pubkey,
+        new_operator: Pubkey,
+    ) -> Result<()> {
+        require!(
+            !self.whitelisted_operators.contains(&new_operator),
+            ErrorCode::OperatorAlreadyWhitelisted
+        );
+        let idx = self
+            .whitelisted_operators
+            .iter()
+            .position(|op| op == old_operator)
+            .ok_or(ErrorCode::OperatorNotWhitelisted)?;
+        self.whitelisted_operators[idx] = new_operator;
+
+        // Otherwise applying a queued whitelist would restore the old key.
+        if let Some(pending) = self.pending_whitelist.as_mut() {
+            require!(
+                !pending.whitelisted_operators.contains(&new_operator),
+                ErrorCode::OperatorAlreadyWhitelisted
+            );
+            if let Some(op) = pending
+                .whitelisted_operators
+                .iter_mut()
+                .find(|op| *op == old_operator)
+            {
+                *op = new_operator;
+            }
+        }
+        Ok(())
+    }
+
     pub fn contains_operator(&self, operator: &Pubkey) -> Result<()> {
         require!(
             self.whitelisted_operators.contains(operator),
@@ -64,3 +389,255 @@ impl ProgramConfig {
         Ok(())
     }
 }
+
+/// Max. whitelist size of LegacyProgramConfig.
+const LEGACY_MAX_OPERATOR_WHITELIST: usize = 64;
+
+/// Layout of ProgramConfig before operator weights were added.
+#[derive(Debug, AnchorDeserialize, InitSpace)]
+pub struct LegacyProgramConfig {
+    pub authority: Pubkey,
+    pub proposed_authority: Option<Pubkey>,
+    #[max_len(LEGACY_MAX_OPERATOR_WHITELIST)]
+    pub whitelisted_operators: Vec<Pubkey>,
+    pub min_consensus_threshold_bps: u16,
+    pub tie_breaker_admin: Pubkey,
+    pub next_ballot_id: u64,
+    pub vote_duration: i64,
+}
+
+impl LegacyProgramConfig {
+    /// Account size including the discriminator.
+    pub const SIZE: usize = 8 + LegacyProgramConfig::INIT_SPACE;
+}
+
+impl From<LegacyProgramConfig> for ProgramConfig {
+    fn from(legacy: LegacyProgramConfig) -> Self {
+        let operator_weights = vec![DEFAULT_OPERATOR_WEIGHT; legacy.whitelisted_operators.len()];
+        Self {
+            authority: legacy.authority,
+            proposed_authority: legacy.proposed_authority,
+            whitelisted_operators: legacy.whitelisted_operators,
+            operator_weights,
+            min_consensus_threshold_bps: legacy.min_consensus_threshold_bps,
+            tie_breaker_admin: legacy.tie_breaker_admin,
+            next_ballot_id: legacy.next_ballot_id,
+            vote_duration: legacy.vote_duration,
+            next_proposal_id: 0,
+            close_grace_period: 0,
+            admins: vec![],
+            admin_threshold: 0,
+            next_admin_proposal_id: 0,
+            config_change_delay: 0,
+            pending_config: None,
+            pending_whitelist: None,
+            scheduled_slot_offset: None,
+            guardian: Pubkey::default(),
+            balloting_paused: false,
+            verification_paused: false,
+            // Legacy ballot boxes couldn't be extended.
+            max_vote_duration: legacy.vote_duration,
+            commit_duration: 0,
+            min_votes: 0,
+            expiry_resolution: ExpiryResolution::TieBreakerAdmin,
+            plurality_floor_bps: 0,
+            version: 0,
+            admin_set_id: 0,
+            next_scheduled_epoch: 0,
+            // Open legacy ballot boxes are counted by migrate_ballot_box.
+            open_ballot_boxes: 0,
+            _reserved: [0; RESERVED_SPACE],
+        }
+    }
+}
+
+/// Changes to the operator whitelist. Fields that are None are left unchanged.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default)]
+pub struct OperatorWhitelistUpdate {
+    /// Operators to add with the default weight
+    pub operators_to_add: Option<Vec<Pubkey>>,
+    /// Operators to remove
+    pub operators_to_remove: Option<Vec<Pubkey>>,
+    /// Weights of whitelisted operators
+    pub operator_weights: Option<Vec<OperatorWeight>>,
+}
+
+/// Changes to ProgramConfig parameters. Fields that are None are left unchanged.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default, InitSpace)]
+pub struct ProgramConfigUpdate {
+    /// Authority to be set to upon finalization of proposal.
+    pub proposed_authority: Option<Pubkey>,
+    /// Min. percentage of votes required to finalize a ballot.
+    pub min_consensus_threshold_bps: Option<u16>,
+    /// Admin allowed to decide the winning ballot if vote expires before consensus.
+    pub tie_breaker_admin: Option<Pubkey>,
+    /// Duration for which ballot box will be opened for voting.
+    pub vote_duration: Option<i64>,
+    /// Duration after finalization before a BallotBox can be closed.
+    pub close_grace_period: Option<i64>,
+    /// Delay before queued config and whitelist changes can be applied.
+    pub config_change_delay: Option<i64>,
+    /// Slot offset for scheduled ballot boxes. Some(None) disables scheduled ballot boxes.
+    pub scheduled_slot_offset: Option<Option<u64>>,
+    /// Key allowed to pause and unpause the program alongside the authority.
+    pub guardian: Option<Pubkey>,
+    /// Max. total duration a ballot box can be open for, including vote extensions.
+    pub max_vote_duration: Option<i64>,
+    /// Duration of the commit window of new ballot boxes. 0 disables commit-reveal.
+    pub commit_duration: Option<i64>,
+    /// Min. number of operators that must vote before a ballot can reach consensus.
+    pub min_votes: Option<u16>,
+    /// How ballot boxes that expire without consensus are resolved.
+    pub expiry_resolution: Option<ExpiryResolution>,
+    /// Min. percentage of votes the highest ballot needs to win under Plurality.
+    pub plurality_floor_bps: Option<u16>,
+}
+
+impl ProgramConfigUpdate {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(min_consensus_threshold_bps) = self.min_consensus_threshold_bps {
+            require_gt!(min_consensus_threshold_bps, 0);
+            require_gte!(10000, min_consensus_threshold_bps);
+        }
+        if let Some(vote_duration) = self.vote_duration {
+            require_gt!(vote_duration, 0);
+        }
+        if let Some(close_grace_period) = self.close_grace_period {
+            require_gte!(close_grace_period, 0);
+        }
+        if let Some(config_change_delay) = self.config_change_delay {
+            require_gte!(config_change_delay, 0);
+        }
+        if let Some(max_vote_duration) = self.max_vote_duration {
+            require_gte!(max_vote_duration, 0);
+        }
+        if let Some(commit_duration) = self.commit_duration {
+            require_gte!(commit_duration, 0);
+        }
+        if let Some(min_votes) = self.min_votes {
+            require_gte!(MAX_OPERATOR_WHITELIST, usize::from(min_votes));
+        }
+        if let Some(plurality_floor_bps) = self.plurality_floor_bps {
+            require_gte!(10000, plurality_floor_bps);
+        }
+        Ok(())
+    }
+
+    /// Returns this update with the fields set in `newer` taking precedence.
+    pub fn merge(self, newer: ProgramConfigUpdate) -> Self {
+        Self {
+            proposed_authority: newer.proposed_authority.or(self.proposed_authority),
+            min_consensus_threshold_bps: newer
+                .min_consensus_threshold_bps
+                .or(self.min_consensus_threshold_bps),
+            tie_breaker_admin: newer.tie_breaker_admin.or(self.tie_breaker_admin),
+            vote_duration: newer.vote_duration.or(self.vote_duration),
+            close_grace_period: newer.close_grace_period.or(self.close_grace_period),
+            config_change_delay: newer.config_change_delay.or(self.config_change_delay),
+            scheduled_slot_offset: newer.scheduled_slot_offset.or(self.scheduled_slot_offset),
+            guardian: newer.guardian.or(self.guardian),
+            max_vote_duration: newer.max_vote_duration.or(self.max_vote_duration),
+            commit_duration: newer.commit_duration.or(self.commit_duration),
+            min_votes: newer.min_votes.or(self.min_votes),
+            expiry_resolution: newer.expiry_resolution.or(self.expiry_resolution),
+            plurality_floor_bps: newer.plurality_floor_bps.or(self.plurality_floor_bps),
+        }
+    }
+}
+
+/// Resolution of a ballot box whose voting expired without consensus
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Default)]
+pub enum ExpiryResolution {
+    /// The tie breaker admin selects the winning ballot with `set_tie_breaker`.
+    #[default]
+    TieBreakerAdmin,
+    /// `finalize_expired_ballot` selects the ballot with the highest tally if it is unique
+    /// and reaches plurality_floor_bps. Otherwise the ballot box fails.
+    Plurality,
+    /// `finalize_expired_ballot` marks the ballot box as failed. A new ballot box must be
+    /// created for the snapshot.
+    NoResult,
+}
+
+/// Config update queued until effective_at.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct PendingConfig {
+    /// Queued update
+    pub update: ProgramConfigUpdate,
+    /// Timestamp after which the update can be applied.
+    pub effective_at: i64,
+}
+
+/// Operator whitelist queued until effective_at.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct PendingWhitelist {
+    /// Operators whitelisted once applied.
+    #[max_len(MAX_OPERATOR_WHITELIST)]
+    pub whitelisted_operators: Vec<Pubkey>,
+    /// Voting weight of each operator, in the same order as whitelisted_operators.
+    #[max_len(MAX_OPERATOR_WHITELIST)]
+    pub operator_weights: Vec<u16>,
+    /// Timestamp after which the whitelist can be applied.
+    pub effective_at: i64,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct OperatorWeight {
+    /// Whitelisted operator
+    pub operator: Pubkey,
+    /// Voting weight of the operator. Must be non-zero.
+    pub weight: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+    use super::*;
+
+    #[test]
+    fn test_migrate_legacy_program_config() {
+        let authority = Pubkey::new_unique();
+        let tie_breaker_admin = Pubkey::new_unique();
+        let operators: Vec<Pubkey> = (0..LEGACY_MAX_OPERATOR_WHITELIST)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        let mut data = ProgramConfig::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(authority.as_ref());
+        data.push(0);
+        data.extend_from_slice(&(operators.len() as u32).to_le_bytes());
+        for operator in operators.iter() {
+            data.extend_from_slice(operator.as_ref());
+        }
+        data.extend_from_slice(&6_667u16.to_le_bytes());
+        data.extend_from_slice(tie_breaker_admin.as_ref());
+        data.extend_from_slice(&12u64.to_le_bytes());
+        data.extend_from_slice(&86_400i64.to_le_bytes());
+        data.resize(LegacyProgramConfig::SIZE, 0);
+        assert_eq!(LegacyProgramConfig::SIZE, 8 + 32 + 33 + (4 + 64 * 32) + 2 + 32 + 8 + 8);
+
+        let legacy = LegacyProgramConfig::deserialize(&mut &data[8..]).unwrap();
+        let program_config: ProgramConfig = legacy.into();
+        assert_eq!(program_config.authority, authority);
+        assert_eq!(program_config.proposed_authority, None);
+        assert_eq!(program_config.whitelisted_operators, operators);
+        assert_eq!(
+            program_config.operator_weights,
+            vec![DEFAULT_OPERATOR_WEIGHT; operators.len()]
+        );
+        assert_eq!(program_config.min_consensus_threshold_bps, 6_667);
+        assert_eq!(program_config.tie_breaker_admin, tie_breaker_admin);
+        assert_eq!(program_config.next_ballot_id, 12);
+        assert_eq!(program_config.vote_duration, 86_400);
+        assert_eq!(program_config.max_vote_duration, 86_400);
+        assert_eq!(program_config.version, 0);
+
+        // The converted config fits in the account after its first grow.
+        let mut account = vec![0; LegacyProgramConfig::SIZE + MAX_PERMITTED_DATA_INCREASE];
+        program_config.try_serialize(&mut &mut account[..]).unwrap();
+        let program_config = ProgramConfig::try_deserialize(&mut &account[..]).unwrap();
+        assert_eq!(program_config.whitelisted_operators, operators);
+        assert_eq!(program_config.version, 0);
+    }
+}
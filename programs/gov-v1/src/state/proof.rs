@@ -1,7 +1,9 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::hash::{hashv, Hash},
-};
+use crate::error::ErrorCode;
+
+use anchor_lang::{prelude::*, solana_program::hash::Hash};
+
+/// Bytes reserved at the end of MetaMerkleProof for fields added in later versions.
+const RESERVED_SPACE: usize = 32;
 
 #[account]
 #[derive(Debug)]
@@ -17,9 +19,19 @@ pub struct MetaMerkleProof {
     /// Timestamp after which MetaMerkleProof can be closed permissionlessly.
     /// This is selected by the payer but our recommendation is to set to vote expiry time.
     pub close_timestamp: i64,
+    /// Declared length of the meta merkle proof, which the account is allocated for.
+    pub proof_len: u8,
+    /// True once the complete proof has been verified. Unsealed proofs cannot be used
+    /// for verification.
+    pub sealed: bool,
+    /// Layout version. 0 for proofs created before versioning.
+    pub version: u8,
+    _reserved: [u8; RESERVED_SPACE],
 }
 
 impl MetaMerkleProof {
+    pub const VERSION: u8 = 1;
+
     pub fn pda(consensus_result: &Pubkey, vote_account: &Pubkey) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[
@@ -31,8 +43,27 @@ impl MetaMerkleProof {
         )
     }
 
-    pub fn init_space(meta_merkle_proof: Vec<[u8; 32]>) -> usize {
-        72 + MetaMerkleLeaf::INIT_SPACE + 4 + 32 * meta_merkle_proof.len()
+    pub fn init_space(proof_len: usize) -> usize {
+        75 + RESERVED_SPACE + MetaMerkleLeaf::INIT_SPACE + 4 + 32 * proof_len
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.meta_merkle_proof.len() == usize::from(self.proof_len)
+    }
+
+    /// Upgrades a proof read from an older layout to the current version.
+    pub fn migrate(&mut self) -> Result<()> {
+        // Proofs created before chunked initialization were complete and verified at init.
+        if self.proof_len == 0 && !self.meta_merkle_proof.is_empty() {
+            self.proof_len = self
+                .meta_merkle_proof
+                .len()
+                .try_into()
+                .map_err(|_| ErrorCode::VecFull)?;
+            self.sealed = true;
+        }
+        self.version = Self::VERSION;
+        Ok(())
     }
 }
 
@@ -51,12 +82,12 @@ pub struct MetaMerkleLeaf {
 
 impl MetaMerkleLeaf {
     pub fn hash(&self) -> Hash {
-        hashv(&[
+        Hash::new_from_array(gov_merkle::hash_meta_merkle_leaf(
             &self.voting_wallet.to_bytes(),
             &self.vote_account.to_bytes(),
             &self.stake_merkle_root,
-            &self.active_stake.to_le_bytes(),
-        ])
+            self.active_stake,
+        ))
     }
 }
 
@@ -72,10 +103,40 @@ pub struct StakeMerkleLeaf {
 
 impl StakeMerkleLeaf {
     pub fn hash(&self) -> Hash {
-        hashv(&[
+        Hash::new_from_array(gov_merkle::hash_stake_merkle_leaf(
             &self.voting_wallet.to_bytes(),
             &self.stake_account.to_bytes(),
-            &self.active_stake.to_le_bytes(),
-        ])
+            self.active_stake,
+        ))
     }
 }
+
+/// Return data of `verify_merkle_proof`, describing the verified leaf.
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize, PartialEq)]
+pub struct VerifiedStake {
+    /// Wallet designated for governance voting for the verified account.
+    pub voting_wallet: Pubkey,
+    /// Vote account of the verified MetaMerkleLeaf.
+    pub vote_account: Pubkey,
+    /// Stake account of the verified StakeMerkleLeaf. None if only the vote account was verified.
+    pub stake_account: Option<Pubkey>,
+    /// Active stake of the verified account.
+    pub active_stake: u64,
+    /// Ballot ID of the ConsensusResult the leaf was verified against.
+    pub ballot_id: u64,
+}
+
+/// Return data of `verify_stake_merkle_proofs_batch`, describing the verified leaves.
+#[derive(Clone, Debug, AnchorDeserialize, AnchorSerialize, PartialEq)]
+pub struct VerifiedStakeBatch {
+    /// Wallet designated for governance voting for all verified stake accounts.
+    pub voting_wallet: Pubkey,
+    /// Vote account of the verified MetaMerkleLeaf.
+    pub vote_account: Pubkey,
+    /// Number of verified stake accounts.
+    pub stake_account_count: u32,
+    /// Sum of active stake of the verified stake accounts.
+    pub active_stake: u64,
+    /// Ballot ID of the ConsensusResult the leaves were verified against.
+    pub ballot_id: u64,
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Proposal {
+    /// ID
+    pub proposal_id: u64,
+    /// Bump seed for the PDA
+    pub bump: u8,
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+    /// ConsensusResult whose snapshot determines voting weights.
+    pub consensus_result: Pubkey,
+    /// SHA256 hash of the off-chain proposal description.
+    pub description_hash: [u8; 32],
+    /// Timestamp when voting starts
+    pub vote_start_timestamp: i64,
+    /// Timestamp when voting ends
+    pub vote_expiry_timestamp: i64,
+    /// Min. total stake (for, against and abstain) that must vote for the proposal to pass.
+    pub quorum_stake: u64,
+    /// Min. percentage of for stake out of for and against stake required to pass.
+    pub pass_threshold_bps: u16,
+    /// Stake voting for the proposal
+    pub for_stake: u64,
+    /// Stake voting against the proposal
+    pub against_stake: u64,
+    /// Stake abstaining from the proposal
+    pub abstain_stake: u64,
+    /// Current status of the proposal
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+    pub fn pda(proposal_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"Proposal", &proposal_id.to_le_bytes()], &crate::ID)
+    }
+
+    pub fn has_vote_expired(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.vote_expiry_timestamp
+    }
+
+    pub fn total_stake(&self) -> u64 {
+        self.for_stake
+            .checked_add(self.against_stake)
+            .and_then(|stake| stake.checked_add(self.abstain_stake))
+            .unwrap()
+    }
+
+    fn tally_mut(&mut self, choice: VoteChoice) -> &mut u64 {
+        match choice {
+            VoteChoice::For => &mut self.for_stake,
+            VoteChoice::Against => &mut self.against_stake,
+            VoteChoice::Abstain => &mut self.abstain_stake,
+        }
+    }
+
+    /// Adds stake to the tally of the given choice.
+    pub fn add_stake(&mut self, choice: VoteChoice, stake: u64) {
+        let tally = self.tally_mut(choice);
+        *tally = tally.checked_add(stake).unwrap();
+    }
+
+    /// Removes stake from the tally of the given choice.
+    pub fn sub_stake(&mut self, choice: VoteChoice, stake: u64) {
+        let tally = self.tally_mut(choice);
+        *tally = tally.checked_sub(stake).unwrap();
+    }
+
+    /// Returns true if quorum is met and for stake meets the pass threshold.
+    pub fn has_passed(&self) -> bool {
+        let decisive_stake = self.for_stake.checked_add(self.against_stake).unwrap();
+        if self.total_stake() < self.quorum_stake || decisive_stake == 0 {
+            return false;
+        }
+        let for_bps = u128::from(self.for_stake) * 10000 / u128::from(decisive_stake);
+        for_bps >= self.pass_threshold_bps.into()
+    }
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct VoteRecord {
+    /// Proposal the vote was cast for
+    pub proposal: Pubkey,
+    /// Voting wallet that cast the vote
+    pub voting_wallet: Pubkey,
+    /// Vote account whose MetaMerkleLeaf was proven
+    pub vote_account: Pubkey,
+    /// Stake account whose StakeMerkleLeaf was proven. None if voted with the vote account.
+    pub stake_account: Option<Pubkey>,
+    /// Stake counted towards the choice. For a vote account, this excludes stake
+    /// of delegators that have voted with their stake accounts.
+    pub stake: u64,
+    /// Stake of delegators that overrode the vote account's vote. Always 0 for stake accounts.
+    pub overridden_stake: u64,
+    /// Vote choice. None if the vote account has not voted and the record was created
+    /// by a delegator override.
+    pub choice: Option<VoteChoice>,
+}
+
+impl VoteRecord {
+    /// `voted_account` is the stake account for delegator votes, otherwise the vote account.
+    pub fn pda(proposal: &Pubkey, voted_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"VoteRecord", proposal.as_ref(), voted_account.as_ref()],
+            &crate::ID,
+        )
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Default)]
+pub enum ProposalStatus {
+    #[default]
+    Voting,
+    Passed,
+    Rejected,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
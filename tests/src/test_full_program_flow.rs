@@ -11,13 +11,31 @@ use anchor_client::{
 };
 use cli::{utils::*, MetaMerkleSnapshot};
 use gov_v1::{
-    Ballot, BallotBox, BallotTally, ConsensusResult, MetaMerkleProof, OperatorVote, ProgramConfig,
+    AdminAction, AdminProposal, Ballot, BallotBox, BallotTally, ConsensusResult, DecisionMethod, ExpiryResolution, MetaMerkleLeaf, MetaMerkleProof, OperatorCommit, OperatorKeyRotation, OperatorStats, OperatorVote, OperatorWeight,
+    ProgramConfig, ProgramConfigUpdate, Proposal, ProposalStatus, StakeMerkleLeaf, VerifiedStake, VerifiedStakeBatch,
+    VoteChoice, VoteRecord, DEFAULT_OPERATOR_WEIGHT,
 };
 
-use crate::utils::{assert::assert_client_err, data_types::ProgramTestContext, fetch_utils::*};
+use crate::utils::{
+    assert::assert_client_err, data_types::ProgramTestContext, fetch_utils::*,
+    merkle_utils::build_meta_merkle_snapshot,
+};
 
 const VOTE_DURATION: i64 = 10;
 const MIN_CONSENSUS_BPS: u16 = 6666;
+const PROPOSAL_DURATION: i64 = 10;
+const QUORUM_STAKE: u64 = 250;
+const PASS_THRESHOLD_BPS: u16 = 5000;
+const CLOSE_GRACE_PERIOD: i64 = 5;
+const CONFIG_CHANGE_DELAY: i64 = 3;
+
+/// Returns the operators that voted with their votes, in the order of eligible_operators.
+fn operator_votes(ballot_box: &BallotBox) -> Vec<(Pubkey, OperatorVote)> {
+    ballot_box
+        .operator_votes()
+        .map(|(operator, vote)| (*operator, *vote))
+        .collect()
+}
 
 fn test_program_config(
     program: &Program<&Keypair>,
@@ -29,7 +47,38 @@ fn test_program_config(
         payer: &context.payer,
         authority: &context.payer,
     };
-    send_init_program_config(tx_sender)?;
+    // The consensus threshold must be set on initialization.
+    let tx = send_init_program_config(
+        tx_sender,
+        0,
+        0,
+        Pubkey::default(),
+        VOTE_DURATION,
+        0,
+        0,
+        None,
+        Pubkey::default(),
+        0,
+        0,
+        ExpiryResolution::TieBreakerAdmin,
+        0,
+    );
+    assert_client_err(tx, "RequireGtViolated");
+    send_init_program_config(
+        tx_sender,
+        MIN_CONSENSUS_BPS,
+        0,
+        Pubkey::default(),
+        VOTE_DURATION,
+        0,
+        0,
+        None,
+        Pubkey::default(),
+        0,
+        0,
+        ExpiryResolution::TieBreakerAdmin,
+        0,
+    )?;
 
     // Verify values in ProgramConfig
     let program_config: ProgramConfig = program.account(context.program_config_pda)?;
@@ -37,24 +86,65 @@ fn test_program_config(
     assert_eq!(program_config.proposed_authority, None);
     assert_eq!(program_config.tie_breaker_admin, Pubkey::default());
     assert_eq!(program_config.whitelisted_operators.len(), 0);
-    assert_eq!(program_config.min_consensus_threshold_bps, 0);
+    assert_eq!(program_config.min_consensus_threshold_bps, MIN_CONSENSUS_BPS);
+    assert_eq!(program_config.min_votes, 0);
     assert_eq!(program_config.next_ballot_id, 0);
-    assert_eq!(program_config.vote_duration, 0);
+    assert_eq!(program_config.vote_duration, VOTE_DURATION);
+
+    // ProgramConfig is grown to full size in the same transaction.
+    let program_config_account = program.rpc().get_account(&context.program_config_pda)?;
+    assert_eq!(program_config_account.data.len(), ProgramConfig::SIZE);
+    let tx = send_realloc_program_config(tx_sender);
+    assert_client_err(tx, "Account already at full size");
 
     // Add operators
     let mut operators_to_add: Vec<Pubkey> = context.operators.iter().map(|x| x.pubkey()).collect();
 
-    send_update_operator_whitelist(tx_sender, Some(operators_to_add.clone()), None)?;
+    send_update_operator_whitelist(tx_sender, Some(operators_to_add.clone()), None, None)?;
 
     // Verify values in ProgramConfig
     let program_config: ProgramConfig = program.account(context.program_config_pda)?;
     assert_eq!(program_config.whitelisted_operators, operators_to_add);
+    assert_eq!(
+        program_config.operator_weights,
+        vec![DEFAULT_OPERATOR_WEIGHT; operators_to_add.len()]
+    );
+
+    // Set weight of an operator.
+    let operator_weight = OperatorWeight {
+        operator: operators_to_add[0],
+        weight: 3,
+    };
+    send_update_operator_whitelist(tx_sender, None, None, Some(vec![operator_weight]))?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.operator_weights[0], 3);
+
+    // Setting a zero weight or a weight for a non-whitelisted operator should fail.
+    let zero_weight = OperatorWeight {
+        operator: operators_to_add[0],
+        weight: 0,
+    };
+    let tx = send_update_operator_whitelist(tx_sender, None, None, Some(vec![zero_weight]));
+    assert_client_err(tx, "Invalid operator weight");
+    let unknown_weight = OperatorWeight {
+        operator: Keypair::new().pubkey(),
+        weight: 2,
+    };
+    let tx = send_update_operator_whitelist(tx_sender, None, None, Some(vec![unknown_weight]));
+    assert_client_err(tx, "Operator not whitelisted");
+
+    // Reset to equal weighting.
+    let default_weight = OperatorWeight {
+        operator: operators_to_add[0],
+        weight: DEFAULT_OPERATOR_WEIGHT,
+    };
+    send_update_operator_whitelist(tx_sender, None, None, Some(vec![default_weight]))?;
 
     // Add a new operator twice.
     let new_operator = Keypair::new();
     operators_to_add.push(new_operator.pubkey());
     operators_to_add.push(new_operator.pubkey());
-    send_update_operator_whitelist(tx_sender, Some(operators_to_add.clone()), None)?;
+    send_update_operator_whitelist(tx_sender, Some(operators_to_add.clone()), None, None)?;
     let program_config: ProgramConfig = program.account(context.program_config_pda)?;
 
     // Verify that the new operator is added only once.
@@ -69,7 +159,7 @@ fn test_program_config(
     // Remove operators
     let operators_to_remove = operators_to_add[8..].to_vec();
 
-    send_update_operator_whitelist(tx_sender, None, Some(operators_to_remove))?;
+    send_update_operator_whitelist(tx_sender, None, Some(operators_to_remove), None)?;
 
     // Verify values in ProgramConfig
     let program_config: ProgramConfig = program.account(context.program_config_pda)?;
@@ -80,7 +170,7 @@ fn test_program_config(
 
     // Overlap between operators to add and to remove should fail.
     let overlap = vec![Keypair::new().pubkey()];
-    let tx = send_update_operator_whitelist(tx_sender, Some(overlap.clone()), Some(overlap));
+    let tx = send_update_operator_whitelist(tx_sender, Some(overlap.clone()), Some(overlap), None);
     assert_client_err(tx, "Overlapping operators");
 
     let new_authority = Keypair::new();
@@ -91,6 +181,15 @@ fn test_program_config(
         Some(MIN_CONSENSUS_BPS),
         Some(program.payer()),
         Some(VOTE_DURATION),
+        Some(CLOSE_GRACE_PERIOD),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )?;
 
     // Verify values in ProgramConfig
@@ -108,6 +207,7 @@ fn test_program_config(
     );
     assert_eq!(program_config.next_ballot_id, 0);
     assert_eq!(program_config.vote_duration, VOTE_DURATION);
+    assert_eq!(program_config.close_grace_period, CLOSE_GRACE_PERIOD);
 
     // Finalize proposed authority
     let tx_sender2 = &TxSender {
@@ -130,6 +230,15 @@ fn test_program_config(
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )?;
     // Finalize proposed authority.
     send_finalize_proposed_authority(tx_sender)?;
@@ -149,6 +258,7 @@ fn test_program_config(
     );
     assert_eq!(program_config.next_ballot_id, 0);
     assert_eq!(program_config.vote_duration, VOTE_DURATION);
+    assert_eq!(program_config.close_grace_period, CLOSE_GRACE_PERIOD);
 
     Ok(())
 }
@@ -168,68 +278,149 @@ fn test_balloting(
         authority: operator1,
     };
 
-    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda)?;
+    // Target slot must be in the current or previous epoch.
+    let epoch_schedule = program.rpc().get_epoch_schedule()?;
+    let target_slot = program.rpc().get_slot()?;
+    let future_slot = target_slot + 2 * epoch_schedule.get_slots_in_epoch(u64::MAX);
+    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda, future_slot);
+    assert_client_err(tx, "Invalid target slot");
+
+    // Target slot must not be later in the current epoch either.
+    let later_slot = (target_slot + 1_000)
+        .min(epoch_schedule.get_last_slot_in_epoch(epoch_schedule.get_epoch(target_slot)));
+    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda, later_slot);
+    assert_client_err(tx, "Target slot not reached");
+
+    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda, target_slot)?;
     let (slot_created, tx_block_time) = fetch_tx_block_details(program, tx);
-    let epoch_info = program.rpc().get_epoch_info()?;
+    let target_epoch = epoch_schedule.get_epoch(target_slot);
     let vote_expiry_timestamp = tx_block_time + VOTE_DURATION;
 
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.ballot_id, 0);
     assert_eq!(ballot_box.bump, bump);
-    assert_eq!(ballot_box.epoch, epoch_info.epoch);
+    assert_eq!(ballot_box.epoch, target_epoch);
     assert_eq!(ballot_box.slot_created, slot_created);
+    assert_eq!(ballot_box.target_slot, target_slot);
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.min_consensus_threshold_bps, MIN_CONSENSUS_BPS);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes.len(), 0);
-    assert_eq!(ballot_box.ballot_tallies.len(), 0);
+    assert_eq!(ballot_box.vote_count(), 0);
+    assert_eq!(ballot_box.ballot_tallies().len(), 0);
     assert_eq!(ballot_box.vote_expiry_timestamp, vote_expiry_timestamp);
+    assert_eq!(ballot_box.created_timestamp, tx_block_time);
+    assert_eq!(ballot_box.cancelled_timestamp, 0);
+
+    // The ballot box is allocated at full size and can't be migrated or reallocated.
+    let ballot_box_account = program.rpc().get_account(&ballot_box_pda)?;
+    assert_eq!(ballot_box_account.data.len(), BallotBox::SIZE);
+    let tx = send_migrate_ballot_box(tx_sender1, ballot_box_pda);
+    assert_client_err(tx, "Account already migrated");
 
     // Check that next_ballot_id is incremented
     let program_config: ProgramConfig = program.account(context.program_config_pda)?;
     assert_eq!(program_config.next_ballot_id, 1);
+    assert_eq!(
+        ballot_box.eligible_operators(),
+        program_config.whitelisted_operators
+    );
+    assert_eq!(
+        ballot_box.eligible_operator_weights(),
+        program_config.operator_weights
+    );
+    assert_eq!(ballot_box.total_weight, 8);
+
+    // Operators added to the whitelist after creation are not eligible to vote.
+    let late_operator = Keypair::new();
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    send_update_operator_whitelist(
+        tx_sender_admin,
+        Some(vec![late_operator.pubkey()]),
+        None,
+        None,
+    )?;
+    let tx_sender_late = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &late_operator,
+    };
+    let tx = send_cast_vote(
+        tx_sender_late,
+        ballot_box_pda,
+        Ballot {
+            meta_merkle_root: [1; 32],
+            snapshot_hash: [2; 32],
+            snapshot_slot: target_slot,
+            ..Default::default()
+        },
+    );
+    assert_client_err(tx, "Operator not whitelisted");
+    send_update_operator_whitelist(
+        tx_sender_admin,
+        None,
+        Some(vec![late_operator.pubkey()]),
+        None,
+    )?;
 
     // Casting an invalid ballot fails.
     let ballot1 = Ballot {
         meta_merkle_root: [0; 32],
         snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
     };
 
-    let tx = send_cast_vote(tx_sender1, ballot_box_pda, ballot1.clone());
+    let tx = send_cast_vote(tx_sender1, ballot_box_pda, ballot1);
     assert_client_err(tx, "Invalid ballot");
 
+    // Casting a ballot for a different snapshot slot fails.
+    let ballot1 = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot + 1,
+        ..Default::default()
+    };
+    let tx = send_cast_vote(tx_sender1, ballot_box_pda, ballot1);
+    assert_client_err(tx, "Snapshot slot does not match target slot");
+
     // Operator 1 casts a vote.
     let ballot1 = Ballot {
         meta_merkle_root: [1; 32],
         snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
     };
-    let tx = send_cast_vote(tx_sender1, ballot_box_pda, ballot1.clone())?;
+    let tx = send_cast_vote(tx_sender1, ballot_box_pda, ballot1)?;
+
+    // The vote is emitted as an event.
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteCast(event)]
+            if event.operator == operator1.pubkey() && event.ballot == ballot1 && event.tally == 1
+    ));
 
     let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
-    let mut expected_operator_votes = [OperatorVote {
-        operator: operator1.pubkey(),
-        slot_voted: tx_slot,
-        ballot_index: 0,
-    }]
-    .to_vec();
-    let mut expected_ballot_tallies = [BallotTally {
-        index: 0,
-        ballot: ballot1.clone(),
-        tally: 1,
-    }]
-    .to_vec();
+    let mut expected_operator_votes = vec![(operator1.pubkey(), OperatorVote::new(tx_slot, 0))];
+    let mut expected_ballot_tallies = vec![BallotTally::new(0, ballot1, 1)];
 
     // Checks that a new ballot tally is created.
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.ballot_id, 0);
     assert_eq!(ballot_box.bump, bump);
-    assert_eq!(ballot_box.epoch, epoch_info.epoch);
+    assert_eq!(ballot_box.epoch, target_epoch);
     assert_eq!(ballot_box.slot_created, slot_created);
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.min_consensus_threshold_bps, MIN_CONSENSUS_BPS);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
     assert_eq!(ballot_box.vote_expiry_timestamp, vote_expiry_timestamp);
 
     // Casting ballot for non-whitelisted operator should fail.
@@ -239,7 +430,7 @@ fn test_balloting(
         payer: &context.payer,
         authority: &Keypair::new(),
     };
-    let tx = send_cast_vote(tx_sender_null, ballot_box_pda, ballot1.clone());
+    let tx = send_cast_vote(tx_sender_null, ballot_box_pda, ballot1);
     assert_client_err(tx, "Operator not whitelisted");
 
     // Operator 2 casts a different vote.
@@ -247,6 +438,8 @@ fn test_balloting(
     let ballot2 = Ballot {
         meta_merkle_root: [2; 32],
         snapshot_hash: [3; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
     };
     let tx_sender2 = &TxSender {
         program,
@@ -254,32 +447,28 @@ fn test_balloting(
         payer: &context.payer,
         authority: operator2,
     };
-    let tx = send_cast_vote(tx_sender2, ballot_box_pda, ballot2.clone())?;
+    let tx = send_cast_vote(tx_sender2, ballot_box_pda, ballot2)?;
     let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
 
-    expected_operator_votes.push(OperatorVote {
-        operator: operator2.pubkey(),
-        slot_voted: tx_slot,
-        ballot_index: 1,
-    });
-    expected_ballot_tallies.push(BallotTally {
-        index: 1,
-        ballot: ballot2.clone(),
-        tally: 1,
-    });
+    expected_operator_votes.push((operator2.pubkey(), OperatorVote::new(tx_slot, 1)));
+    expected_ballot_tallies.push(BallotTally::new(1, ballot2, 1));
 
     // Checks that a new ballot tally is created.
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
     assert_eq!(ballot_box.vote_expiry_timestamp, vote_expiry_timestamp);
 
     // Operator 3, 4, 5, 6, 7 casts ballot 3.
     let ballot3 = Ballot {
         meta_merkle_root: context.meta_merkle_snapshot.root,
         snapshot_hash: [4; 32],
+        snapshot_slot: target_slot,
+        total_active_stake: 1_000,
+        vote_account_count: 3,
+        stake_account_count: 5,
     };
     for i in 2..7 {
         let operator = &context.operators[i];
@@ -289,26 +478,18 @@ fn test_balloting(
             payer: &context.payer,
             authority: operator,
         };
-        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot3.clone())?;
+        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot3)?;
         let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
-        expected_operator_votes.push(OperatorVote {
-            operator: operator.pubkey(),
-            slot_voted: tx_slot,
-            ballot_index: 2,
-        });
+        expected_operator_votes.push((operator.pubkey(), OperatorVote::new(tx_slot, 2)));
     }
-    expected_ballot_tallies.push(BallotTally {
-        index: 2,
-        ballot: ballot3.clone(),
-        tally: 5,
-    });
+    expected_ballot_tallies.push(BallotTally::new(2, ballot3, 5));
 
     // Checks votes for operator 3, 4, 5, 6, 7 - no consensus reached yet.
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
 
     // Operator 2 removes vote (ballot 1).
     send_remove_vote(tx_sender2, ballot_box_pda)?;
@@ -316,8 +497,8 @@ fn test_balloting(
     expected_ballot_tallies[1].tally = 0;
 
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
 
     // Removing non-existent vote should fail.
     let tx = send_remove_vote(tx_sender2, ballot_box_pda);
@@ -329,21 +510,26 @@ fn test_balloting(
     assert_client_err(tx, "Consensus not reached");
 
     // Operator 2 votes for ballot 3 instead. Consensus expected with 6/8 votes (75%).
-    let tx = send_cast_vote(tx_sender2, ballot_box_pda, ballot3.clone())?;
+    let tx = send_cast_vote(tx_sender2, ballot_box_pda, ballot3)?;
     let (consensus_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
 
-    expected_operator_votes.push(OperatorVote {
-        operator: operator2.pubkey(),
-        slot_voted: consensus_slot,
-        ballot_index: 2,
-    });
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteCast(_), GovEvent::ConsensusReached(event)]
+            if event.ballot == ballot3 && event.tally == 6 && event.slot == consensus_slot
+    ));
+
+    // Votes are stored by position in eligible_operators, so operator 2 is listed second.
+    let operator2_vote = OperatorVote::new(consensus_slot, 2);
+    expected_operator_votes.insert(1, (operator2.pubkey(), operator2_vote));
     expected_ballot_tallies[2].tally += 1;
 
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, consensus_slot);
     assert_eq!(ballot_box.winning_ballot, ballot3);
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
 
     // Operator 8 should be able to vote even after consensus.
     let operator8 = &context.operators[7];
@@ -353,36 +539,80 @@ fn test_balloting(
         payer: &context.payer,
         authority: operator8,
     };
-    let tx = send_cast_vote(tx_sender8, ballot_box_pda, ballot3.clone())?;
-    let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
+    let tx = send_cast_vote(tx_sender8, ballot_box_pda, ballot3)?;
+    let (operator8_vote_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
 
-    expected_operator_votes.push(OperatorVote {
-        operator: operator8.pubkey(),
-        slot_voted: tx_slot,
-        ballot_index: 2,
-    });
+    expected_operator_votes.push((operator8.pubkey(), OperatorVote::new(operator8_vote_slot, 2)));
     expected_ballot_tallies[2].tally += 1;
 
     // Voting after consensus doesn't change the consensus result.
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, consensus_slot);
     assert_eq!(ballot_box.winning_ballot, ballot3);
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
 
     // Voting more than once per operator should fail.
-    let tx = send_cast_vote(tx_sender8, ballot_box_pda, ballot3.clone());
+    let tx = send_cast_vote(tx_sender8, ballot_box_pda, ballot3);
     assert_client_err(tx, "Operator has voted");
 
     // Removing vote after consensus fails.
     let tx = send_remove_vote(tx_sender2, ballot_box_pda);
     assert_client_err(tx, "Consensus has reached");
 
+    // Operator stats can't be recorded before the ballot is finalized.
+    let tx = send_record_operator_stats(tx_sender1, ballot_box_pda, &[operator1.pubkey()]);
+    assert_client_err(tx, "Ballot not finalized");
+
     // Finalize ballot should succeed.
-    send_finalize_ballot(tx_sender1, ballot_box_pda, consensus_result_pda)?;
+    let tx = send_finalize_ballot(tx_sender1, ballot_box_pda, consensus_result_pda)?;
     let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
     assert_eq!(consensus_result.ballot_id, ballot_box.ballot_id);
     assert_eq!(consensus_result.ballot, ballot_box.winning_ballot);
+    assert_eq!(consensus_result.ballot.snapshot_slot, target_slot);
+    assert_eq!(consensus_result.ballot.total_active_stake, 1_000);
+    assert_eq!(consensus_result.decision_method, DecisionMethod::Consensus);
+    assert_eq!(consensus_result.epoch, ballot_box.epoch);
+    assert_eq!(consensus_result.slot_consensus_reached, consensus_slot);
+    assert_eq!(consensus_result.tally, 7);
+    assert_eq!(consensus_result.total_weight, 8);
+    assert_eq!(consensus_result.tally_bps, 8750);
+
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::BallotFinalized(event)]
+            if event.decision_method == DecisionMethod::Consensus
+                && event.tally == 7
+                && event.tally_bps == 8750
+    ));
+
+    // Operator stats are recorded once per eligible operator after finalization.
+    let operators = ballot_box.eligible_operators().to_vec();
+    send_record_operator_stats(tx_sender1, ballot_box_pda, &operators)?;
+    let tx = send_record_operator_stats(tx_sender1, ballot_box_pda, &[operator1.pubkey()]);
+    assert_client_err(tx, "Operator stats already recorded for ballot box");
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert!((0..operators.len()).all(|idx| ballot_box.has_operator_stats_recorded(idx)));
+
+    // Operator stats are updated by votes and recorded ballot boxes.
+    let stats1: OperatorStats = program.account(OperatorStats::pda(&operator1.pubkey()).0)?;
+    assert_eq!(stats1.operator, operator1.pubkey());
+    assert_eq!(stats1.ballots_seen, 1);
+    assert_eq!(stats1.votes_cast, 1);
+    assert_eq!(stats1.votes_matching_consensus, 0);
+
+    // Removed votes still count as cast.
+    let stats2: OperatorStats = program.account(OperatorStats::pda(&operator2.pubkey()).0)?;
+    assert_eq!(stats2.ballots_seen, 1);
+    assert_eq!(stats2.votes_cast, 2);
+    assert_eq!(stats2.votes_matching_consensus, 1);
+
+    let stats8: OperatorStats = program.account(OperatorStats::pda(&operator8.pubkey()).0)?;
+    assert_eq!(stats8.ballots_seen, 1);
+    assert_eq!(stats8.votes_cast, 1);
+    assert_eq!(stats8.votes_matching_consensus, 1);
+    assert_eq!(stats8.average_vote_latency_slots(), operator8_vote_slot - slot_created);
 
     Ok(())
 }
@@ -401,46 +631,42 @@ fn test_tie_breaker(
         payer: &context.payer,
         authority: operator1,
     };
-    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda)?;
+    let target_slot = program.rpc().get_slot()?;
+    let tx = send_init_ballot_box(tx_sender1, ballot_box_pda, target_slot)?;
     let (slot_created, tx_block_time) = fetch_tx_block_details(program, tx);
-    let epoch_info = program.rpc().get_epoch_info()?;
+    let target_epoch = program.rpc().get_epoch_schedule()?.get_epoch(target_slot);
     let vote_expiry_timestamp = tx_block_time + VOTE_DURATION;
 
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.ballot_id, 1);
     assert_eq!(ballot_box.bump, bump);
-    assert_eq!(ballot_box.epoch, epoch_info.epoch);
+    assert_eq!(ballot_box.epoch, target_epoch);
     assert_eq!(ballot_box.slot_created, slot_created);
+    assert_eq!(ballot_box.target_slot, target_slot);
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.min_consensus_threshold_bps, MIN_CONSENSUS_BPS);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes.len(), 0);
-    assert_eq!(ballot_box.ballot_tallies.len(), 0);
+    assert_eq!(ballot_box.eligible_operators().len(), 8);
+    assert_eq!(ballot_box.vote_count(), 0);
+    assert_eq!(ballot_box.ballot_tallies().len(), 0);
     assert_eq!(ballot_box.vote_expiry_timestamp, vote_expiry_timestamp);
 
     let ballot1 = Ballot {
         meta_merkle_root: [1; 32],
         snapshot_hash: [3; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
     };
     let ballot2 = Ballot {
         meta_merkle_root: [2; 32],
         snapshot_hash: [4; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
     };
 
     let mut expected_operator_votes = vec![];
-    let mut expected_ballot_tallies = [
-        BallotTally {
-            index: 0,
-            ballot: ballot1.clone(),
-            tally: 0,
-        },
-        BallotTally {
-            index: 1,
-            ballot: ballot2.clone(),
-            tally: 0,
-        },
-    ]
-    .to_vec();
+    let mut expected_ballot_tallies =
+        [BallotTally::new(0, ballot1, 0), BallotTally::new(1, ballot2, 0)];
 
     for i in 0..2 {
         let operator = &context.operators[i];
@@ -450,13 +676,9 @@ fn test_tie_breaker(
             payer: &context.payer,
             authority: operator,
         };
-        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot1.clone())?;
+        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot1)?;
         let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
-        expected_operator_votes.push(OperatorVote {
-            operator: operator.pubkey(),
-            slot_voted: tx_slot,
-            ballot_index: 0,
-        });
+        expected_operator_votes.push((operator.pubkey(), OperatorVote::new(tx_slot, 0)));
         expected_ballot_tallies[0].tally += 1;
     }
 
@@ -468,21 +690,17 @@ fn test_tie_breaker(
             payer: &context.payer,
             authority: operator,
         };
-        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot2.clone())?;
+        let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot2)?;
         let (tx_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
-        expected_operator_votes.push(OperatorVote {
-            operator: operator.pubkey(),
-            slot_voted: tx_slot,
-            ballot_index: 1,
-        });
+        expected_operator_votes.push((operator.pubkey(), OperatorVote::new(tx_slot, 1)));
         expected_ballot_tallies[1].tally += 1;
     }
 
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, 0);
     assert_eq!(ballot_box.winning_ballot, Ballot::default());
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
 
     // Setting tie breaker vote before vote expiry fails.
     let tx_sender_admin = &TxSender {
@@ -515,27 +733,81 @@ fn test_tie_breaker(
         payer: &context.payer,
         authority: &context.operators[7],
     };
-    let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot1.clone());
+    let tx = send_cast_vote(tx_sender, ballot_box_pda, ballot1);
     assert_client_err(tx, "Voting has expired");
 
     // Verify that consensus is reached.
     let ballot_box: BallotBox = program.account(ballot_box_pda)?;
     assert_eq!(ballot_box.slot_consensus_reached, consensus_slot);
     assert_eq!(ballot_box.winning_ballot, ballot1);
-    assert_eq!(ballot_box.operator_votes, expected_operator_votes);
-    assert_eq!(ballot_box.ballot_tallies, expected_ballot_tallies);
+    assert_eq!(operator_votes(&ballot_box), expected_operator_votes);
+    assert_eq!(ballot_box.ballot_tallies(), expected_ballot_tallies);
+
+    // Closing ballot box before finalization fails.
+    let tx = send_close_ballot_box(tx_sender_admin, ballot_box_pda, context.payer.pubkey());
+    assert_client_err(tx, "Ballot not finalized");
 
     // Finalize ballot after consensus.
     let (consensus_result_pda, _bump) = ConsensusResult::pda(1);
-    send_finalize_ballot(tx_sender, ballot_box_pda, consensus_result_pda)?;
+    let tx = send_finalize_ballot(tx_sender, ballot_box_pda, consensus_result_pda)?;
+    let (_tx_slot, finalized_timestamp) = fetch_tx_block_details(program, tx);
     let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
     assert_eq!(consensus_result.ballot_id, ballot_box.ballot_id);
     assert_eq!(consensus_result.ballot, ballot_box.winning_ballot);
+    assert_eq!(consensus_result.payer, context.payer.pubkey());
+    assert_eq!(consensus_result.decision_method, DecisionMethod::TieBreaker);
+    assert_eq!(consensus_result.slot_consensus_reached, consensus_slot);
+    assert_eq!(consensus_result.tally, 2);
+    assert_eq!(consensus_result.tally_bps, 2500);
+    assert_eq!(consensus_result.finalized_timestamp, finalized_timestamp);
+    assert_eq!(consensus_result.ballot_box, ballot_box_pda);
+
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.payer, context.payer.pubkey());
+    assert_eq!(ballot_box.finalized_timestamp, finalized_timestamp);
 
     // Setting tie breaker vote after consensus fails.
     let tx = send_set_tie_breaker(tx_sender_admin, ballot_box_pda, 0);
     assert_client_err(tx, "Consensus has reached");
 
+    // Closing ballot box fails if signer is neither authority nor payer.
+    let tx = send_close_ballot_box(tx_sender, ballot_box_pda, context.payer.pubkey());
+    assert_client_err(tx, "Signer is not authorized");
+
+    // Closing ballot box within grace period fails.
+    let tx = send_close_ballot_box(tx_sender_admin, ballot_box_pda, context.payer.pubkey());
+    assert_client_err(tx, "Grace period not elapsed");
+
+    // Archiving consensus result fails while the ballot box is open.
+    let tx = send_archive_consensus_result(
+        tx_sender_admin,
+        consensus_result_pda,
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Ballot box must be closed before archiving");
+
+    // Sleep till grace period has elapsed and close ballot box.
+    thread::sleep(Duration::from_secs(CLOSE_GRACE_PERIOD as u64 + 2));
+    send_close_ballot_box(tx_sender_admin, ballot_box_pda, context.payer.pubkey())?;
+    program
+        .rpc()
+        .get_account(&ballot_box_pda)
+        .expect_err("AccountNotFound");
+
+    // Archiving consensus result fails if signer is not the authority.
+    let tx = send_archive_consensus_result(tx_sender, consensus_result_pda, context.payer.pubkey());
+    assert_client_err(tx, "ConstraintHasOne");
+
+    send_archive_consensus_result(
+        tx_sender_admin,
+        consensus_result_pda,
+        context.payer.pubkey(),
+    )?;
+    program
+        .rpc()
+        .get_account(&consensus_result_pda)
+        .expect_err("AccountNotFound");
+
     Ok(())
 }
 
@@ -574,27 +846,51 @@ fn test_merkle_proofs(
     assert_eq!(merkle_proof.meta_merkle_leaf, bundle.meta_merkle_leaf);
     assert_eq!(merkle_proof.meta_merkle_proof, meta_proof);
     assert_eq!(merkle_proof.close_timestamp, 1);
+    assert_eq!(merkle_proof.proof_len as usize, meta_proof.len());
+    assert!(merkle_proof.sealed);
 
     // Verifies that leaf exist in root stored in consensus result.
-    send_verify_merkle_proof(
+    let tx = send_verify_merkle_proof(
         tx_sender,
         consensus_result_pda,
         merkle_proof_pda,
         None,
         None,
     )?;
+    let verified_stake: VerifiedStake = fetch_tx_return_data(program, tx);
+    assert_eq!(
+        verified_stake,
+        VerifiedStake {
+            voting_wallet: bundle.meta_merkle_leaf.voting_wallet,
+            vote_account: bundle.meta_merkle_leaf.vote_account,
+            stake_account: None,
+            active_stake: bundle.meta_merkle_leaf.active_stake,
+            ballot_id: 0,
+        }
+    );
 
     // Verify for stake accounts under this vote account.
     let stake_leaves = &bundle.stake_merkle_leaves;
     for i in 0..min(5, stake_leaves.len()) {
         let stake_proof = bundle.clone().get_stake_merkle_proof(i);
-        send_verify_merkle_proof(
+        let tx = send_verify_merkle_proof(
             tx_sender,
             consensus_result_pda,
             merkle_proof_pda,
             Some(stake_proof),
             Some(stake_leaves[i].clone()),
         )?;
+        let verified_stake: VerifiedStake = fetch_tx_return_data(program, tx);
+        assert_eq!(
+            verified_stake,
+            VerifiedStake {
+                voting_wallet: stake_leaves[i].voting_wallet,
+                vote_account: bundle.meta_merkle_leaf.vote_account,
+                stake_account: Some(stake_leaves[i].stake_account),
+                active_stake: stake_leaves[i].active_stake,
+                ballot_id: 0,
+            }
+        );
     }
 
     // Close MetaMerkleProof
@@ -605,6 +901,60 @@ fn test_merkle_proofs(
         .rpc()
         .get_account(&merkle_proof_pda)
         .expect_err("AccountNotFound");
+
+    // Init MetaMerkleProof with a declared proof length and append the proof in chunks.
+    send_init_chunked_meta_merkle_proof(
+        tx_sender,
+        merkle_proof_pda,
+        consensus_result_pda,
+        bundle.meta_merkle_leaf.clone(),
+        meta_proof.len() as u8,
+        1,
+    )?;
+    let merkle_proof: MetaMerkleProof = program.account(merkle_proof_pda)?;
+    assert_eq!(merkle_proof.proof_len as usize, meta_proof.len());
+    assert!(merkle_proof.meta_merkle_proof.is_empty());
+    assert!(!merkle_proof.sealed);
+
+    // Verify and seal should fail before the proof is complete.
+    let tx = send_verify_merkle_proof(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        None,
+        None,
+    );
+    assert_client_err(tx, "MetaMerkleProof is not sealed");
+    let tx = send_seal_meta_merkle_proof(tx_sender, consensus_result_pda, merkle_proof_pda);
+    assert_client_err(tx, "MetaMerkleProof is incomplete");
+
+    for proof_chunk in meta_proof.chunks(2) {
+        send_append_meta_merkle_proof(tx_sender, merkle_proof_pda, proof_chunk.to_vec())?;
+    }
+    let merkle_proof: MetaMerkleProof = program.account(merkle_proof_pda)?;
+    assert_eq!(merkle_proof.meta_merkle_proof, meta_proof);
+
+    // Appending beyond the declared proof length should fail.
+    let tx = send_append_meta_merkle_proof(tx_sender, merkle_proof_pda, vec![[0; 32]]);
+    assert_client_err(tx, "Vector size exceeded");
+
+    send_seal_meta_merkle_proof(tx_sender, consensus_result_pda, merkle_proof_pda)?;
+    let merkle_proof: MetaMerkleProof = program.account(merkle_proof_pda)?;
+    assert!(merkle_proof.sealed);
+
+    // Appending after sealing should fail.
+    let tx = send_append_meta_merkle_proof(tx_sender, merkle_proof_pda, vec![]);
+    assert_client_err(tx, "MetaMerkleProof is sealed");
+
+    send_verify_merkle_proof(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        None,
+        None,
+    )?;
+    send_close_meta_merkle_proof(tx_sender, merkle_proof_pda)?;
+
     Ok(())
 }
 
@@ -674,7 +1024,2143 @@ fn test_invalid_merkle_proofs(
     Ok(())
 }
 
-#[test]
+/// Creates a ballot box with the given id targeting the slot of `ballot` and has operators
+/// vote for `ballot` until consensus is reached, then finalizes it. Returns the
+/// ConsensusResult address.
+fn reach_consensus(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+    ballot_id: u64,
+    ballot: Ballot,
+) -> Result<Pubkey, ClientError> {
+    let (ballot_box_pda, _bump) = BallotBox::pda(ballot_id);
+    let tx_sender1 = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.operators[0],
+    };
+    send_init_ballot_box(tx_sender1, ballot_box_pda, ballot.snapshot_slot)?;
+
+    // 6 of 8 equally weighted operators (75%) reach consensus.
+    for operator in &context.operators[..6] {
+        let tx_sender = &TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        };
+        send_cast_vote(tx_sender, ballot_box_pda, ballot)?;
+    }
+
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(ballot_id);
+    send_finalize_ballot(tx_sender1, ballot_box_pda, consensus_result_pda)?;
+    Ok(consensus_result_pda)
+}
+
+fn test_proposals(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    // Build a snapshot with voting wallets controlled by the test.
+    let validator = Keypair::new();
+    let staker1 = Keypair::new();
+    let staker2 = Keypair::new();
+    let vote_account = Keypair::new().pubkey();
+    let stake_leaf1 = StakeMerkleLeaf {
+        voting_wallet: staker1.pubkey(),
+        stake_account: Keypair::new().pubkey(),
+        active_stake: 100,
+    };
+    let stake_leaf2 = StakeMerkleLeaf {
+        voting_wallet: staker2.pubkey(),
+        stake_account: Keypair::new().pubkey(),
+        active_stake: 200,
+    };
+    let other_stake_leaf = StakeMerkleLeaf {
+        voting_wallet: Keypair::new().pubkey(),
+        stake_account: Keypair::new().pubkey(),
+        active_stake: 500,
+    };
+    let snapshot = build_meta_merkle_snapshot(
+        vec![
+            (
+                vote_account,
+                validator.pubkey(),
+                vec![stake_leaf1.clone(), stake_leaf2.clone()],
+            ),
+            (
+                Keypair::new().pubkey(),
+                Keypair::new().pubkey(),
+                vec![other_stake_leaf],
+            ),
+        ],
+        1,
+    );
+    let bundle = snapshot
+        .leaf_bundles
+        .iter()
+        .find(|bundle| bundle.meta_merkle_leaf.vote_account == vote_account)
+        .unwrap()
+        .clone();
+    let meta_leaf = bundle.meta_merkle_leaf.clone();
+    let stake_index1 = bundle
+        .stake_merkle_leaves
+        .iter()
+        .position(|leaf| leaf.stake_account == stake_leaf1.stake_account)
+        .unwrap();
+    let stake_index2 = bundle
+        .stake_merkle_leaves
+        .iter()
+        .position(|leaf| leaf.stake_account == stake_leaf2.stake_account)
+        .unwrap();
+    let stake_proof1 = bundle.clone().get_stake_merkle_proof(stake_index1);
+    let stake_proof2 = bundle.clone().get_stake_merkle_proof(stake_index2);
+
+    let target_slot = program.rpc().get_slot()?;
+    let ballot = Ballot {
+        meta_merkle_root: snapshot.root,
+        snapshot_hash: [5; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let consensus_result_pda = reach_consensus(program, context, 2, ballot)?;
+
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let (merkle_proof_pda, _bump) = MetaMerkleProof::pda(&consensus_result_pda, &vote_account);
+    send_init_meta_merkle_proof(
+        tx_sender_admin,
+        merkle_proof_pda,
+        consensus_result_pda,
+        meta_leaf.clone(),
+        bundle.proof.clone().unwrap(),
+        1,
+    )?;
+
+    // Create proposal
+    let (proposal_pda, bump) = Proposal::pda(0);
+    let tx = send_create_proposal(
+        tx_sender_admin,
+        proposal_pda,
+        consensus_result_pda,
+        [6; 32],
+        PROPOSAL_DURATION,
+        QUORUM_STAKE,
+        PASS_THRESHOLD_BPS,
+    )?;
+    let (_tx_slot, tx_block_time) = fetch_tx_block_details(program, tx);
+    let vote_expiry_timestamp = tx_block_time + PROPOSAL_DURATION;
+
+    let proposal: Proposal = program.account(proposal_pda)?;
+    assert_eq!(proposal.proposal_id, 0);
+    assert_eq!(proposal.bump, bump);
+    assert_eq!(proposal.proposer, context.payer.pubkey());
+    assert_eq!(proposal.consensus_result, consensus_result_pda);
+    assert_eq!(proposal.description_hash, [6; 32]);
+    assert_eq!(proposal.vote_start_timestamp, tx_block_time);
+    assert_eq!(proposal.vote_expiry_timestamp, vote_expiry_timestamp);
+    assert_eq!(proposal.quorum_stake, QUORUM_STAKE);
+    assert_eq!(proposal.pass_threshold_bps, PASS_THRESHOLD_BPS);
+    assert_eq!(proposal.total_stake(), 0);
+    assert_eq!(proposal.status, ProposalStatus::Voting);
+
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.next_proposal_id, 1);
+
+    // Staker 1 votes against with its stake account before the validator votes.
+    let tx_sender_staker1 = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &staker1,
+    };
+
+    // Votes verify merkle proofs, so they are rejected while verification is paused.
+    send_set_paused(tx_sender_admin, None, Some(true))?;
+    let tx = send_cast_delegator_vote(
+        tx_sender_staker1,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Against,
+        stake_proof1.clone(),
+        stake_leaf1.clone(),
+    );
+    assert_client_err(tx, "Verification is paused");
+    let tx = send_cast_proposal_vote(
+        tx_sender_staker1,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Against,
+    );
+    assert_client_err(tx, "Verification is paused");
+    send_set_paused(tx_sender_admin, None, Some(false))?;
+
+    send_cast_delegator_vote(
+        tx_sender_staker1,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Against,
+        stake_proof1.clone(),
+        stake_leaf1.clone(),
+    )?;
+
+    let (vote_record_pda, _bump) = VoteRecord::pda(&proposal_pda, &stake_leaf1.stake_account);
+    let vote_record: VoteRecord = program.account(vote_record_pda)?;
+    assert_eq!(vote_record.proposal, proposal_pda);
+    assert_eq!(vote_record.voting_wallet, staker1.pubkey());
+    assert_eq!(vote_record.vote_account, vote_account);
+    assert_eq!(vote_record.stake_account, Some(stake_leaf1.stake_account));
+    assert_eq!(vote_record.stake, stake_leaf1.active_stake);
+    assert_eq!(vote_record.overridden_stake, 0);
+    assert_eq!(vote_record.choice, Some(VoteChoice::Against));
+
+    // Validator record tracks the overridden stake before the validator has voted.
+    let (validator_vote_record_pda, _bump) = VoteRecord::pda(&proposal_pda, &vote_account);
+    let validator_vote_record: VoteRecord = program.account(validator_vote_record_pda)?;
+    assert_eq!(validator_vote_record.vote_account, vote_account);
+    assert_eq!(validator_vote_record.stake_account, None);
+    assert_eq!(validator_vote_record.stake, 0);
+    assert_eq!(
+        validator_vote_record.overridden_stake,
+        stake_leaf1.active_stake
+    );
+    assert_eq!(validator_vote_record.choice, None);
+
+    let proposal: Proposal = program.account(proposal_pda)?;
+    assert_eq!(proposal.against_stake, stake_leaf1.active_stake);
+
+    // Voting twice with the same stake account should fail.
+    let tx = send_cast_delegator_vote(
+        tx_sender_staker1,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::For,
+        stake_proof1,
+        stake_leaf1.clone(),
+    );
+    assert_client_err(tx, "already in use");
+
+    // Voting with a stake account of a different voting wallet should fail.
+    let tx = send_cast_delegator_vote(
+        tx_sender_staker1,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::For,
+        stake_proof2.clone(),
+        stake_leaf2.clone(),
+    );
+    assert_client_err(tx, "Signer is not the voting wallet");
+
+    // Validator votes for with its vote account, excluding stake of staker 1.
+    let tx_sender_validator = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &validator,
+    };
+    send_cast_proposal_vote(
+        tx_sender_validator,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::For,
+    )?;
+    let validator_vote_record: VoteRecord = program.account(validator_vote_record_pda)?;
+    assert_eq!(validator_vote_record.voting_wallet, validator.pubkey());
+    assert_eq!(
+        validator_vote_record.stake,
+        meta_leaf.active_stake - stake_leaf1.active_stake
+    );
+    assert_eq!(validator_vote_record.choice, Some(VoteChoice::For));
+
+    let proposal: Proposal = program.account(proposal_pda)?;
+    assert_eq!(
+        proposal.for_stake,
+        meta_leaf.active_stake - stake_leaf1.active_stake
+    );
+    assert_eq!(proposal.against_stake, stake_leaf1.active_stake);
+
+    // Validator voting twice should fail.
+    let tx = send_cast_proposal_vote(
+        tx_sender_validator,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Against,
+    );
+    assert_client_err(tx, "Vote already cast");
+
+    // Staker 2 abstains after the validator voted, moving its stake out of for.
+    let tx_sender_staker2 = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &staker2,
+    };
+    send_cast_delegator_vote(
+        tx_sender_staker2,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Abstain,
+        stake_proof2,
+        stake_leaf2.clone(),
+    )?;
+    let validator_vote_record: VoteRecord = program.account(validator_vote_record_pda)?;
+    assert_eq!(validator_vote_record.stake, 0);
+    assert_eq!(
+        validator_vote_record.overridden_stake,
+        meta_leaf.active_stake
+    );
+
+    let proposal: Proposal = program.account(proposal_pda)?;
+    assert_eq!(proposal.for_stake, 0);
+    assert_eq!(proposal.against_stake, stake_leaf1.active_stake);
+    assert_eq!(proposal.abstain_stake, stake_leaf2.active_stake);
+    assert_eq!(proposal.total_stake(), meta_leaf.active_stake);
+
+    // Finalizing before expiry should fail.
+    let tx = send_finalize_proposal(tx_sender_admin, 0);
+    assert_client_err(tx, "Voting not expired");
+
+    // Sleep till expiry
+    let current_slot = program.rpc().get_slot()?;
+    let current_time = program.rpc().get_block_time(current_slot)?;
+    let sleep_duration = vote_expiry_timestamp - current_time + 2;
+    thread::sleep(Duration::from_secs(sleep_duration as u64));
+
+    // Voting after expiry should fail.
+    let tx = send_cast_proposal_vote(
+        tx_sender_validator,
+        proposal_pda,
+        consensus_result_pda,
+        &meta_leaf,
+        VoteChoice::Against,
+    );
+    assert_client_err(tx, "Voting has expired");
+
+    // Finalize proposal: quorum is met but for stake is below pass threshold.
+    send_finalize_proposal(tx_sender_admin, 0)?;
+    let proposal: Proposal = program.account(proposal_pda)?;
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    // Finalizing twice should fail.
+    let tx = send_finalize_proposal(tx_sender_admin, 0);
+    assert_client_err(tx, "Proposal has been finalized");
+
+    Ok(())
+}
+
+fn test_batch_merkle_proofs(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+
+    // Build a snapshot where a single voting wallet controls most stake accounts
+    // delegated to a vote account.
+    let pool_wallet = Keypair::new().pubkey();
+    let vote_account = Keypair::new().pubkey();
+    let mut stake_leaves: Vec<StakeMerkleLeaf> = (1..=5)
+        .map(|i| StakeMerkleLeaf {
+            voting_wallet: pool_wallet,
+            stake_account: Keypair::new().pubkey(),
+            active_stake: i * 10,
+        })
+        .collect();
+    stake_leaves.push(StakeMerkleLeaf {
+        voting_wallet: Keypair::new().pubkey(),
+        stake_account: Keypair::new().pubkey(),
+        active_stake: 1000,
+    });
+    let other_stake_leaf = StakeMerkleLeaf {
+        voting_wallet: Keypair::new().pubkey(),
+        stake_account: Keypair::new().pubkey(),
+        active_stake: 500,
+    };
+    let snapshot = build_meta_merkle_snapshot(
+        vec![
+            (vote_account, Keypair::new().pubkey(), stake_leaves),
+            (
+                Keypair::new().pubkey(),
+                Keypair::new().pubkey(),
+                vec![other_stake_leaf],
+            ),
+        ],
+        1,
+    );
+    let bundle = snapshot
+        .leaf_bundles
+        .iter()
+        .find(|bundle| bundle.meta_merkle_leaf.vote_account == vote_account)
+        .unwrap()
+        .clone();
+
+    let target_slot = program.rpc().get_slot()?;
+    let ballot = Ballot {
+        meta_merkle_root: snapshot.root,
+        snapshot_hash: [7; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let consensus_result_pda = reach_consensus(program, context, 3, ballot)?;
+    let (merkle_proof_pda, _bump) = MetaMerkleProof::pda(&consensus_result_pda, &vote_account);
+    send_init_meta_merkle_proof(
+        tx_sender,
+        merkle_proof_pda,
+        consensus_result_pda,
+        bundle.meta_merkle_leaf.clone(),
+        bundle.proof.clone().unwrap(),
+        1,
+    )?;
+
+    // Verify all stake accounts of the pool wallet in one instruction.
+    let pool_indices: Vec<usize> = (0..bundle.stake_merkle_leaves.len())
+        .filter(|&i| bundle.stake_merkle_leaves[i].voting_wallet == pool_wallet)
+        .collect();
+    let pool_leaves: Vec<StakeMerkleLeaf> = pool_indices
+        .iter()
+        .map(|&i| bundle.stake_merkle_leaves[i].clone())
+        .collect();
+    let (proof, proof_flags) = bundle.get_stake_merkle_multiproof(&pool_indices);
+    let tx = send_verify_stake_merkle_proofs_batch(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        pool_leaves.clone(),
+        proof.clone(),
+        proof_flags.clone(),
+    )?;
+    let verified_stake_batch: VerifiedStakeBatch = fetch_tx_return_data(program, tx);
+    assert_eq!(
+        verified_stake_batch,
+        VerifiedStakeBatch {
+            voting_wallet: pool_wallet,
+            vote_account,
+            stake_account_count: 5,
+            active_stake: 150,
+            ballot_id: 3,
+        }
+    );
+
+    // Verify a subset of the stake accounts.
+    let subset_indices = vec![pool_indices[1], pool_indices[3]];
+    let (subset_proof, subset_proof_flags) = bundle.get_stake_merkle_multiproof(&subset_indices);
+    let tx = send_verify_stake_merkle_proofs_batch(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        vec![pool_leaves[1].clone(), pool_leaves[3].clone()],
+        subset_proof.clone(),
+        subset_proof_flags.clone(),
+    )?;
+    let verified_stake_batch: VerifiedStakeBatch = fetch_tx_return_data(program, tx);
+    assert_eq!(verified_stake_batch.stake_account_count, 2);
+    assert_eq!(
+        verified_stake_batch.active_stake,
+        pool_leaves[1].active_stake + pool_leaves[3].active_stake
+    );
+
+    // Duplicate stake accounts should fail.
+    let tx = send_verify_stake_merkle_proofs_batch(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        vec![pool_leaves[1].clone(), pool_leaves[1].clone()],
+        subset_proof.clone(),
+        subset_proof_flags.clone(),
+    );
+    assert_client_err(tx, "Stake accounts must be unique");
+
+    // Stake accounts of different voting wallets should fail.
+    let all_indices: Vec<usize> = (0..bundle.stake_merkle_leaves.len()).collect();
+    let (all_proof, all_proof_flags) = bundle.get_stake_merkle_multiproof(&all_indices);
+    let tx = send_verify_stake_merkle_proofs_batch(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        bundle.stake_merkle_leaves.clone(),
+        all_proof,
+        all_proof_flags,
+    );
+    assert_client_err(tx, "Stake accounts have different voting wallets");
+
+    // Multiproof that doesn't match the leaves should fail.
+    let tx = send_verify_stake_merkle_proofs_batch(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        vec![pool_leaves[0].clone(), pool_leaves[3].clone()],
+        subset_proof,
+        subset_proof_flags,
+    );
+    assert_client_err(tx, "Invalid merkle proof");
+
+    Ok(())
+}
+
+fn test_admin_multisig(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let admins: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let admin_senders: Vec<TxSender> = admins
+        .iter()
+        .map(|admin| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: admin,
+        })
+        .collect();
+
+    // Threshold larger than the number of admins should fail.
+    let admin_keys: Vec<Pubkey> = admins.iter().map(|x| x.pubkey()).collect();
+    let tx = send_set_admins(tx_sender, admin_keys.clone(), 4);
+    assert_client_err(tx, "Invalid admin threshold");
+
+    // Enable 2-of-3 multisig.
+    send_set_admins(tx_sender, admin_keys.clone(), 2)?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.admins, admin_keys);
+    assert_eq!(program_config.admin_threshold, 2);
+
+    // Single authority updates should fail while multisig is enabled.
+    let tx = send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        Some(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    assert_client_err(tx, "Multisig is enabled, use an admin proposal");
+    let tx = send_set_admins(tx_sender, vec![], 0);
+    assert_client_err(tx, "Multisig is enabled, use an admin proposal");
+
+    // Non-admins cannot create proposals.
+    let proposal_id = program_config.next_admin_proposal_id;
+    let action = AdminAction::UpdateProgramConfig(ProgramConfigUpdate {
+        vote_duration: Some(VOTE_DURATION * 2),
+        ..Default::default()
+    });
+    let tx = send_create_admin_proposal(tx_sender, proposal_id, action.clone());
+    assert_client_err(tx, "Signer is not an admin");
+
+    // Proposer approves on creation.
+    send_create_admin_proposal(&admin_senders[0], proposal_id, action.clone())?;
+    let admin_proposal_pda = AdminProposal::pda(proposal_id).0;
+    let admin_proposal: AdminProposal = program.account(admin_proposal_pda)?;
+    assert_eq!(admin_proposal.proposal_id, proposal_id);
+    assert_eq!(admin_proposal.proposer, admin_keys[0]);
+    assert_eq!(admin_proposal.action, action);
+    assert_eq!(admin_proposal.admin_set_id, program_config.admin_set_id);
+    assert_eq!(admin_proposal.approvals, 0b1);
+
+    // Execution fails before threshold is met.
+    let tx = send_execute_admin_proposal(tx_sender, proposal_id, admin_keys[0], None);
+    assert_client_err(tx, "Admin threshold not met");
+
+    // Approving twice or approving as non-admin should fail.
+    let tx = send_approve_admin_proposal(&admin_senders[0], proposal_id);
+    assert_client_err(tx, "Admin has approved");
+    let tx = send_approve_admin_proposal(tx_sender, proposal_id);
+    assert_client_err(tx, "Signer is not an admin");
+
+    send_approve_admin_proposal(&admin_senders[1], proposal_id)?;
+    send_execute_admin_proposal(tx_sender, proposal_id, admin_keys[0], None)?;
+
+    // Verify config is updated and proposal is closed.
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.vote_duration, VOTE_DURATION * 2);
+    assert_eq!(program_config.next_admin_proposal_id, proposal_id + 1);
+    assert!(program.rpc().get_account(&admin_proposal_pda).is_err());
+
+    // Cancelled proposals are closed and can't be executed.
+    let proposal_id = program_config.next_admin_proposal_id;
+    send_create_admin_proposal(&admin_senders[1], proposal_id, action.clone())?;
+    send_cancel_admin_proposal(&admin_senders[1], proposal_id)?;
+    assert!(program
+        .rpc()
+        .get_account(&AdminProposal::pda(proposal_id).0)
+        .is_err());
+
+    // Open proposal, invalidated by the admin set change below.
+    let stale_proposal_id = proposal_id + 1;
+    send_create_admin_proposal(&admin_senders[0], stale_proposal_id, action)?;
+
+    // Disable multisig through an admin proposal.
+    let proposal_id = stale_proposal_id + 1;
+    let action = AdminAction::SetAdmins {
+        admins: vec![],
+        threshold: 0,
+    };
+    send_create_admin_proposal(&admin_senders[1], proposal_id, action)?;
+    send_approve_admin_proposal(&admin_senders[2], proposal_id)?;
+    send_execute_admin_proposal(tx_sender, proposal_id, admin_keys[1], None)?;
+
+    let admin_set_id = program_config.admin_set_id;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.admins.len(), 0);
+    assert_eq!(program_config.admin_threshold, 0);
+    assert_eq!(program_config.admin_set_id, admin_set_id + 1);
+
+    // Proposals from the previous admin set can't be approved, only cancelled.
+    let tx = send_approve_admin_proposal(&admin_senders[1], stale_proposal_id);
+    assert_client_err(tx, "Admin set changed since the proposal was created");
+    send_cancel_admin_proposal(&admin_senders[0], stale_proposal_id)?;
+
+    // Single authority updates work again.
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        Some(VOTE_DURATION),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.vote_duration, VOTE_DURATION);
+
+    Ok(())
+}
+
+fn test_config_timelock(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+
+    // Negative delay should fail.
+    let tx = send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(-1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    assert_client_err(tx, "RequireGteViolated");
+
+    // Setting the delay is applied immediately as there is no delay yet.
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(CONFIG_CHANGE_DELAY),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.config_change_delay, CONFIG_CHANGE_DELAY);
+    assert_eq!(program_config.pending_config, None);
+
+    // Queue a whitelist change and cancel it.
+    let operator = program_config.whitelisted_operators[0];
+    let operator_weight = OperatorWeight {
+        operator,
+        weight: 2,
+    };
+    send_update_operator_whitelist(tx_sender, None, None, Some(vec![operator_weight]))?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.operator_weights[0], DEFAULT_OPERATOR_WEIGHT);
+    let pending_whitelist = program_config.pending_whitelist.unwrap();
+    assert_eq!(pending_whitelist.whitelisted_operators[0], operator);
+    assert_eq!(pending_whitelist.operator_weights[0], 2);
+
+    send_cancel_pending_config(tx_sender)?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.pending_whitelist, None);
+    let tx = send_apply_pending_config(tx_sender);
+    assert_client_err(tx, "No pending config change");
+
+    // Queued config changes are merged and can't be applied before the delay.
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        Some(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let tx = send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        Some(VOTE_DURATION * 2),
+        Some(CLOSE_GRACE_PERIOD * 2),
+        Some(0),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let (_slot, timestamp) = fetch_tx_block_details(program, tx);
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.vote_duration, VOTE_DURATION);
+    let pending_config = program_config.pending_config.unwrap();
+    assert_eq!(pending_config.effective_at, timestamp + CONFIG_CHANGE_DELAY);
+    assert_eq!(pending_config.update.vote_duration, Some(VOTE_DURATION * 2));
+    assert_eq!(
+        pending_config.update.close_grace_period,
+        Some(CLOSE_GRACE_PERIOD * 2)
+    );
+    assert_eq!(pending_config.update.config_change_delay, Some(0));
+
+    let tx = send_apply_pending_config(tx_sender);
+    assert_client_err(tx, "Pending config change not effective");
+
+    // Apply after the delay.
+    thread::sleep(Duration::from_secs(CONFIG_CHANGE_DELAY as u64 + 1));
+    send_apply_pending_config(tx_sender)?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.pending_config, None);
+    assert_eq!(program_config.vote_duration, VOTE_DURATION * 2);
+    assert_eq!(program_config.close_grace_period, CLOSE_GRACE_PERIOD * 2);
+    assert_eq!(program_config.config_change_delay, 0);
+
+    // Changes are applied immediately again.
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        Some(VOTE_DURATION),
+        Some(CLOSE_GRACE_PERIOD),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.vote_duration, VOTE_DURATION);
+    assert_eq!(program_config.close_grace_period, CLOSE_GRACE_PERIOD);
+
+    Ok(())
+}
+
+fn test_scheduled_ballot_box(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let epoch_schedule = program.rpc().get_epoch_schedule()?;
+    let epoch = program.rpc().get_epoch_info()?.epoch;
+    let target_slot = epoch_schedule.get_first_slot_in_epoch(epoch);
+
+    // Scheduled ballot boxes are disabled until an offset is configured.
+    let tx = send_init_scheduled_ballot_box(tx_sender, epoch, target_slot);
+    assert_client_err(tx, "Scheduled ballot boxes are disabled");
+
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Some(0)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.scheduled_slot_offset, Some(0));
+    let next_ballot_id = program_config.next_ballot_id;
+
+    // Target slot must match the configured offset within the epoch.
+    let tx = send_init_scheduled_ballot_box(tx_sender, epoch, target_slot + 1);
+    assert_client_err(tx, "Invalid target slot");
+
+    // Anyone can create the ballot box once the target slot has passed.
+    let operator = &context.operators[0];
+    let tx_sender_operator = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: operator,
+    };
+    send_init_scheduled_ballot_box(tx_sender_operator, epoch, target_slot)?;
+    let (ballot_box_pda, bump) = BallotBox::scheduled_pda(epoch, target_slot);
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.ballot_id, next_ballot_id);
+    assert_eq!(ballot_box.bump, bump);
+    assert_eq!(ballot_box.epoch, epoch);
+    assert_eq!(ballot_box.target_slot, target_slot);
+    assert_eq!(ballot_box.min_consensus_threshold_bps, MIN_CONSENSUS_BPS);
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.next_ballot_id, next_ballot_id + 1);
+
+    // Only one ballot box can exist per scheduled target slot.
+    let tx = send_init_scheduled_ballot_box(tx_sender, epoch, target_slot);
+    assert_client_err(tx, "already in use");
+
+    // Operators vote on the scheduled ballot box as usual.
+    let ballot = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    send_cast_vote(tx_sender_operator, ballot_box_pda, ballot)?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.vote_count(), 1);
+    assert_eq!(ballot_box.ballot_tallies()[0].ballot, ballot);
+
+    // Once closed, the scheduled ballot box of the epoch can't be created again.
+    assert_eq!(program_config.next_scheduled_epoch, epoch + 1);
+    send_cancel_ballot_box(tx_sender, ballot_box_pda)?;
+    thread::sleep(Duration::from_secs(CLOSE_GRACE_PERIOD as u64 + 2));
+    send_close_ballot_box(tx_sender, ballot_box_pda, context.payer.pubkey())?;
+    let tx = send_init_scheduled_ballot_box(tx_sender, epoch, target_slot);
+    assert_client_err(tx, "Scheduled ballot box already created for epoch");
+
+    // Disable scheduled ballot boxes again.
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(None),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.scheduled_slot_offset, None);
+
+    Ok(())
+}
+
+fn test_pause(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let guardian = Keypair::new();
+    let tx_sender_guardian = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &guardian,
+    };
+
+    // Only the authority or guardian can pause.
+    let tx = send_set_paused(tx_sender_guardian, Some(true), None);
+    assert_client_err(tx, "Signer is not authorized");
+
+    send_update_program_config(
+        tx_sender,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(guardian.pubkey()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.guardian, guardian.pubkey());
+    assert!(!program_config.balloting_paused);
+    assert!(!program_config.verification_paused);
+
+    // Guardian pauses balloting only.
+    send_set_paused(tx_sender_guardian, Some(true), None)?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert!(program_config.balloting_paused);
+    assert!(!program_config.verification_paused);
+
+    let operator = &context.operators[0];
+    let tx_sender_operator = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: operator,
+    };
+    let target_slot = program.rpc().get_slot()?;
+    let (ballot_box_pda, _bump) = BallotBox::pda(program_config.next_ballot_id);
+    let tx = send_init_ballot_box(tx_sender_operator, ballot_box_pda, target_slot);
+    assert_client_err(tx, "Balloting is paused");
+    let ballot = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let tx = send_cast_vote(tx_sender_operator, BallotBox::pda(3).0, ballot);
+    assert_client_err(tx, "Balloting is paused");
+
+    // Verification still works while only balloting is paused.
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(3);
+    let (merkle_proof_pda, _merkle_proof) = program
+        .accounts::<MetaMerkleProof>(vec![])?
+        .into_iter()
+        .find(|(_, proof)| proof.consensus_result == consensus_result_pda)
+        .unwrap();
+    send_verify_merkle_proof(tx_sender, consensus_result_pda, merkle_proof_pda, None, None)?;
+
+    // Authority pauses verification.
+    send_set_paused(tx_sender, None, Some(true))?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert!(program_config.balloting_paused);
+    assert!(program_config.verification_paused);
+    let tx = send_verify_merkle_proof(
+        tx_sender,
+        consensus_result_pda,
+        merkle_proof_pda,
+        None,
+        None,
+    );
+    assert_client_err(tx, "Verification is paused");
+    let vote_account = Keypair::new().pubkey();
+    let tx = send_init_chunked_meta_merkle_proof(
+        tx_sender,
+        MetaMerkleProof::pda(&consensus_result_pda, &vote_account).0,
+        consensus_result_pda,
+        MetaMerkleLeaf {
+            voting_wallet: Keypair::new().pubkey(),
+            vote_account,
+            stake_merkle_root: [0; 32],
+            active_stake: 0,
+        },
+        1,
+        1,
+    );
+    assert_client_err(tx, "Verification is paused");
+
+    // Guardian unpauses both scopes.
+    send_set_paused(tx_sender_guardian, Some(false), Some(false))?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert!(!program_config.balloting_paused);
+    assert!(!program_config.verification_paused);
+    send_verify_merkle_proof(tx_sender, consensus_result_pda, merkle_proof_pda, None, None)?;
+
+    Ok(())
+}
+
+fn test_vote_extension_and_cancellation(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let operator = &context.operators[0];
+    let tx_sender = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: operator,
+    };
+    let max_vote_duration = VOTE_DURATION * 3;
+
+    let tx = send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(-1),
+        None,
+        None,
+        Some(-1),
+        None,
+        None,
+        None,
+        None,
+    );
+    assert_client_err(tx, "RequireGteViolated");
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(max_vote_duration),
+        None,
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.max_vote_duration, max_vote_duration);
+
+    let ballot_id = program_config.next_ballot_id;
+    let (ballot_box_pda, _bump) = BallotBox::pda(ballot_id);
+    let target_slot = program.rpc().get_slot()?;
+    let tx = send_init_ballot_box(tx_sender, ballot_box_pda, target_slot)?;
+    let (_slot, created_timestamp) = fetch_tx_block_details(program, tx);
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.created_timestamp, created_timestamp);
+    let vote_expiry_timestamp = ballot_box.vote_expiry_timestamp;
+
+    // Only the authority can extend voting.
+    let tx = send_extend_vote_expiry(tx_sender, ballot_box_pda, vote_expiry_timestamp + 1);
+    assert_client_err(tx, "ConstraintHasOne");
+
+    // Vote expiry can only be moved later, up to the max. vote duration.
+    let tx = send_extend_vote_expiry(tx_sender_admin, ballot_box_pda, vote_expiry_timestamp);
+    assert_client_err(tx, "Invalid vote expiry");
+    let tx = send_extend_vote_expiry(
+        tx_sender_admin,
+        ballot_box_pda,
+        created_timestamp + max_vote_duration + 1,
+    );
+    assert_client_err(tx, "Max vote duration exceeded");
+
+    let extended_timestamp = created_timestamp + max_vote_duration;
+    let tx = send_extend_vote_expiry(tx_sender_admin, ballot_box_pda, extended_timestamp)?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.vote_expiry_timestamp, extended_timestamp);
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteExpiryExtended(event)]
+            if event.ballot_id == ballot_id && event.vote_expiry_timestamp == extended_timestamp
+    ));
+
+    // Voting continues past the original expiry.
+    let current_slot = program.rpc().get_slot()?;
+    let current_time = program.rpc().get_block_time(current_slot)?;
+    let sleep_duration = vote_expiry_timestamp - current_time + 2;
+    thread::sleep(Duration::from_secs(sleep_duration.max(0) as u64));
+    let ballot = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    send_cast_vote(tx_sender, ballot_box_pda, ballot)?;
+
+    // Only the authority can cancel.
+    let tx = send_cancel_ballot_box(tx_sender, ballot_box_pda);
+    assert_client_err(tx, "ConstraintHasOne");
+
+    let tx = send_cancel_ballot_box(tx_sender_admin, ballot_box_pda)?;
+    let (_slot, cancelled_timestamp) = fetch_tx_block_details(program, tx);
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.cancelled_timestamp, cancelled_timestamp);
+
+    // Cancelled ballot boxes can't be voted on, extended, decided or finalized.
+    let tx = send_cancel_ballot_box(tx_sender_admin, ballot_box_pda);
+    assert_client_err(tx, "Ballot box is cancelled");
+    let tx_sender_operator2 = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.operators[1],
+    };
+    let tx = send_cast_vote(tx_sender_operator2, ballot_box_pda, ballot);
+    assert_client_err(tx, "Ballot box is cancelled");
+    let tx = send_remove_vote(tx_sender, ballot_box_pda);
+    assert_client_err(tx, "Ballot box is cancelled");
+    let tx = send_extend_vote_expiry(tx_sender_admin, ballot_box_pda, extended_timestamp);
+    assert_client_err(tx, "Ballot box is cancelled");
+    let tx = send_set_tie_breaker(tx_sender_admin, ballot_box_pda, 0);
+    assert_client_err(tx, "Ballot box is cancelled");
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(ballot_id);
+    let tx = send_finalize_ballot(tx_sender, ballot_box_pda, consensus_result_pda);
+    assert_client_err(tx, "Ballot box is cancelled");
+
+    // Finalized ballot boxes can't be cancelled.
+    let tx = send_cancel_ballot_box(tx_sender_admin, BallotBox::pda(3).0);
+    assert_client_err(tx, "Ballot is finalized");
+
+    // Cancelled ballot boxes can be closed after the grace period.
+    thread::sleep(Duration::from_secs(CLOSE_GRACE_PERIOD as u64 + 2));
+    send_close_ballot_box(tx_sender_admin, ballot_box_pda, context.payer.pubkey())?;
+    program
+        .rpc()
+        .get_account(&ballot_box_pda)
+        .expect_err("AccountNotFound");
+
+    Ok(())
+}
+
+fn test_commit_reveal(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let tx_senders: Vec<TxSender> = context
+        .operators
+        .iter()
+        .take(8)
+        .map(|operator| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        })
+        .collect();
+    let commit_duration = 4;
+
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(commit_duration),
+        None,
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.commit_duration, commit_duration);
+
+    // New ballot boxes open a commit window followed by a reveal window of vote_duration.
+    let ballot_id = program_config.next_ballot_id;
+    let (ballot_box_pda, _bump) = BallotBox::pda(ballot_id);
+    let target_slot = program.rpc().get_slot()?;
+    let tx = send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot)?;
+    let (_slot, created_timestamp) = fetch_tx_block_details(program, tx);
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    let commit_expiry_timestamp = created_timestamp + commit_duration;
+    assert_eq!(ballot_box.commit_expiry_timestamp, commit_expiry_timestamp);
+    assert_eq!(
+        ballot_box.vote_expiry_timestamp,
+        commit_expiry_timestamp + VOTE_DURATION
+    );
+
+    let ballot_a = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let ballot_b = Ballot {
+        meta_merkle_root: [3; 32],
+        snapshot_hash: [4; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let salt = |i: usize| [i as u8 + 1; 32];
+    let commitment = |i: usize, ballot: Ballot| {
+        ballot.commitment(
+            &context.operators[i].pubkey(),
+            &ballot_box_pda,
+            &salt(i),
+        )
+    };
+
+    // Ballots can't be cast directly.
+    let tx = send_cast_vote(&tx_senders[0], ballot_box_pda, ballot_a);
+    assert_client_err(tx, "Ballot box requires commit-reveal");
+
+    // Operators 0-5 commit to ballot A and operator 6 to ballot B.
+    for (i, tx_sender) in tx_senders.iter().enumerate().take(7) {
+        let ballot = if i < 6 { ballot_a } else { ballot_b };
+        let tx = send_commit_vote(tx_sender, ballot_box_pda, commitment(i, ballot))?;
+        let events = fetch_events(program, tx)?;
+        assert!(matches!(
+            &events[..],
+            [GovEvent::VoteCommitted(event)]
+                if event.ballot_id == ballot_id && event.commitment == commitment(i, ballot)
+        ));
+    }
+    // Operator 7 copies the commitment of operator 6, and can't reveal it.
+    send_commit_vote(&tx_senders[7], ballot_box_pda, commitment(6, ballot_b))?;
+    let tx = send_commit_vote(&tx_senders[0], ballot_box_pda, commitment(0, ballot_a));
+    assert_client_err(tx, "already in use");
+    let operator_commit_pda =
+        OperatorCommit::pda(&ballot_box_pda, &context.operators[0].pubkey()).0;
+    let operator_commit: OperatorCommit = program.account(operator_commit_pda)?;
+    assert_eq!(operator_commit.ballot_box, ballot_box_pda);
+    assert_eq!(operator_commit.operator, context.operators[0].pubkey());
+    assert_eq!(operator_commit.payer, context.payer.pubkey());
+    assert_eq!(operator_commit.commitment, commitment(0, ballot_a));
+
+    // Commitments are not tallied, and can't be revealed during the commit window.
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.vote_count(), 0);
+    assert_eq!(ballot_box.ballot_tallies().len(), 0);
+    let tx = send_reveal_vote(
+        &tx_senders[0],
+        ballot_box_pda,
+        ballot_a,
+        salt(0),
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Reveal window not open");
+
+    // Sleep till the commit window closes.
+    let current_slot = program.rpc().get_slot()?;
+    let current_time = program.rpc().get_block_time(current_slot)?;
+    let sleep_duration = commit_expiry_timestamp - current_time + 2;
+    thread::sleep(Duration::from_secs(sleep_duration as u64));
+
+    // Reveals must match the commitment.
+    let tx = send_reveal_vote(
+        &tx_senders[0],
+        ballot_box_pda,
+        ballot_b,
+        salt(0),
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Revealed ballot does not match commitment");
+    let tx = send_reveal_vote(
+        &tx_senders[0],
+        ballot_box_pda,
+        ballot_a,
+        salt(1),
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Revealed ballot does not match commitment");
+    let tx = send_reveal_vote(
+        &tx_senders[7],
+        ballot_box_pda,
+        ballot_b,
+        salt(6),
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Revealed ballot does not match commitment");
+
+    // Reveals are tallied like direct votes, and close the commitment.
+    for i in [0, 1, 2, 3, 4, 6] {
+        let ballot = if i < 6 { ballot_a } else { ballot_b };
+        send_reveal_vote(
+            &tx_senders[i],
+            ballot_box_pda,
+            ballot,
+            salt(i),
+            context.payer.pubkey(),
+        )?;
+    }
+    program
+        .rpc()
+        .get_account(&operator_commit_pda)
+        .expect_err("AccountNotFound");
+    let tx = send_commit_vote(&tx_senders[6], ballot_box_pda, commitment(6, ballot_b));
+    assert_client_err(tx, "Commit window has closed");
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert!(!ballot_box.has_consensus_reached());
+    assert_eq!(ballot_box.vote_count(), 6);
+    assert_eq!(ballot_box.ballot_tallies()[0].ballot, ballot_a);
+    assert_eq!(ballot_box.ballot_tallies()[0].tally, 5);
+    assert_eq!(ballot_box.ballot_tallies()[1].ballot, ballot_b);
+    assert_eq!(ballot_box.ballot_tallies()[1].tally, 1);
+
+    // Consensus is reached once enough reveals are in.
+    send_reveal_vote(
+        &tx_senders[5],
+        ballot_box_pda,
+        ballot_a,
+        salt(5),
+        context.payer.pubkey(),
+    )?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert!(ballot_box.has_consensus_reached());
+    assert_eq!(ballot_box.winning_ballot, ballot_a);
+    assert_eq!(ballot_box.ballot_tallies()[0].tally, 6);
+
+    // Unrevealed commitments, like the copied one of operator 7, can be closed by anyone once voting has ended.
+    let operator = context.operators[7].pubkey();
+    let operator_commit_pda = OperatorCommit::pda(&ballot_box_pda, &operator).0;
+    let tx = send_close_operator_commit(
+        &tx_senders[0],
+        ballot_box_pda,
+        operator,
+        context.payer.pubkey(),
+    );
+    assert_client_err(tx, "Voting has not ended");
+    send_cancel_ballot_box(tx_sender_admin, ballot_box_pda)?;
+    let tx = send_close_operator_commit(
+        &tx_senders[0],
+        ballot_box_pda,
+        operator,
+        context.payer.pubkey(),
+    )?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::OperatorCommitClosed(event)]
+            if event.ballot_box == ballot_box_pda && event.operator == operator
+    ));
+    program
+        .rpc()
+        .get_account(&operator_commit_pda)
+        .expect_err("AccountNotFound");
+
+    // Disable commit-reveal again. Ballot boxes without it reject commitments.
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0),
+        None,
+        None,
+        None,
+    )?;
+    let tx = send_commit_vote(&tx_senders[0], BallotBox::pda(3).0, commitment(0, ballot_a));
+    assert_client_err(tx, "Ballot box does not use commit-reveal");
+
+    Ok(())
+}
+
+fn test_change_vote(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_senders: Vec<TxSender> = context
+        .operators
+        .iter()
+        .take(8)
+        .map(|operator| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        })
+        .collect();
+
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    let (ballot_box_pda, _bump) = BallotBox::pda(program_config.next_ballot_id);
+    let target_slot = program.rpc().get_slot()?;
+    send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot)?;
+
+    let ballot = |i: u8| Ballot {
+        meta_merkle_root: [i; 32],
+        snapshot_hash: [i; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let (ballot_a, ballot_b, ballot_c, ballot_d) = (ballot(1), ballot(2), ballot(3), ballot(4));
+
+    let tx = send_change_vote(&tx_senders[1], ballot_box_pda, ballot_a);
+    assert_client_err(tx, "Operator has not voted");
+
+    send_cast_vote(&tx_senders[0], ballot_box_pda, ballot_a)?;
+    send_cast_vote(&tx_senders[1], ballot_box_pda, ballot_b)?;
+    let tx = send_change_vote(&tx_senders[1], ballot_box_pda, ballot_b);
+    assert_client_err(tx, "Operator already voted for this ballot");
+
+    // Operator 1 was the only voter for ballot B, so its BallotTally is reused for ballot C.
+    let tx = send_change_vote(&tx_senders[1], ballot_box_pda, ballot_c)?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteChanged(event)]
+            if event.previous_ballot_index == 1
+                && event.previous_tally == 0
+                && event.ballot == ballot_c
+                && event.ballot_index == 1
+                && event.weight == 1
+                && event.tally == 1
+    ));
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(
+        ballot_box.ballot_tallies(),
+        [BallotTally::new(0, ballot_a, 1), BallotTally::new(1, ballot_c, 1)]
+    );
+    assert_eq!(ballot_box.vote_count(), 2);
+    let operator1_vote = ballot_box.operator_vote(&tx_senders[1].authority.pubkey());
+    assert_eq!(operator1_vote.unwrap().ballot_index, 1);
+
+    // Moving to an existing ballot leaves a tally of 0, which cast_vote also reuses.
+    send_change_vote(&tx_senders[1], ballot_box_pda, ballot_a)?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.ballot_tallies()[0].tally, 2);
+    assert_eq!(ballot_box.ballot_tallies()[1].tally, 0);
+    let operator1_vote = ballot_box.operator_vote(&tx_senders[1].authority.pubkey());
+    assert_eq!(operator1_vote.unwrap().ballot_index, 0);
+
+    for tx_sender in &tx_senders[2..7] {
+        send_cast_vote(tx_sender, ballot_box_pda, ballot_d)?;
+    }
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert!(!ballot_box.has_consensus_reached());
+    assert_eq!(ballot_box.ballot_tallies().len(), 2);
+    assert_eq!(ballot_box.ballot_tallies()[1].ballot, ballot_d);
+    assert_eq!(ballot_box.ballot_tallies()[1].tally, 5);
+
+    // Changing a vote can reach consensus with 6/8 votes (75%).
+    let tx = send_change_vote(&tx_senders[0], ballot_box_pda, ballot_d)?;
+    let (consensus_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteChanged(_), GovEvent::ConsensusReached(event)]
+            if event.ballot == ballot_d && event.tally == 6 && event.slot == consensus_slot
+    ));
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.slot_consensus_reached, consensus_slot);
+    assert_eq!(ballot_box.winning_ballot, ballot_d);
+    assert_eq!(ballot_box.ballot_tallies()[0].tally, 1);
+    assert_eq!(ballot_box.ballot_tallies()[1].tally, 6);
+    let operator0_vote = ballot_box.operator_vote(&tx_senders[0].authority.pubkey());
+    assert_eq!(operator0_vote.unwrap().slot_voted, consensus_slot);
+
+    let tx = send_change_vote(&tx_senders[1], ballot_box_pda, ballot_d);
+    assert_client_err(tx, "Consensus has reached");
+
+    Ok(())
+}
+
+fn test_min_votes(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let tx_senders: Vec<TxSender> = context
+        .operators
+        .iter()
+        .take(8)
+        .map(|operator| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        })
+        .collect();
+
+    // A quorum larger than the whitelist could never be met.
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(9),
+        None,
+        None,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.min_votes, 9);
+    let (ballot_box_pda, _bump) = BallotBox::pda(program_config.next_ballot_id);
+    let target_slot = program.rpc().get_slot()?;
+    let tx = send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot);
+    assert_client_err(tx, "Min. votes exceeds number of eligible operators");
+
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(7),
+        None,
+        None,
+    )?;
+    send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot)?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.min_votes, 7);
+
+    let ballot_a = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [1; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let ballot_b = Ballot {
+        meta_merkle_root: [2; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+
+    // 6/8 votes (75%) is above the threshold, but only 6 operators have voted.
+    for tx_sender in &tx_senders[..6] {
+        send_cast_vote(tx_sender, ballot_box_pda, ballot_a)?;
+    }
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert!(!ballot_box.has_consensus_reached());
+
+    // The 7th vote meets the quorum, and ballot A wins even though the vote was for ballot B.
+    let tx = send_cast_vote(&tx_senders[6], ballot_box_pda, ballot_b)?;
+    let (consensus_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::VoteCast(_), GovEvent::ConsensusReached(event)]
+            if event.ballot == ballot_a && event.tally == 6 && event.slot == consensus_slot
+    ));
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.slot_consensus_reached, consensus_slot);
+    assert_eq!(ballot_box.winning_ballot, ballot_a);
+
+    // Votes are rejected once the ballot box is finalized, even before it expires.
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(ballot_box.ballot_id);
+    send_finalize_ballot(&tx_senders[0], ballot_box_pda, consensus_result_pda)?;
+    let tx = send_cast_vote(&tx_senders[7], ballot_box_pda, ballot_a);
+    assert_client_err(tx, "Ballot is finalized");
+
+    send_update_program_config(
+        tx_sender_admin,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(0),
+        None,
+        None,
+    )?;
+
+    Ok(())
+}
+
+fn test_operator_key_rotation(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let old_operator = &context.operators[0];
+    let new_operator = Keypair::new();
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let tx_sender_old = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: old_operator,
+    };
+    let tx_sender_new = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &new_operator,
+    };
+
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    let ballot_id = program_config.next_ballot_id;
+    let (ballot_box_pda, _bump) = BallotBox::pda(ballot_id);
+    let target_slot = program.rpc().get_slot()?;
+    send_init_ballot_box(tx_sender_old, ballot_box_pda, target_slot)?;
+    let ballot = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [1; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    send_cast_vote(tx_sender_old, ballot_box_pda, ballot)?;
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    let old_vote = *ballot_box.operator_vote(&old_operator.pubkey()).unwrap();
+    // A second open ballot box, in which the old key hasn't voted.
+    let (other_ballot_box_pda, _bump) = BallotBox::pda(ballot_id + 1);
+    send_init_ballot_box(tx_sender_old, other_ballot_box_pda, target_slot)?;
+
+    // Only whitelisted operators can rotate, and only to a key that isn't whitelisted.
+    let tx = send_propose_operator_key_rotation(tx_sender_new, Keypair::new().pubkey());
+    assert_client_err(tx, "Operator not whitelisted");
+    let tx = send_propose_operator_key_rotation(tx_sender_old, context.operators[1].pubkey());
+    assert_client_err(tx, "Operator already whitelisted");
+
+    let tx = send_propose_operator_key_rotation(tx_sender_old, new_operator.pubkey())?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::OperatorKeyRotationProposed(event)]
+            if event.old_operator == old_operator.pubkey()
+                && event.new_operator == new_operator.pubkey()
+    ));
+    let operator_key_rotation_pda = OperatorKeyRotation::pda(&old_operator.pubkey()).0;
+    let operator_key_rotation: OperatorKeyRotation =
+        program.account(operator_key_rotation_pda)?;
+    assert_eq!(operator_key_rotation.new_operator, new_operator.pubkey());
+    assert_eq!(operator_key_rotation.payer, context.payer.pubkey());
+
+    // A pending rotation can't be re-proposed, only cancelled by the old key.
+    let tx = send_propose_operator_key_rotation(tx_sender_old, Keypair::new().pubkey());
+    assert_client_err(tx, "already in use");
+    let tx = send_cancel_operator_key_rotation(tx_sender_new, context.payer.pubkey());
+    assert_client_err(tx, "AccountNotInitialized");
+    let tx = send_cancel_operator_key_rotation(tx_sender_old, context.payer.pubkey())?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::OperatorKeyRotationCancelled(event)]
+            if event.old_operator == old_operator.pubkey()
+                && event.new_operator == new_operator.pubkey()
+    ));
+    program
+        .rpc()
+        .get_account(&operator_key_rotation_pda)
+        .expect_err("AccountNotFound");
+    send_propose_operator_key_rotation(tx_sender_old, new_operator.pubkey())?;
+
+    // Only the proposed key can accept.
+    let tx_sender_other = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.operators[1],
+    };
+    let tx = send_accept_operator_key_rotation(
+        tx_sender_other,
+        old_operator.pubkey(),
+        context.payer.pubkey(),
+        &[ballot_box_pda],
+    );
+    assert_client_err(tx, "ConstraintHasOne");
+
+    // Commitments are bound to the old key, so the rotation is rejected while one of them
+    // can still be revealed.
+    let send_update_commit_duration = |commit_duration: i64| {
+        send_update_program_config(
+            tx_sender_admin,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(commit_duration),
+            None,
+            None,
+            None,
+        )
+    };
+    send_update_commit_duration(4)?;
+    let (commit_ballot_box_pda, _bump) = BallotBox::pda(ballot_id + 2);
+    send_init_ballot_box(tx_sender_old, commit_ballot_box_pda, target_slot)?;
+    send_update_commit_duration(0)?;
+    let commitment = ballot.commitment(&old_operator.pubkey(), &commit_ballot_box_pda, &[1; 32]);
+    send_commit_vote(tx_sender_old, commit_ballot_box_pda, commitment)?;
+    let tx = send_accept_operator_key_rotation(
+        tx_sender_new,
+        old_operator.pubkey(),
+        context.payer.pubkey(),
+        &find_open_ballot_boxes(program)?,
+    );
+    assert_client_err(tx, "Operator has an unrevealed commitment");
+    send_cancel_ballot_box(tx_sender_admin, commit_ballot_box_pda)?;
+
+    // Every open ballot box must be passed once, sorted by address.
+    let open_ballot_boxes = find_open_ballot_boxes(program)?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(
+        program_config.open_ballot_boxes as usize,
+        open_ballot_boxes.len()
+    );
+    assert!(open_ballot_boxes.contains(&ballot_box_pda));
+    assert!(open_ballot_boxes.contains(&other_ballot_box_pda));
+    let tx = send_accept_operator_key_rotation(
+        tx_sender_new,
+        old_operator.pubkey(),
+        context.payer.pubkey(),
+        &[ballot_box_pda],
+    );
+    assert_client_err(tx, "All open ballot boxes must be passed");
+    let reversed: Vec<Pubkey> = open_ballot_boxes.iter().rev().copied().collect();
+    let tx = send_accept_operator_key_rotation(
+        tx_sender_new,
+        old_operator.pubkey(),
+        context.payer.pubkey(),
+        &reversed,
+    );
+    assert_client_err(tx, "Ballot boxes must be sorted and unique");
+
+    let old_stats_pda = OperatorStats::pda(&old_operator.pubkey()).0;
+    let old_stats: OperatorStats = program.account(old_stats_pda)?;
+    let tx = send_accept_operator_key_rotation(
+        tx_sender_new,
+        old_operator.pubkey(),
+        context.payer.pubkey(),
+        &open_ballot_boxes,
+    )?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::OperatorKeyRotated(event)]
+            if event.old_operator == old_operator.pubkey()
+                && event.new_operator == new_operator.pubkey()
+                && event.ballot_ids.contains(&ballot_id)
+                && event.ballot_ids.contains(&(ballot_id + 1))
+    ));
+    program
+        .rpc()
+        .get_account(&operator_key_rotation_pda)
+        .expect_err("AccountNotFound");
+
+    // The new key takes the place of the old key in the whitelist and keeps its vote.
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.whitelisted_operators[0], new_operator.pubkey());
+    assert!(!program_config.whitelisted_operators.contains(&old_operator.pubkey()));
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.eligible_operators()[0], new_operator.pubkey());
+    assert_eq!(ballot_box.operator_vote(&new_operator.pubkey()), Some(&old_vote));
+    assert_eq!(ballot_box.operator_vote(&old_operator.pubkey()), None);
+    let other_ballot_box: BallotBox = program.account(other_ballot_box_pda)?;
+    assert!(other_ballot_box.operator_index(&old_operator.pubkey()).is_err());
+    let tx = send_cast_vote(tx_sender_old, other_ballot_box_pda, ballot);
+    assert_client_err(tx, "Operator not whitelisted");
+
+    // The operator's stats move to the new key.
+    program
+        .rpc()
+        .get_account(&old_stats_pda)
+        .expect_err("AccountNotFound");
+    let new_stats: OperatorStats =
+        program.account(OperatorStats::pda(&new_operator.pubkey()).0)?;
+    assert_eq!(new_stats.operator, new_operator.pubkey());
+    assert_eq!(new_stats.ballots_seen, old_stats.ballots_seen);
+    assert_eq!(new_stats.votes_cast, old_stats.votes_cast);
+    assert_eq!(
+        new_stats.votes_matching_consensus,
+        old_stats.votes_matching_consensus
+    );
+    assert_eq!(
+        new_stats.total_vote_latency_slots,
+        old_stats.total_vote_latency_slots
+    );
+
+    // Rotate back so that later tests can keep using the old key.
+    send_propose_operator_key_rotation(tx_sender_new, old_operator.pubkey())?;
+    send_accept_operator_key_rotation(
+        tx_sender_old,
+        new_operator.pubkey(),
+        context.payer.pubkey(),
+        &open_ballot_boxes,
+    )?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.whitelisted_operators[0], old_operator.pubkey());
+    let stats: OperatorStats = program.account(old_stats_pda)?;
+    assert_eq!(stats.operator, old_operator.pubkey());
+    assert_eq!(stats.votes_cast, old_stats.votes_cast);
+
+    Ok(())
+}
+
+#[test]
+fn test_expiry_resolution(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let tx_senders: Vec<TxSender> = context
+        .operators
+        .iter()
+        .take(8)
+        .map(|operator| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        })
+        .collect();
+    let send_update_expiry_resolution =
+        |expiry_resolution: ExpiryResolution, plurality_floor_bps: Option<u16>| {
+            send_update_program_config(
+                tx_sender_admin,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(expiry_resolution),
+                plurality_floor_bps,
+            )
+        };
+
+    // Create ballot boxes with a plurality winner, a tie, a plurality winner to be failed and a
+    // tie left to the tie breaker admin.
+    let target_slot = program.rpc().get_slot()?;
+    let ballot_a = Ballot {
+        meta_merkle_root: [1; 32],
+        snapshot_hash: [1; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let ballot_b = Ballot {
+        meta_merkle_root: [2; 32],
+        snapshot_hash: [2; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let mut ballot_box_pdas = vec![];
+    for votes in [
+        [ballot_a, ballot_a, ballot_a, ballot_b],
+        [ballot_a, ballot_a, ballot_b, ballot_b],
+        [ballot_a, ballot_a, ballot_a, ballot_b],
+        [ballot_a, ballot_a, ballot_b, ballot_b],
+    ] {
+        let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+        let (ballot_box_pda, _bump) = BallotBox::pda(program_config.next_ballot_id);
+        send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot)?;
+        for (tx_sender, ballot) in tx_senders.iter().zip(votes) {
+            send_cast_vote(tx_sender, ballot_box_pda, ballot)?;
+        }
+        ballot_box_pdas.push(ballot_box_pda);
+    }
+    let (plurality_pda, tie_pda, no_result_pda, tie_breaker_pda) = (
+        ballot_box_pdas[0],
+        ballot_box_pdas[1],
+        ballot_box_pdas[2],
+        ballot_box_pdas[3],
+    );
+
+    // Expired ballot boxes can't be finalized before voting expires.
+    let tx = send_finalize_expired_ballot(tx_sender_admin, no_result_pda);
+    assert_client_err(tx, "Voting not expired");
+
+    // Sleep till the last ballot box expires
+    let ballot_box: BallotBox = program.account(tie_breaker_pda)?;
+    let current_slot = program.rpc().get_slot()?;
+    let current_time = program.rpc().get_block_time(current_slot)?;
+    let sleep_duration = ballot_box.vote_expiry_timestamp - current_time + 2;
+    thread::sleep(Duration::from_secs(sleep_duration as u64));
+
+    // With the default policy, only the tie breaker admin resolves expired ballot boxes
+    // during the grace period.
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.expiry_resolution, ExpiryResolution::TieBreakerAdmin);
+    let tx = send_finalize_expired_ballot(tx_sender_admin, tie_breaker_pda);
+    assert_client_err(tx, "Not allowed by expiry resolution");
+
+    let tx = send_update_expiry_resolution(ExpiryResolution::Plurality, Some(10001));
+    assert_client_err(tx, "RequireGteViolated");
+    send_update_expiry_resolution(ExpiryResolution::Plurality, Some(2500))?;
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.expiry_resolution, ExpiryResolution::Plurality);
+    assert_eq!(program_config.plurality_floor_bps, 2500);
+
+    // The tie breaker admin can't override the policy.
+    let tx = send_set_tie_breaker(tx_sender_admin, plurality_pda, 0);
+    assert_client_err(tx, "Not allowed by expiry resolution");
+
+    // Ballot A wins by plurality with 3/8 votes (37.5%).
+    let tx = send_finalize_expired_ballot(tx_sender_admin, plurality_pda)?;
+    let (resolved_slot, _tx_block_time) = fetch_tx_block_details(program, tx);
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::ExpiredBallotFinalized(event)]
+            if event.expiry_resolution == ExpiryResolution::Plurality
+                && event.ballot == Some(ballot_a)
+                && event.tally == 3
+    ));
+    let ballot_box: BallotBox = program.account(plurality_pda)?;
+    assert_eq!(ballot_box.winning_ballot, ballot_a);
+    assert_eq!(ballot_box.slot_consensus_reached, resolved_slot);
+    assert_eq!(ballot_box.decision_method(), DecisionMethod::Plurality);
+    assert!(!ballot_box.is_failed());
+
+    let tx = send_finalize_expired_ballot(tx_sender_admin, plurality_pda);
+    assert_client_err(tx, "Consensus has reached");
+
+    let ballot_id = ballot_box.ballot_id;
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(ballot_id);
+    send_finalize_ballot(tx_sender_admin, plurality_pda, consensus_result_pda)?;
+    let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
+    assert_eq!(consensus_result.ballot, ballot_a);
+    assert_eq!(consensus_result.decision_method, DecisionMethod::Plurality);
+    assert_eq!(consensus_result.tally, 3);
+    assert_eq!(consensus_result.tally_bps, 3750);
+
+    // Tied ballots have no plurality winner, so the ballot box fails.
+    let tx = send_finalize_expired_ballot(tx_sender_admin, tie_pda)?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::ExpiredBallotFinalized(event)]
+            if event.ballot.is_none() && event.tally == 0
+    ));
+    let ballot_box: BallotBox = program.account(tie_pda)?;
+    assert!(ballot_box.is_failed());
+    assert!(!ballot_box.has_consensus_reached());
+
+    let tx = send_finalize_expired_ballot(tx_sender_admin, tie_pda);
+    assert_client_err(tx, "Ballot box failed");
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(ballot_box.ballot_id);
+    let tx = send_finalize_ballot(tx_sender_admin, tie_pda, consensus_result_pda);
+    assert_client_err(tx, "Consensus not reached");
+
+    // A failed ballot box can be closed once the grace period after expiry has elapsed.
+    thread::sleep(Duration::from_secs(CLOSE_GRACE_PERIOD as u64));
+    send_close_ballot_box(tx_sender_admin, tie_pda, ballot_box.payer)?;
+    assert!(program.rpc().get_account(&tie_pda).is_err());
+
+    // With NoResult, the ballot box fails even with a plurality winner.
+    send_update_expiry_resolution(ExpiryResolution::NoResult, None)?;
+    let tx = send_finalize_expired_ballot(tx_sender_admin, no_result_pda)?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::ExpiredBallotFinalized(event)]
+            if event.expiry_resolution == ExpiryResolution::NoResult && event.ballot.is_none()
+    ));
+    let ballot_box: BallotBox = program.account(no_result_pda)?;
+    assert!(ballot_box.is_failed());
+
+    send_update_expiry_resolution(ExpiryResolution::TieBreakerAdmin, Some(0))?;
+
+    // The tie breaker admin didn't set a winner within the grace period after expiry, so
+    // anyone can fail the ballot box instead of leaving it open.
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    let open_ballot_boxes = program_config.open_ballot_boxes;
+    let tx = send_finalize_expired_ballot(&tx_senders[0], tie_breaker_pda)?;
+    let events = fetch_events(program, tx)?;
+    assert!(matches!(
+        &events[..],
+        [GovEvent::ExpiredBallotFinalized(event)]
+            if event.expiry_resolution == ExpiryResolution::TieBreakerAdmin
+                && event.ballot.is_none()
+    ));
+    let ballot_box: BallotBox = program.account(tie_breaker_pda)?;
+    assert!(ballot_box.is_failed());
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.open_ballot_boxes, open_ballot_boxes - 1);
+
+    Ok(())
+}
+
+fn test_account_versioning(
+    program: &Program<&Keypair>,
+    context: &ProgramTestContext,
+) -> Result<(), ClientError> {
+    let tx_sender_admin = &TxSender {
+        program,
+        micro_lamports: None,
+        payer: &context.payer,
+        authority: &context.payer,
+    };
+    let tx_senders: Vec<TxSender> = context
+        .operators
+        .iter()
+        .take(8)
+        .map(|operator| TxSender {
+            program,
+            micro_lamports: None,
+            payer: &context.payer,
+            authority: operator,
+        })
+        .collect();
+
+    // Create a ballot box, consensus result and proof at the current version.
+    let target_slot = program.rpc().get_slot()?;
+    let ballot = Ballot {
+        meta_merkle_root: [3; 32],
+        snapshot_hash: [3; 32],
+        snapshot_slot: target_slot,
+        ..Default::default()
+    };
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    let (ballot_box_pda, _bump) = BallotBox::pda(program_config.next_ballot_id);
+    send_init_ballot_box(&tx_senders[0], ballot_box_pda, target_slot)?;
+    for tx_sender in tx_senders.iter().take(6) {
+        send_cast_vote(tx_sender, ballot_box_pda, ballot)?;
+    }
+    let (consensus_result_pda, _bump) = ConsensusResult::pda(program_config.next_ballot_id);
+    send_finalize_ballot(tx_sender_admin, ballot_box_pda, consensus_result_pda)?;
+
+    let meta_leaf = context.meta_merkle_snapshot.leaf_bundles[0]
+        .meta_merkle_leaf
+        .clone();
+    let (merkle_proof_pda, _bump) =
+        MetaMerkleProof::pda(&consensus_result_pda, &meta_leaf.vote_account);
+    send_init_chunked_meta_merkle_proof(
+        tx_sender_admin,
+        merkle_proof_pda,
+        consensus_result_pda,
+        meta_leaf,
+        2,
+        1,
+    )?;
+
+    let program_config: ProgramConfig = program.account(context.program_config_pda)?;
+    assert_eq!(program_config.version, ProgramConfig::VERSION);
+    let ballot_box: BallotBox = program.account(ballot_box_pda)?;
+    assert_eq!(ballot_box.version, BallotBox::VERSION);
+    let consensus_result: ConsensusResult = program.account(consensus_result_pda)?;
+    assert_eq!(consensus_result.version, ConsensusResult::VERSION);
+    let merkle_proof: MetaMerkleProof = program.account(merkle_proof_pda)?;
+    assert_eq!(merkle_proof.version, MetaMerkleProof::VERSION);
+
+    // No account was created with an older layout.
+    assert!(find_accounts_to_migrate(program)?.is_empty());
+
+    // Only the authority can migrate accounts.
+    let tx = send_migrate_account(&tx_senders[0], ballot_box_pda);
+    assert_client_err(tx, "ConstraintHasOne");
+
+    for address in [
+        context.program_config_pda,
+        consensus_result_pda,
+        merkle_proof_pda,
+    ] {
+        let tx = send_migrate_account(tx_sender_admin, address);
+        assert_client_err(tx, "Account already migrated");
+    }
+
+    // Ballot boxes are only converted from the legacy borsh layout, by migrate_ballot_box.
+    let tx = send_migrate_ballot_box(tx_sender_admin, ballot_box_pda);
+    assert_client_err(tx, "Account already migrated");
+    let operator = context.operators[0].pubkey();
+    for address in [ballot_box_pda, OperatorStats::pda(&operator).0] {
+        let tx = send_migrate_account(tx_sender_admin, address);
+        assert_client_err(tx, "Account type cannot be migrated");
+    }
+
+    send_close_meta_merkle_proof(tx_sender_admin, merkle_proof_pda)?;
+
+    Ok(())
+}
+
 fn main() {
     let anchor_wallet = std::env::var("ANCHOR_WALLET").unwrap();
     let payer = read_keypair_file(&anchor_wallet).unwrap();
@@ -703,4 +3189,17 @@ fn main() {
     test_merkle_proofs(&program, &context).unwrap();
     test_invalid_merkle_proofs(&program, &context).unwrap();
     test_tie_breaker(&program, &context).unwrap();
+    test_proposals(&program, &context).unwrap();
+    test_batch_merkle_proofs(&program, &context).unwrap();
+    test_admin_multisig(&program, &context).unwrap();
+    test_config_timelock(&program, &context).unwrap();
+    test_scheduled_ballot_box(&program, &context).unwrap();
+    test_pause(&program, &context).unwrap();
+    test_vote_extension_and_cancellation(&program, &context).unwrap();
+    test_commit_reveal(&program, &context).unwrap();
+    test_change_vote(&program, &context).unwrap();
+    test_min_votes(&program, &context).unwrap();
+    test_operator_key_rotation(&program, &context).unwrap();
+    test_expiry_resolution(&program, &context).unwrap();
+    test_account_versioning(&program, &context).unwrap();
 }
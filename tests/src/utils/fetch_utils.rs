@@ -1,4 +1,5 @@
 use anchor_client::{
+    anchor_lang::AnchorDeserialize,
     solana_client::rpc_config::RpcTransactionConfig,
     solana_sdk::{
         commitment_config::CommitmentConfig,
@@ -6,6 +7,7 @@ use anchor_client::{
     },
     Program,
 };
+use base64::{engine::general_purpose::STANDARD, Engine};
 
 pub fn fetch_tx_block_details(program: &Program<&Keypair>, tx: Signature) -> (u64, i64) {
     let tx_details = program
@@ -21,3 +23,22 @@ pub fn fetch_tx_block_details(program: &Program<&Keypair>, tx: Signature) -> (u6
         .unwrap();
     (tx_details.slot, tx_details.block_time.unwrap())
 }
+
+/// Fetches and deserializes the return data set by the transaction.
+pub fn fetch_tx_return_data<T: AnchorDeserialize>(program: &Program<&Keypair>, tx: Signature) -> T {
+    let tx_details = program
+        .rpc()
+        .get_transaction_with_config(
+            &tx,
+            RpcTransactionConfig {
+                encoding: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: None,
+            },
+        )
+        .unwrap();
+    let return_data = tx_details.transaction.meta.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, gov_v1::ID.to_string());
+    let data = STANDARD.decode(return_data.data.0).unwrap();
+    T::try_from_slice(&data).unwrap()
+}
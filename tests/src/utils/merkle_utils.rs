@@ -0,0 +1,57 @@
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use cli::{MetaMerkleLeafBundle, MetaMerkleSnapshot};
+use gov_merkle::MerkleTree;
+use gov_v1::{MetaMerkleLeaf, StakeMerkleLeaf};
+
+/// Builds a MetaMerkleSnapshot from (vote account, voting wallet, stake leaves) entries,
+/// following the same layout as the CLI snapshot generation.
+pub fn build_meta_merkle_snapshot(
+    mut vote_accounts: Vec<(Pubkey, Pubkey, Vec<StakeMerkleLeaf>)>,
+    slot: u64,
+) -> MetaMerkleSnapshot {
+    vote_accounts.sort_by_key(|(vote_account, _, _)| *vote_account);
+
+    let (meta_merkle_leaves, stake_merkle_leaves_collection): (Vec<_>, Vec<_>) = vote_accounts
+        .into_iter()
+        .map(|(vote_account, voting_wallet, mut stake_merkle_leaves)| {
+            stake_merkle_leaves.sort_by_key(|leaf| leaf.stake_account);
+            let hashed_nodes: Vec<[u8; 32]> = stake_merkle_leaves
+                .iter()
+                .map(|n| n.hash().to_bytes())
+                .collect();
+            let stake_merkle = MerkleTree::new(&hashed_nodes);
+            let meta_merkle_leaf = MetaMerkleLeaf {
+                voting_wallet,
+                vote_account,
+                stake_merkle_root: stake_merkle.root().unwrap(),
+                active_stake: stake_merkle_leaves.iter().map(|n| n.active_stake).sum(),
+            };
+            (meta_merkle_leaf, stake_merkle_leaves)
+        })
+        .unzip();
+
+    let hashed_nodes: Vec<[u8; 32]> = meta_merkle_leaves
+        .iter()
+        .map(|n| n.hash().to_bytes())
+        .collect();
+    let meta_merkle = MerkleTree::new(&hashed_nodes);
+
+    let leaf_bundles = meta_merkle_leaves
+        .into_iter()
+        .zip(stake_merkle_leaves_collection)
+        .enumerate()
+        .map(
+            |(i, (meta_merkle_leaf, stake_merkle_leaves))| MetaMerkleLeafBundle {
+                meta_merkle_leaf,
+                stake_merkle_leaves,
+                proof: meta_merkle.get_proof(i),
+            },
+        )
+        .collect();
+
+    MetaMerkleSnapshot {
+        root: meta_merkle.root().unwrap(),
+        leaf_bundles,
+        slot,
+    }
+}
@@ -3,3 +3,4 @@
 pub mod assert;
 pub mod data_types;
 pub mod fetch_utils;
+pub mod merkle_utils;
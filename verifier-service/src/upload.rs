@@ -9,7 +9,7 @@ use axum::{
     response::Json,
 };
 use cli::MetaMerkleSnapshot;
-use meta_merkle_tree::{merkle_tree::MerkleTree, utils::get_proof};
+use gov_merkle::MerkleTree;
 use serde_json::{json, Value};
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use sqlx::sqlite::SqlitePool;
@@ -145,11 +145,13 @@ async fn index_snapshot_data(
             .iter()
             .map(|n| n.hash().to_bytes())
             .collect();
-        let stake_merkle = MerkleTree::new(&hashed_nodes[..], true);
+        let stake_merkle = MerkleTree::new(&hashed_nodes);
 
         // Create stake account records for each stake leaf
         for (idx, stake_leaf) in bundle.stake_merkle_leaves.iter().enumerate() {
-            let stake_merkle_proof = get_proof(&stake_merkle, idx)
+            let stake_merkle_proof = stake_merkle
+                .get_proof(idx)
+                .unwrap()
                 .iter()
                 .map(|hash| bs58::encode(hash).into_string())
                 .collect();